@@ -0,0 +1,51 @@
+// Native timings are a proxy for gas: both scale with the number of wasm instructions executed.
+use core::str::FromStr;
+use cosmwasm_common_library::bigdecimal::BigDecimal;
+use cosmwasm_common_library::biginteger::BigInteger;
+use cosmwasm_std::Uint256;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// A contract normalizing a batch of token amounts with mixed decimals.
+fn amounts() -> Vec<(BigInteger, u32)> {
+    (0..1_000u64)
+        .map(|i| (BigInteger::from(i * 1_000_003), (i % 19) as u32))
+        .collect()
+}
+
+fn bench_scale_up(c: &mut Criterion) {
+    let amounts = amounts();
+    c.bench_function("scale_up/pow", |b| {
+        b.iter(|| {
+            for (amount, decimals) in &amounts {
+                black_box(amount.0 * Uint256::from(10u64).pow(18 - *decimals));
+            }
+        })
+    });
+    c.bench_function("scale_up/table", |b| {
+        b.iter(|| {
+            for (amount, decimals) in &amounts {
+                black_box(amount.scale_up(18 - *decimals));
+            }
+        })
+    });
+}
+
+fn bench_scale_down(c: &mut Criterion) {
+    let amounts = amounts();
+    c.bench_function("scale_down", |b| {
+        b.iter(|| {
+            for (amount, decimals) in &amounts {
+                black_box(amount.scale_down(*decimals).scale_up(*decimals));
+            }
+        })
+    });
+}
+
+fn bench_from_str(c: &mut Criterion) {
+    c.bench_function("from_str", |b| {
+        b.iter(|| black_box(BigDecimal::from_str(black_box("12345.000000000000000678"))))
+    });
+}
+
+criterion_group!(benches, bench_scale_up, bench_scale_down, bench_from_str);
+criterion_main!(benches);
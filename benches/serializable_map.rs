@@ -0,0 +1,30 @@
+use cosmwasm_common_library::biginteger::BigInteger;
+use cosmwasm_common_library::serializable_map::SerializableMap;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn map() -> SerializableMap<u64, BigInteger> {
+    SerializableMap::from(
+        (0..10_000u64)
+            .map(|i| (i, BigInteger::from(i * 7_919)))
+            .collect(),
+    )
+}
+
+fn bench_serialize(c: &mut Criterion) {
+    let map = map();
+    c.bench_function("serializable_map/serialize_10k", |b| {
+        b.iter(|| black_box(cosmwasm_std::to_json_vec(&map).unwrap()))
+    });
+}
+
+fn bench_deserialize(c: &mut Criterion) {
+    let json = cosmwasm_std::to_json_vec(&map()).unwrap();
+    c.bench_function("serializable_map/deserialize_10k", |b| {
+        b.iter(|| {
+            black_box(cosmwasm_std::from_json::<SerializableMap<u64, BigInteger>>(&json).unwrap())
+        })
+    });
+}
+
+criterion_group!(benches, bench_serialize, bench_deserialize);
+criterion_main!(benches);
@@ -0,0 +1,80 @@
+use crate::error::CommonError;
+use cosmwasm_std::{Addr, Api};
+
+pub const ELLIPSIS: char = '…';
+pub const DEFAULT_HEAD: usize = 4;
+pub const DEFAULT_TAIL: usize = 4;
+
+// "osmo1abcdefxyz" with head 2 and tail 3 -> "osmo1ab…xyz". `head` counts characters after the
+// bech32 separator so every address of a chain keeps its full prefix. Addresses too short to
+// gain anything are returned unchanged.
+pub fn short(addr: &str, head: usize, tail: usize) -> String {
+    let data_start = addr.rfind('1').map(|i| i + 1).unwrap_or(0);
+    let chars: Vec<char> = addr[data_start..].chars().collect();
+    if chars.len() <= head + tail + 1 {
+        return addr.to_string();
+    }
+    let mut out = String::with_capacity(data_start + head + tail + ELLIPSIS.len_utf8());
+    out.push_str(&addr[..data_start]);
+    out.extend(&chars[..head]);
+    out.push(ELLIPSIS);
+    out.extend(&chars[chars.len() - tail..]);
+    out
+}
+
+// Shortened form used in human-facing attributes (e.g. `display_sender`). Attributes that are
+// parsed back must carry the full address.
+pub fn short_attr(addr: &Addr) -> String {
+    short(addr.as_str(), DEFAULT_HEAD, DEFAULT_TAIL)
+}
+
+pub fn is_shortened(input: &str) -> bool {
+    input.contains(ELLIPSIS) || input.contains("...")
+}
+
+// Validates user input as an address, with a dedicated error for display strings pasted back in.
+pub fn parse_addr(api: &dyn Api, input: &str) -> Result<Addr, CommonError> {
+    if is_shortened(input) {
+        return Err(CommonError::ShortenedAddress(input.to_string()));
+    }
+    Ok(api.addr_validate(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::addr_display::{is_shortened, parse_addr, short, short_attr};
+    use crate::error::CommonError;
+    use cosmwasm_std::testing::MockApi;
+
+    #[test]
+    fn test_short() {
+        assert_eq!(short("osmo1abcdefghxyz", 2, 3), "osmo1ab…xyz");
+        assert_eq!(short("osmo1abcxyz", 2, 3), "osmo1abcxyz");
+        assert_eq!(short("nobech32separator", 2, 2), "no…or");
+
+        let api = MockApi::default();
+        let addr = api.addr_make("alice");
+        let display = short_attr(&addr);
+        assert!(display.starts_with("cosmwasm1"));
+        assert_eq!(display.chars().count(), "cosmwasm1".len() + 9);
+        assert!(is_shortened(&display));
+    }
+
+    #[test]
+    fn test_parse_addr() {
+        let api = MockApi::default();
+        let addr = api.addr_make("alice");
+        assert_eq!(parse_addr(&api, addr.as_str()).unwrap(), addr);
+
+        let display = short_attr(&addr);
+        assert_eq!(
+            parse_addr(&api, &display),
+            Err(CommonError::ShortenedAddress(display.clone()))
+        );
+        assert!(matches!(
+            parse_addr(&api, "cosmwasm1abcd...wxyz"),
+            Err(CommonError::ShortenedAddress(_))
+        ));
+        assert!(parse_addr(&api, "not an address").is_err());
+    }
+}
@@ -0,0 +1,172 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::number::Number;
+use crate::rounding::{mul_div, RoundingMode};
+use crate::signed_bigdecimal::SignedBigDecimal;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Decimal256;
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub struct PoolState {
+    pub base_reserve: BigInteger,
+    pub quote_reserve: BigInteger,
+}
+
+impl PoolState {
+    pub fn new(base_reserve: BigInteger, quote_reserve: BigInteger) -> Self {
+        Self {
+            base_reserve,
+            quote_reserve,
+        }
+    }
+}
+
+// Constant product (x * y = k) output for `amount_in`, with the fee taken from the input. Every
+// rounding step is a floor so the pool never pays out more than the invariant allows.
+pub fn constant_product_out(
+    amount_in: BigInteger,
+    reserve_in: BigInteger,
+    reserve_out: BigInteger,
+    fee: BigDecimal,
+) -> Result<BigInteger, CommonError> {
+    if !fee.is_ratio() {
+        return Err(CommonError::InvalidRatio(fee.to_string()));
+    }
+    let one = BigInteger(Decimal256::one().atomics());
+    let amount_in = mul_div(
+        amount_in,
+        BigInteger((Decimal256::one() - fee.0).atomics()),
+        one,
+        RoundingMode::Floor,
+    )?;
+    let reserve_after = reserve_in.checked_add(amount_in)?;
+    if reserve_after.is_zero() {
+        return Ok(BigInteger::zero());
+    }
+    mul_div(reserve_out, amount_in, reserve_after, RoundingMode::Floor)
+}
+
+// Impermanent loss of a 50/50 constant product position when the price moves by
+// `price_ratio_change` (exit price / entry price): 1 - 2 * sqrt(r) / (1 + r), in [0, 1].
+pub fn il(price_ratio_change: BigDecimal) -> BigDecimal {
+    let r = price_ratio_change.0;
+    let denominator = r.checked_add(Decimal256::one()).unwrap_or(Decimal256::MAX);
+    let lp_over_hold = (r.sqrt() * Decimal256::from_atomics(2u8, 0).unwrap()) / denominator;
+    BigDecimal(Decimal256::one().saturating_sub(lp_over_hold))
+}
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub struct LpVsHold {
+    pub lp_value: BigDecimal,
+    pub hold_value: BigDecimal,
+    // lp_value / hold_value - 1
+    pub relative: SignedBigDecimal,
+}
+
+// Values, in quote units, of a balanced deposit of `deposit_value` kept in the pool versus held,
+// after the price moved from `entry_price` to `exit_price`. `fee_return` is the pool fee income
+// as a fraction of the position value (e.g. 0.02 for 2%).
+pub fn lp_vs_hold(
+    deposit_value: BigDecimal,
+    entry_price: BigDecimal,
+    exit_price: BigDecimal,
+    fee_return: BigDecimal,
+) -> Result<LpVsHold, CommonError> {
+    let r = exit_price.checked_div(entry_price)?;
+    let half = deposit_value.checked_div(BigDecimal::percent(200))?;
+    let hold_value = half.checked_mul(BigDecimal::one().checked_add(r)?)?;
+    let lp_value = deposit_value
+        .checked_mul(BigDecimal(r.0.sqrt()))?
+        .checked_mul(BigDecimal::one().checked_add(fee_return)?)?;
+    let relative = if hold_value.is_zero() {
+        SignedBigDecimal::zero()
+    } else {
+        BigDecimal::percent_change(hold_value, lp_value)?
+    };
+    Ok(LpVsHold {
+        lp_value,
+        hold_value,
+        relative,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::amm::{constant_product_out, il, lp_vs_hold};
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use core::str::FromStr;
+
+    #[test]
+    fn test_constant_product_out() {
+        let reserve = BigInteger::from(1_000u64);
+        assert_eq!(
+            constant_product_out(
+                BigInteger::from(1_000u64),
+                reserve,
+                reserve,
+                BigDecimal::zero()
+            )
+            .unwrap(),
+            BigInteger::from(500u64)
+        );
+        assert_eq!(
+            constant_product_out(
+                BigInteger::from(100u64),
+                reserve,
+                reserve,
+                BigDecimal::percent(1)
+            )
+            .unwrap(),
+            BigInteger::from(90u64)
+        );
+        assert!(constant_product_out(
+            BigInteger::one(),
+            reserve,
+            reserve,
+            BigDecimal::percent(101)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_il() {
+        assert_eq!(il(BigDecimal::one()), BigDecimal::zero());
+        // a 4x move costs 20% versus holding, in either direction
+        assert_eq!(il(BigDecimal::percent(400)), BigDecimal::percent(20));
+        assert_eq!(il(BigDecimal::percent(25)), BigDecimal::percent(20));
+        // pinned so off-chain consumers can check they match to the last digit
+        assert_eq!(
+            il(BigDecimal::percent(200)),
+            BigDecimal::from_str("0.057190958417936635").unwrap()
+        );
+        assert_eq!(il(BigDecimal::zero()), BigDecimal::one());
+        assert!(il(BigDecimal::MAX) <= BigDecimal::one());
+    }
+
+    #[test]
+    fn test_lp_vs_hold() {
+        let deposit = BigDecimal::from(BigInteger::from(1000u64), 0);
+        let entry = BigDecimal::percent(200);
+        let exit = BigDecimal::percent(800);
+
+        let result = lp_vs_hold(deposit, entry, exit, BigDecimal::zero()).unwrap();
+        assert_eq!(
+            result.hold_value,
+            BigDecimal::from(BigInteger::from(2500u64), 0)
+        );
+        assert_eq!(
+            result.lp_value,
+            BigDecimal::from(BigInteger::from(2000u64), 0)
+        );
+        assert_eq!(result.relative.abs(), il(BigDecimal::percent(400)));
+        assert!(result.relative.is_negative());
+
+        let with_fees = lp_vs_hold(deposit, entry, exit, BigDecimal::percent(25)).unwrap();
+        assert_eq!(with_fees.lp_value, result.hold_value);
+        assert!(with_fees.relative.is_zero());
+    }
+}
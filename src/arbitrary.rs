@@ -0,0 +1,127 @@
+use crate::asset::Asset;
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::serializable_denom::SerializableDenom;
+use cosmwasm_std::{Decimal256, Uint256};
+use proptest::prelude::*;
+
+impl Arbitrary for BigInteger {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<[u8; 32]>()
+            .prop_map(BigInteger::from_be_bytes)
+            .boxed()
+    }
+}
+
+impl Arbitrary for BigDecimal {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<[u8; 32]>()
+            .prop_map(BigDecimal::from_be_bytes)
+            .boxed()
+    }
+}
+
+impl Arbitrary for SerializableDenom {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            "[a-z][a-z0-9]{2,15}".prop_map(SerializableDenom::Native),
+            "[a-z]{2,8}1[a-z0-9]{38,58}".prop_map(SerializableDenom::Cw20),
+        ]
+        .boxed()
+    }
+}
+
+// amounts are bounded by u128 so that sums and products of a few assets do not overflow
+impl Arbitrary for Asset {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<SerializableDenom>(), any::<u128>())
+            .prop_map(|(denom, amount)| Asset::new(denom, amount))
+            .boxed()
+    }
+}
+
+// inclusive on both ends
+pub fn big_integer_range(min: BigInteger, max: BigInteger) -> BoxedStrategy<BigInteger> {
+    assert!(min <= max, "min must not be greater than max");
+    let span = max - min;
+    any::<[u8; 32]>()
+        .prop_map(move |bytes| {
+            let value = Uint256::from_be_bytes(bytes);
+            if span == BigInteger::MAX {
+                BigInteger(value)
+            } else {
+                min + BigInteger(value % (span.0 + Uint256::one()))
+            }
+        })
+        .boxed()
+}
+
+pub fn big_integer_up_to(max: BigInteger) -> BoxedStrategy<BigInteger> {
+    big_integer_range(BigInteger::zero(), max)
+}
+
+// inclusive on both ends, at full 18 decimal precision
+pub fn big_decimal_range(min: BigDecimal, max: BigDecimal) -> BoxedStrategy<BigDecimal> {
+    big_integer_range(BigInteger(min.0.atomics()), BigInteger(max.0.atomics()))
+        .prop_map(|atomics| BigDecimal(Decimal256::new(atomics.0)))
+        .boxed()
+}
+
+pub fn big_decimal_ratio() -> BoxedStrategy<BigDecimal> {
+    big_decimal_range(BigDecimal::zero(), BigDecimal::one())
+}
+
+pub fn native_asset(denom: &str, max_amount: BigInteger) -> BoxedStrategy<Asset> {
+    let denom = denom.to_string();
+    big_integer_up_to(max_amount)
+        .prop_map(move |amount| Asset::native(denom.clone(), amount))
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::arbitrary::{big_decimal_ratio, big_integer_range, native_asset};
+    use crate::asset::Asset;
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use core::str::FromStr;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_bounded(
+            value in big_integer_range(BigInteger::from(10u64), BigInteger::from(20u64)),
+            ratio in big_decimal_ratio(),
+        ) {
+            prop_assert!(value >= BigInteger::from(10u64) && value <= BigInteger::from(20u64));
+            prop_assert!(ratio.is_ratio());
+        }
+
+        #[test]
+        fn test_asset_round_trip(asset in any::<Asset>()) {
+            prop_assert_eq!(Asset::from_str(&asset.to_string()).unwrap(), asset);
+        }
+
+        #[test]
+        fn test_native_asset(asset in native_asset("uatom", BigInteger::from(1000u64))) {
+            prop_assert!(asset.amount <= BigInteger::from(1000u64));
+        }
+
+        #[test]
+        fn test_bytes_round_trip(value in any::<BigDecimal>()) {
+            prop_assert_eq!(BigDecimal::from_be_bytes(value.to_be_bytes()), value);
+        }
+    }
+}
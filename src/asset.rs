@@ -0,0 +1,156 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::serializable_denom::SerializableDenom;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+use cosmwasm_schema::cw_serde;
+#[cfg(not(feature = "cw20"))]
+use cosmwasm_std::StdError;
+use cosmwasm_std::{coins, BankMsg, Coin, CosmosMsg, StdResult, Uint128};
+#[cfg(feature = "cw20")]
+use cosmwasm_std::{to_json_binary, WasmMsg};
+#[cfg(feature = "cw20")]
+use cw20::Cw20ExecuteMsg;
+
+#[cw_serde]
+#[derive(Eq, Ord, PartialOrd)]
+pub struct Asset {
+    pub denom: SerializableDenom,
+    pub amount: BigInteger,
+}
+
+impl Asset {
+    pub fn new(denom: SerializableDenom, amount: impl Into<BigInteger>) -> Self {
+        Self {
+            denom,
+            amount: amount.into(),
+        }
+    }
+
+    pub fn native(denom: impl Into<String>, amount: impl Into<BigInteger>) -> Self {
+        Self::new(SerializableDenom::Native(denom.into()), amount)
+    }
+
+    pub fn cw20(contract_address: impl Into<String>, amount: impl Into<BigInteger>) -> Self {
+        Self::new(SerializableDenom::Cw20(contract_address.into()), amount)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount.is_zero()
+    }
+
+    pub fn transfer_msg(&self, recipient: impl Into<String>) -> StdResult<CosmosMsg> {
+        let amount = self.amount.to_uint128()?;
+        Ok(match &self.denom {
+            SerializableDenom::Native(denom) => BankMsg::Send {
+                to_address: recipient.into(),
+                amount: coins(amount.u128(), denom),
+            }
+            .into(),
+            #[cfg(feature = "cw20")]
+            SerializableDenom::Cw20(contract_address) => WasmMsg::Execute {
+                contract_addr: contract_address.clone(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.into(),
+                    amount,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+            #[cfg(not(feature = "cw20"))]
+            SerializableDenom::Cw20(_) => return Err(cw20_disabled()),
+        })
+    }
+
+    pub fn burn_msg(&self) -> StdResult<CosmosMsg> {
+        let amount = self.amount.to_uint128()?;
+        Ok(match &self.denom {
+            SerializableDenom::Native(denom) => BankMsg::Burn {
+                amount: coins(amount.u128(), denom),
+            }
+            .into(),
+            #[cfg(feature = "cw20")]
+            SerializableDenom::Cw20(contract_address) => WasmMsg::Execute {
+                contract_addr: contract_address.clone(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Burn { amount })?,
+                funds: vec![],
+            }
+            .into(),
+            #[cfg(not(feature = "cw20"))]
+            SerializableDenom::Cw20(_) => return Err(cw20_disabled()),
+        })
+    }
+}
+
+#[cfg(not(feature = "cw20"))]
+fn cw20_disabled() -> StdError {
+    StdError::generic_err("cw20 messages require the `cw20` feature")
+}
+
+impl From<Coin> for Asset {
+    fn from(coin: Coin) -> Self {
+        Self::native(coin.denom, coin.amount)
+    }
+}
+
+// Fails for cw20 tokens and for amounts above `Uint128::MAX`.
+impl TryFrom<Asset> for Coin {
+    type Error = CommonError;
+
+    fn try_from(asset: Asset) -> Result<Self, Self::Error> {
+        match &asset.denom {
+            SerializableDenom::Native(denom) => {
+                Ok(Coin::new(Uint128::try_from(asset.amount.0)?, denom))
+            }
+            SerializableDenom::Cw20(_) => {
+                Err(CommonError::UnsupportedDenom(asset.denom.to_string()))
+            }
+        }
+    }
+}
+
+impl Display for Asset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}", self.denom, self.amount)
+    }
+}
+
+impl FromStr for Asset {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (denom, amount) = s
+            .rsplit_once(':')
+            .ok_or_else(|| CommonError::InvalidAsset(s.to_string()))?;
+        Ok(Self {
+            denom: SerializableDenom::from_str(denom)?,
+            amount: BigInteger::from_str(amount)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::asset::Asset;
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::serializable_denom::SerializableDenom;
+    use core::str::FromStr;
+
+    #[test]
+    fn test_from_str() {
+        let asset = Asset::from_str("cw20_contract:42").unwrap();
+        assert_eq!(asset.denom, SerializableDenom::Cw20("contract".to_string()));
+        assert_eq!(asset.amount, BigInteger::from(42u64));
+        assert_eq!(Asset::from_str(&asset.to_string()).unwrap(), asset);
+
+        assert_eq!(
+            Asset::from_str("native_uatom"),
+            Err(CommonError::InvalidAsset("native_uatom".to_string()))
+        );
+        assert!(matches!(
+            Asset::from_str("uatom:1"),
+            Err(CommonError::Std(_))
+        ));
+    }
+}
@@ -0,0 +1,297 @@
+use crate::asset::Asset;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::serializable_denom::SerializableDenom;
+use crate::serializable_map::SerializableMap;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Coins, StdResult};
+#[cfg(feature = "cw20")]
+use cw20::Cw20Coin;
+
+#[cw_serde]
+pub struct Balances(pub SerializableMap<SerializableDenom, BigInteger>);
+
+impl Balances {
+    pub fn new() -> Self {
+        Self(SerializableMap::new())
+    }
+
+    pub fn get(&self, denom: &SerializableDenom) -> BigInteger {
+        self.0.get(denom).copied().unwrap_or_default()
+    }
+
+    pub fn add(&mut self, denom: SerializableDenom, amount: BigInteger) -> Result<(), CommonError> {
+        if amount.is_zero() {
+            return Ok(());
+        }
+        let balance = self.get(&denom).checked_add(amount)?;
+        self.0.set(denom, balance);
+        Ok(())
+    }
+
+    pub fn add_asset(&mut self, asset: &Asset) -> Result<(), CommonError> {
+        self.add(asset.denom.clone(), asset.amount)
+    }
+
+    pub fn checked_sub(
+        &mut self,
+        denom: &SerializableDenom,
+        amount: BigInteger,
+    ) -> Result<(), CommonError> {
+        let balance = self.get(denom);
+        if balance < amount {
+            return Err(CommonError::InsufficientBalance {
+                denom: denom.to_string(),
+                available: balance.to_string(),
+                required: amount.to_string(),
+            });
+        }
+        let remaining = balance - amount;
+        if remaining.is_zero() {
+            self.0.delete(denom);
+        } else {
+            self.0.set(denom.clone(), remaining);
+        }
+        Ok(())
+    }
+
+    pub fn checked_sub_asset(&mut self, asset: &Asset) -> Result<(), CommonError> {
+        self.checked_sub(&asset.denom, asset.amount)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&SerializableDenom, &BigInteger)> {
+        self.0.iter()
+    }
+
+    pub fn to_assets(&self) -> Vec<Asset> {
+        self.iter()
+            .map(|(denom, amount)| Asset::new(denom.clone(), *amount))
+            .collect()
+    }
+
+    pub fn from_coins(coins: &[Coin]) -> Self {
+        let mut balances = Self::new();
+        for coin in coins {
+            balances
+                .add(
+                    SerializableDenom::Native(coin.denom.clone()),
+                    coin.amount.into(),
+                )
+                .expect("Uint128 amounts do not overflow a 256-bit balance");
+        }
+        balances
+    }
+
+    #[cfg(feature = "cw20")]
+    pub fn add_cw20_coins(&mut self, coins: &[Cw20Coin]) {
+        for coin in coins {
+            self.add(
+                SerializableDenom::Cw20(coin.address.clone()),
+                coin.amount.into(),
+            )
+            .expect("Uint128 amounts do not overflow a 256-bit balance");
+        }
+    }
+
+    pub fn to_coins(&self) -> StdResult<Vec<Coin>> {
+        self.iter()
+            .filter_map(|(denom, amount)| match denom {
+                SerializableDenom::Native(denom) => Some((denom, amount)),
+                SerializableDenom::Cw20(_) => None,
+            })
+            .map(|(denom, amount)| Ok(Coin::new(amount.to_uint128()?, denom)))
+            .collect()
+    }
+
+    #[cfg(feature = "cw20")]
+    pub fn to_cw20_coins(&self) -> StdResult<Vec<Cw20Coin>> {
+        self.iter()
+            .filter_map(|(denom, amount)| match denom {
+                SerializableDenom::Native(_) => None,
+                SerializableDenom::Cw20(address) => Some((address, amount)),
+            })
+            .map(|(address, amount)| {
+                Ok(Cw20Coin {
+                    address: address.clone(),
+                    amount: amount.to_uint128()?,
+                })
+            })
+            .collect()
+    }
+}
+
+impl Default for Balances {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<Vec<Asset>> for Balances {
+    type Error = CommonError;
+
+    fn try_from(assets: Vec<Asset>) -> Result<Self, Self::Error> {
+        let mut balances = Self::new();
+        for asset in assets.iter() {
+            balances.add_asset(asset)?;
+        }
+        Ok(balances)
+    }
+}
+
+impl From<Coins> for Balances {
+    fn from(coins: Coins) -> Self {
+        Self::from_coins(&coins.into_vec())
+    }
+}
+
+// Fails if any balance is a cw20 token or above `Uint128::MAX`. Zero balances are dropped.
+impl TryFrom<Balances> for Coins {
+    type Error = CommonError;
+
+    fn try_from(balances: Balances) -> Result<Self, Self::Error> {
+        let mut coins = Coins::default();
+        for asset in balances.to_assets() {
+            coins.add(Coin::try_from(asset)?)?;
+        }
+        Ok(coins)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::asset::Asset;
+    use crate::balances::Balances;
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::serializable_denom::SerializableDenom;
+    use cosmwasm_std::coins;
+
+    #[test]
+    fn test_add_and_sub() {
+        let atom = SerializableDenom::Native("uatom".to_string());
+        let mut balances = Balances::from_coins(&coins(100, "uatom"));
+        balances.add(atom.clone(), BigInteger::from(50u64)).unwrap();
+        assert_eq!(balances.get(&atom), BigInteger::from(150u64));
+
+        assert_eq!(
+            balances.checked_sub(&atom, BigInteger::from(200u64)),
+            Err(CommonError::InsufficientBalance {
+                denom: "native_uatom".to_string(),
+                available: "150".to_string(),
+                required: "200".to_string(),
+            })
+        );
+        assert!(balances
+            .checked_sub_asset(&Asset::native("uosmo", 1u64))
+            .is_err());
+
+        balances
+            .checked_sub(&atom, BigInteger::from(150u64))
+            .unwrap();
+        assert!(balances.is_empty());
+
+        balances.add(atom.clone(), BigInteger::MAX).unwrap();
+        assert!(balances.add(atom.clone(), BigInteger::one()).is_err());
+        assert!(balances.add_asset(&Asset::native("uatom", 1u64)).is_err());
+        assert_eq!(balances.get(&atom), BigInteger::MAX);
+    }
+
+    #[test]
+    #[cfg(feature = "cw20")]
+    fn test_coin_conversion() {
+        use cosmwasm_std::{coin, Uint128};
+        use cw20::Cw20Coin;
+
+        let mut balances = Balances::from_coins(&[coin(2, "uosmo"), coin(1, "uatom")]);
+        balances.add_cw20_coins(&[Cw20Coin {
+            address: "token".to_string(),
+            amount: Uint128::new(3),
+        }]);
+
+        assert_eq!(
+            balances.to_coins().unwrap(),
+            vec![coin(1, "uatom"), coin(2, "uosmo")]
+        );
+        assert_eq!(balances.to_cw20_coins().unwrap()[0].amount, Uint128::new(3));
+        assert_eq!(
+            balances.to_assets(),
+            vec![
+                Asset::native("uatom", 1u64),
+                Asset::native("uosmo", 2u64),
+                Asset::cw20("token", 3u64),
+            ]
+        );
+
+        balances
+            .add(
+                SerializableDenom::Native("big".to_string()),
+                BigInteger::from(u128::MAX) + BigInteger::one(),
+            )
+            .unwrap();
+        assert!(balances.to_coins().is_err());
+    }
+
+    #[test]
+    fn test_cosmwasm_coins() {
+        use cosmwasm_std::{coin, Coin, Coins, Uint128};
+
+        let asset = Asset::from(coin(5, "uatom"));
+        assert_eq!(asset, Asset::native("uatom", 5u64));
+        assert_eq!(Coin::try_from(asset).unwrap(), coin(5, "uatom"));
+        assert_eq!(
+            Coin::try_from(Asset::cw20("token", 5u64)),
+            Err(CommonError::UnsupportedDenom("cw20_token".to_string()))
+        );
+        assert!(matches!(
+            Coin::try_from(Asset::native(
+                "uatom",
+                BigInteger::from(u128::MAX) + BigInteger::one()
+            )),
+            Err(CommonError::ConversionOverflow(_))
+        ));
+
+        let coins = Coins::try_from(vec![coin(2, "uosmo"), coin(1, "uatom")]).unwrap();
+        let mut balances = Balances::from(coins.clone());
+        assert_eq!(
+            balances.get(&SerializableDenom::Native("uosmo".to_string())),
+            BigInteger::from(2u64)
+        );
+        assert_eq!(Coins::try_from(balances.clone()).unwrap(), coins);
+
+        balances
+            .add(
+                SerializableDenom::Native("uusd".to_string()),
+                BigInteger::zero(),
+            )
+            .unwrap();
+        assert_eq!(Coins::try_from(balances.clone()).unwrap().len(), 2);
+
+        balances
+            .add(
+                SerializableDenom::Native("big".to_string()),
+                Uint128::MAX.into(),
+            )
+            .unwrap();
+        balances
+            .add(
+                SerializableDenom::Native("big".to_string()),
+                BigInteger::one(),
+            )
+            .unwrap();
+        assert!(matches!(
+            Coins::try_from(balances.clone()),
+            Err(CommonError::ConversionOverflow(_))
+        ));
+
+        let mut balances = Balances::from(coins);
+        balances.add_asset(&Asset::cw20("token", 1u64)).unwrap();
+        assert_eq!(
+            Coins::try_from(balances),
+            Err(CommonError::UnsupportedDenom("cw20_token".to_string()))
+        );
+    }
+}
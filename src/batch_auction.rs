@@ -0,0 +1,177 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::rounding::{mul_div, RoundingMode};
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct Clearing {
+    pub price: BigDecimal,
+    pub volume: BigInteger,
+    // indexed like the bids and asks passed to `clear`
+    pub bid_fills: Vec<BigInteger>,
+    pub ask_fills: Vec<BigInteger>,
+}
+
+fn checked_sum<'a>(
+    mut amounts: impl Iterator<Item = &'a BigInteger>,
+) -> Result<BigInteger, CommonError> {
+    amounts.try_fold(BigInteger::zero(), |acc, amount| {
+        Ok(acc.checked_add(*amount)?)
+    })
+}
+
+// Finds the uniform price that maximizes the matched volume. Ties are broken by the smallest
+// demand/supply imbalance and then by the midpoint of the remaining candidate prices.
+pub fn clear(
+    bids: &[(BigDecimal, BigInteger)],
+    asks: &[(BigDecimal, BigInteger)],
+) -> Result<Option<Clearing>, CommonError> {
+    let mut candidates: Vec<BigDecimal> = bids.iter().chain(asks.iter()).map(|(p, _)| *p).collect();
+    candidates.sort();
+    candidates.dedup();
+
+    let mut best: Option<(BigInteger, BigInteger, Vec<BigDecimal>)> = None;
+    for price in candidates {
+        let demand = checked_sum(bids.iter().filter(|(p, _)| *p >= price).map(|(_, a)| a))?;
+        let supply = checked_sum(asks.iter().filter(|(p, _)| *p <= price).map(|(_, a)| a))?;
+        let volume = demand.min(supply);
+        if volume.is_zero() {
+            continue;
+        }
+        let imbalance = demand.max(supply) - volume;
+        match &mut best {
+            Some((best_volume, best_imbalance, prices))
+                if *best_volume == volume && *best_imbalance == imbalance =>
+            {
+                prices.push(price)
+            }
+            Some((best_volume, best_imbalance, _))
+                if volume < *best_volume
+                    || (volume == *best_volume && imbalance > *best_imbalance) => {}
+            _ => best = Some((volume, imbalance, vec![price])),
+        }
+    }
+
+    let (volume, _, prices) = match best {
+        Some(best) => best,
+        None => return Ok(None),
+    };
+    let low = prices[0];
+    let high = prices[prices.len() - 1];
+    let price = low + (high - low) / BigDecimal::from_ratio(2u64, 1u64);
+
+    let bid_fills = pro_rata(bids, |p| *p >= price, volume)?;
+    let ask_fills = pro_rata(asks, |p| *p <= price, volume)?;
+    Ok(Some(Clearing {
+        price,
+        volume,
+        bid_fills,
+        ask_fills,
+    }))
+}
+
+// Every eligible order gets its floored pro-rata share; the units lost to flooring are handed out
+// one by one in input order so the fills always add up to `volume`.
+fn pro_rata(
+    orders: &[(BigDecimal, BigInteger)],
+    eligible: impl Fn(&BigDecimal) -> bool,
+    volume: BigInteger,
+) -> Result<Vec<BigInteger>, CommonError> {
+    let total = checked_sum(orders.iter().filter(|(p, _)| eligible(p)).map(|(_, a)| a))?;
+    let mut fills = orders
+        .iter()
+        .map(|(price, amount)| {
+            if eligible(price) {
+                mul_div(*amount, volume, total, RoundingMode::Floor)
+            } else {
+                Ok(BigInteger::zero())
+            }
+        })
+        .collect::<Result<Vec<BigInteger>, CommonError>>()?;
+
+    let mut remainder = volume - checked_sum(fills.iter())?;
+    for (fill, (price, amount)) in fills.iter_mut().zip(orders.iter()) {
+        if remainder.is_zero() {
+            break;
+        }
+        if eligible(price) && *fill < *amount {
+            *fill += BigInteger::one();
+            remainder -= BigInteger::one();
+        }
+    }
+    Ok(fills)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::batch_auction::clear;
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::rand::Prng;
+
+    fn order(price: u64, amount: u64) -> (BigDecimal, BigInteger) {
+        (BigDecimal::percent(price), BigInteger::from(amount))
+    }
+
+    #[test]
+    fn test_clear() {
+        let bids = vec![order(110, 10), order(100, 10)];
+        let asks = vec![order(90, 5), order(105, 10)];
+        let clearing = clear(&bids, &asks).unwrap().unwrap();
+        assert_eq!(clearing.volume, BigInteger::from(10u64));
+        // 1.05 and 1.10 both match 10 with the same imbalance
+        assert_eq!(clearing.price, BigDecimal::from_ratio(1075u64, 1000u64));
+        assert_eq!(
+            clearing.bid_fills,
+            vec![BigInteger::from(10u64), BigInteger::zero()]
+        );
+        assert_eq!(
+            clearing.ask_fills,
+            vec![BigInteger::from(4u64), BigInteger::from(6u64)]
+        );
+
+        assert_eq!(clear(&[order(90, 10)], &[order(100, 10)]).unwrap(), None);
+    }
+
+    #[test]
+    fn test_pro_rata_remainder() {
+        let bids = vec![order(100, 1), order(100, 1), order(100, 1)];
+        let asks = vec![order(100, 2)];
+        let clearing = clear(&bids, &asks).unwrap().unwrap();
+        assert_eq!(
+            clearing.bid_fills,
+            vec![BigInteger::one(), BigInteger::one(), BigInteger::zero()]
+        );
+    }
+
+    #[test]
+    fn test_conservation() {
+        let mut rng = Prng::new([9u8; 32]);
+        let random_orders = |rng: &mut Prng| -> Vec<(BigDecimal, BigInteger)> {
+            (0..1 + rng.next_u64() % 8)
+                .map(|_| order(50 + rng.next_u64() % 100, 1 + rng.next_u64() % 1000))
+                .collect()
+        };
+        for _ in 0..200 {
+            let bids = random_orders(&mut rng);
+            let asks = random_orders(&mut rng);
+            let clearing = match clear(&bids, &asks).unwrap() {
+                Some(clearing) => clearing,
+                None => continue,
+            };
+            let bid_total: BigInteger = clearing.bid_fills.iter().sum();
+            let ask_total: BigInteger = clearing.ask_fills.iter().sum();
+            assert_eq!(bid_total, clearing.volume);
+            assert_eq!(ask_total, clearing.volume);
+            for (fill, (price, amount)) in clearing.bid_fills.iter().zip(bids.iter()) {
+                assert!(fill <= amount);
+                assert!(fill.is_zero() || *price >= clearing.price);
+            }
+            for (fill, (price, amount)) in clearing.ask_fills.iter().zip(asks.iter()) {
+                assert!(fill <= amount);
+                assert!(fill.is_zero() || *price <= clearing.price);
+            }
+        }
+    }
+}
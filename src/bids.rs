@@ -0,0 +1,287 @@
+use crate::asset::Asset;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::rounding::{mul_div, RoundingMode};
+use crate::serializable_denom::SerializableDenom;
+use crate::serializable_map::SerializableMap;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, CosmosMsg, Timestamp};
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum MinIncrement {
+    Absolute(BigInteger),
+    Bps(u64),
+}
+
+impl MinIncrement {
+    // Smallest bid that beats `top`. A new bid always has to be strictly higher, and a bps
+    // increment is rounded up.
+    pub fn min_next_bid(&self, top: BigInteger) -> Result<BigInteger, CommonError> {
+        let increment = match self {
+            MinIncrement::Absolute(amount) => *amount,
+            MinIncrement::Bps(bps) => mul_div(
+                top,
+                BigInteger::from(*bps),
+                BigInteger::from(10_000u64),
+                RoundingMode::Ceil,
+            )?,
+        };
+        Ok(top.checked_add(increment.max(BigInteger::one()))?)
+    }
+}
+
+#[cw_serde]
+pub struct Bid {
+    pub bidder: Addr,
+    pub amount: BigInteger,
+}
+
+#[cw_serde]
+pub struct BidOutcome {
+    // refund for the bidder who was outbid
+    pub refund: Option<CosmosMsg>,
+    pub extended: bool,
+}
+
+// English auction escrow. A bidder's escrow is the sum of what they sent, so raising a bid only
+// needs the difference, and whoever is outbid is refunded in full. A bid landing within
+// `extension_seconds` of `end` pushes `end` to that far after the bid, which stops sniping.
+#[cw_serde]
+pub struct BidBook {
+    pub denom: SerializableDenom,
+    pub reserve: BigInteger,
+    pub min_increment: MinIncrement,
+    pub end: Timestamp,
+    pub extension_seconds: u64,
+    pub top: Option<Bid>,
+    pub escrow: SerializableMap<Addr, BigInteger>,
+}
+
+impl BidBook {
+    pub fn new(
+        denom: SerializableDenom,
+        reserve: BigInteger,
+        min_increment: MinIncrement,
+        end: Timestamp,
+        extension_seconds: u64,
+    ) -> Self {
+        Self {
+            denom,
+            reserve,
+            min_increment,
+            end,
+            extension_seconds,
+            top: None,
+            escrow: SerializableMap::new(),
+        }
+    }
+
+    pub fn is_ended(&self, now: Timestamp) -> bool {
+        now >= self.end
+    }
+
+    pub fn escrowed(&self, bidder: &Addr) -> BigInteger {
+        self.escrow.get(bidder).copied().unwrap_or_default()
+    }
+
+    pub fn min_next_bid(&self) -> Result<BigInteger, CommonError> {
+        match &self.top {
+            Some(top) => self.min_increment.min_next_bid(top.amount),
+            None => Ok(self.reserve),
+        }
+    }
+
+    pub fn place_bid(
+        &mut self,
+        bidder: &Addr,
+        funds: &Asset,
+        now: Timestamp,
+    ) -> Result<BidOutcome, CommonError> {
+        if self.is_ended(now) {
+            return Err(CommonError::AuctionEnded);
+        }
+        if funds.denom != self.denom {
+            return Err(CommonError::InvalidBid(format!(
+                "expected {}, got {}",
+                self.denom, funds.denom
+            )));
+        }
+        let amount = self.escrowed(bidder).checked_add(funds.amount)?;
+        let required = self.min_next_bid()?;
+        if amount < required {
+            return Err(CommonError::InvalidBid(format!(
+                "bid {} is below the minimum {}",
+                amount, required
+            )));
+        }
+
+        // the refund message is built before any state changes, so a failure leaves the book as is
+        let outbid = self
+            .top
+            .as_ref()
+            .map(|top| top.bidder.clone())
+            .filter(|previous| previous != bidder);
+        let refund = outbid
+            .as_ref()
+            .map(|previous| {
+                Asset::new(self.denom.clone(), self.escrowed(previous)).transfer_msg(previous)
+            })
+            .transpose()?;
+        if let Some(previous) = &outbid {
+            self.escrow.delete(previous);
+        }
+        self.escrow.set(bidder.clone(), amount);
+        self.top = Some(Bid {
+            bidder: bidder.clone(),
+            amount,
+        });
+
+        let extended_end = now.plus_seconds(self.extension_seconds);
+        let extended = extended_end > self.end;
+        if extended {
+            self.end = extended_end;
+        }
+        Ok(BidOutcome { refund, extended })
+    }
+
+    // Closes the auction and returns the winning bid, whose escrow now belongs to the seller.
+    pub fn settle(&mut self, now: Timestamp) -> Result<Option<Bid>, CommonError> {
+        if !self.is_ended(now) {
+            return Err(CommonError::AuctionNotEnded);
+        }
+        let top = self.top.take();
+        if let Some(bid) = &top {
+            self.escrow.delete(&bid.bidder);
+        }
+        Ok(top)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::asset::Asset;
+    use crate::bids::{BidBook, MinIncrement};
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::serializable_denom::SerializableDenom;
+    use cosmwasm_std::{coins, Addr, BankMsg, CosmosMsg, Timestamp};
+
+    fn book() -> BidBook {
+        BidBook::new(
+            SerializableDenom::Native("uusd".to_string()),
+            BigInteger::from(100u64),
+            MinIncrement::Bps(500),
+            Timestamp::from_seconds(1000),
+            60,
+        )
+    }
+
+    fn usd(amount: u64) -> Asset {
+        Asset::native("uusd", amount)
+    }
+
+    #[test]
+    fn test_min_increment() {
+        let top = BigInteger::from(101u64);
+        assert_eq!(
+            MinIncrement::Bps(500).min_next_bid(top).unwrap(),
+            BigInteger::from(107u64)
+        );
+        assert_eq!(
+            MinIncrement::Absolute(BigInteger::from(10u64))
+                .min_next_bid(top)
+                .unwrap(),
+            BigInteger::from(111u64)
+        );
+        assert_eq!(
+            MinIncrement::Absolute(BigInteger::zero())
+                .min_next_bid(top)
+                .unwrap(),
+            BigInteger::from(102u64)
+        );
+    }
+
+    #[test]
+    fn test_outbid_refund() {
+        let mut book = book();
+        let alice = Addr::unchecked("alice");
+        let bob = Addr::unchecked("bob");
+        let now = Timestamp::from_seconds(100);
+
+        assert!(matches!(
+            book.place_bid(&alice, &usd(99), now),
+            Err(CommonError::InvalidBid(_))
+        ));
+        assert!(matches!(
+            book.place_bid(&alice, &Asset::native("uatom", 200u64), now),
+            Err(CommonError::InvalidBid(_))
+        ));
+        let outcome = book.place_bid(&alice, &usd(100), now).unwrap();
+        assert_eq!(outcome.refund, None);
+
+        assert!(book.place_bid(&bob, &usd(104), now).is_err());
+        let outcome = book.place_bid(&bob, &usd(105), now).unwrap();
+        assert_eq!(
+            outcome.refund,
+            Some(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "alice".to_string(),
+                amount: coins(100, "uusd"),
+            }))
+        );
+        assert!(book.escrowed(&alice).is_zero());
+
+        // raising your own bid only takes the difference and refunds nothing
+        let outcome = book.place_bid(&bob, &usd(6), now).unwrap();
+        assert_eq!(outcome.refund, None);
+        assert_eq!(book.escrowed(&bob), BigInteger::from(111u64));
+        assert_eq!(book.top.clone().unwrap().amount, BigInteger::from(111u64));
+    }
+
+    #[test]
+    fn test_failed_refund_keeps_state() {
+        let mut book = book();
+        let alice = Addr::unchecked("alice");
+        let bob = Addr::unchecked("bob");
+        let now = Timestamp::from_seconds(100);
+
+        // a refund above Uint128::MAX cannot be sent as a bank message
+        let huge = BigInteger::from(u128::MAX) + BigInteger::one();
+        book.place_bid(&alice, &Asset::native("uusd", huge), now)
+            .unwrap();
+        let bid = Asset::native("uusd", huge + huge);
+        assert!(book.place_bid(&bob, &bid, now).is_err());
+        assert_eq!(book.escrowed(&alice), huge);
+        assert!(book.escrowed(&bob).is_zero());
+        assert_eq!(book.top.clone().unwrap().bidder, alice);
+    }
+
+    #[test]
+    fn test_anti_sniping_and_settle() {
+        let mut book = book();
+        let alice = Addr::unchecked("alice");
+        let bob = Addr::unchecked("bob");
+
+        let outcome = book
+            .place_bid(&alice, &usd(100), Timestamp::from_seconds(900))
+            .unwrap();
+        assert!(!outcome.extended);
+        let outcome = book
+            .place_bid(&bob, &usd(200), Timestamp::from_seconds(990))
+            .unwrap();
+        assert!(outcome.extended);
+        assert_eq!(book.end, Timestamp::from_seconds(1050));
+
+        assert_eq!(
+            book.settle(Timestamp::from_seconds(1000)),
+            Err(CommonError::AuctionNotEnded)
+        );
+        assert_eq!(
+            book.place_bid(&alice, &usd(300), Timestamp::from_seconds(1050)),
+            Err(CommonError::AuctionEnded)
+        );
+        let winner = book.settle(Timestamp::from_seconds(1050)).unwrap().unwrap();
+        assert_eq!(winner.bidder, bob);
+        assert!(book.escrow.is_empty());
+    }
+}
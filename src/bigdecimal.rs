@@ -1,13 +1,21 @@
 use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::math::u256::pow10;
+use crate::repr::numeric_string_schema;
+use crate::rounding::{mul_div, RoundingMode};
+use crate::signed_bigdecimal::SignedBigDecimal;
 use core::fmt::{Display, Formatter};
 use core::str::FromStr;
-use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Decimal256, StdError, Uint128, Uint256};
-use std::iter::Sum;
-use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
-
-#[cw_serde]
-#[derive(Copy, Default, Ord, PartialOrd, Eq)]
+use cosmwasm_schema::serde::{Deserialize, Serialize};
+use cosmwasm_std::{Decimal, Decimal256, StdError, StdResult, Uint128, Uint256, Uint512};
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Copy, Default, Ord, PartialOrd, Eq)]
+#[serde(crate = "cosmwasm_schema::serde")]
 pub struct BigDecimal(pub Decimal256);
 
 impl BigDecimal {
@@ -22,10 +30,23 @@ impl BigDecimal {
     pub fn from(bigint: BigInteger, decimals: u32) -> Self {
         Self(Decimal256::from_ratio(
             bigint.0,
-            Uint128::from(10u64).pow(decimals),
+            pow10(decimals),
         ))
     }
 
+    pub fn checked_from(bigint: BigInteger, decimals: u32) -> Result<Self, CommonError> {
+        if decimals > BigInteger::MAX_SCALE_DOWN_DECIMALS {
+            return Err(CommonError::InvalidDecimals {
+                decimals,
+                max: BigInteger::MAX_SCALE_DOWN_DECIMALS,
+            });
+        }
+        Ok(Self(Decimal256::checked_from_ratio(
+            bigint.0,
+            pow10(decimals),
+        )?))
+    }
+
     pub fn is_zero(&self) -> bool {
         self.0.is_zero()
     }
@@ -34,6 +55,23 @@ impl BigDecimal {
         Self(Decimal256::percent(x))
     }
 
+    pub fn permille(x: u64) -> BigDecimal {
+        Self(Decimal256::permille(x))
+    }
+
+    pub fn bps(x: u64) -> BigDecimal {
+        Self(Decimal256::bps(x))
+    }
+
+    pub fn to_bps(&self, mode: RoundingMode) -> Result<BigInteger, CommonError> {
+        mul_div(
+            BigInteger(self.0.atomics()),
+            BigInteger::from(10_000u64),
+            BigInteger(Decimal256::one().atomics()),
+            mode,
+        )
+    }
+
     pub fn zero() -> Self {
         Self(Decimal256::zero())
     }
@@ -46,20 +84,63 @@ impl BigDecimal {
         Self(Decimal256::from_ratio(numerator, denominator))
     }
 
+    // Fractional digits left once trailing zeros are dropped, e.g. 2 for 1.2300.
+    pub fn significant_fraction_digits(&self) -> u32 {
+        let mut fraction = self.0.atomics() % pow10(Decimal256::DECIMAL_PLACES);
+        if fraction.is_zero() {
+            return 0;
+        }
+        let ten = Uint256::from(10u8);
+        let mut digits = Decimal256::DECIMAL_PLACES;
+        while (fraction % ten).is_zero() {
+            fraction /= ten;
+            digits -= 1;
+        }
+        digits
+    }
+
+    // Shortest decimal string for the value: the integer part, then the fraction up to its last
+    // non-zero digit. Built from the atomics, so comparisons and event attributes using it do not
+    // depend on how `Display` formats.
+    pub fn normalize(&self) -> String {
+        let scale = pow10(Decimal256::DECIMAL_PLACES);
+        let integer = self.0.atomics() / scale;
+        let digits = self.significant_fraction_digits();
+        if digits == 0 {
+            return integer.to_string();
+        }
+        let fraction = (self.0.atomics() % scale) / pow10(Decimal256::DECIMAL_PLACES - digits);
+        format!(
+            "{}.{:0>width$}",
+            integer,
+            fraction.to_string(),
+            width = digits as usize
+        )
+    }
+
+    pub fn to_integer_checked(&self, mode: RoundingMode) -> Result<BigInteger, CommonError> {
+        BigInteger::try_from_decimal(*self, mode)
+    }
+
     pub fn saturating_sub(&self, rhs: Self) -> Self {
         Self(Decimal256::saturating_sub(self.0, rhs.0))
     }
 
     pub fn scale_up(&self, decimals: u32) -> BigInteger {
-        BigInteger((self.0 * Decimal256::from_ratio(10u128.pow(decimals), 1u128)).to_uint_floor())
+        let atomics = self.0.atomics();
+        if decimals <= Decimal256::DECIMAL_PLACES {
+            BigInteger(atomics / pow10(Decimal256::DECIMAL_PLACES - decimals))
+        } else {
+            BigInteger(atomics * pow10(decimals - Decimal256::DECIMAL_PLACES))
+        }
     }
 
     pub fn move_point_right(&self, decimals: u32) -> BigDecimal {
-        *self * BigDecimal::from_ratio(10u128.pow(decimals), 1u128)
+        Self(Decimal256::new(self.0.atomics() * pow10(decimals)))
     }
 
     pub fn move_point_left(&self, decimals: u32) -> BigDecimal {
-        *self / BigDecimal::from_ratio(10u128.pow(decimals), 1u128)
+        Self(Decimal256::new(self.0.atomics() / pow10(decimals)))
     }
 
     pub fn is_ratio(&self) -> bool {
@@ -81,6 +162,36 @@ impl BigDecimal {
     pub fn to_le_bytes(&self) -> [u8; 32] {
         self.0.atomics().to_le_bytes()
     }
+
+    pub fn try_to_decimal(&self) -> StdResult<Decimal> {
+        Decimal::try_from(self.0).map_err(|err| StdError::generic_err(err.to_string()))
+    }
+
+    // Relative change from `from` to `to` as a fraction, e.g. -0.25 for a 25% drop.
+    pub fn percent_change(from: BigDecimal, to: BigDecimal) -> Result<SignedBigDecimal, CommonError> {
+        let from = SignedBigDecimal::try_from(from)?;
+        let to = SignedBigDecimal::try_from(to)?;
+        to.checked_sub(from)?.checked_div(from)
+    }
+
+    pub fn try_sum(iter: impl IntoIterator<Item = BigDecimal>) -> Result<Self, CommonError> {
+        iter.into_iter()
+            .try_fold(Self::zero(), |acc, value| Ok(Self(acc.0.checked_add(value.0)?)))
+    }
+
+    // Accumulates atomics in 512 bits so that only the final total has to fit.
+    pub fn sum_exact(iter: impl IntoIterator<Item = BigDecimal>) -> Result<Self, CommonError> {
+        let total = iter
+            .into_iter()
+            .fold(Uint512::zero(), |acc, value| acc + Uint512::from(value.0.atomics()));
+        Ok(Self(Decimal256::new(Uint256::try_from(total)?)))
+    }
+}
+
+impl From<Decimal> for BigDecimal {
+    fn from(value: Decimal) -> Self {
+        Self(Decimal256::from(value))
+    }
 }
 
 impl Sub<BigDecimal> for BigDecimal {
@@ -131,6 +242,38 @@ impl Mul<BigInteger> for BigDecimal {
     }
 }
 
+impl Mul<Uint128> for BigDecimal {
+    type Output = BigDecimal;
+
+    fn mul(self, rhs: Uint128) -> Self::Output {
+        self * BigInteger::from(rhs)
+    }
+}
+
+impl Div<Uint128> for BigDecimal {
+    type Output = BigDecimal;
+
+    fn div(self, rhs: Uint128) -> Self::Output {
+        self / BigInteger::from(rhs)
+    }
+}
+
+impl Mul<Decimal> for BigDecimal {
+    type Output = BigDecimal;
+
+    fn mul(self, rhs: Decimal) -> Self::Output {
+        self * Self(Decimal256::from(rhs))
+    }
+}
+
+impl Div<Decimal> for BigDecimal {
+    type Output = BigDecimal;
+
+    fn div(self, rhs: Decimal) -> Self::Output {
+        self / Self(Decimal256::from(rhs))
+    }
+}
+
 impl AddAssign for BigDecimal {
     fn add_assign(&mut self, rhs: Self) {
         self.0 += rhs.0;
@@ -149,57 +292,110 @@ impl MulAssign for BigDecimal {
     }
 }
 
+impl MulAssign<BigInteger> for BigDecimal {
+    fn mul_assign(&mut self, rhs: BigInteger) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for BigDecimal {
+    fn div_assign(&mut self, rhs: Self) {
+        self.0 /= rhs.0;
+    }
+}
+
+impl DivAssign<BigInteger> for BigDecimal {
+    fn div_assign(&mut self, rhs: BigInteger) {
+        *self = *self / rhs;
+    }
+}
+
+forward_ref_binop!(impl Add, add for BigDecimal, BigDecimal);
+forward_ref_binop!(impl Sub, sub for BigDecimal, BigDecimal);
+forward_ref_binop!(impl Mul, mul for BigDecimal, BigDecimal);
+forward_ref_binop!(impl Div, div for BigDecimal, BigDecimal);
+forward_ref_binop!(impl Mul, mul for BigDecimal, BigInteger);
+forward_ref_binop!(impl Div, div for BigDecimal, BigInteger);
+forward_ref_op_assign!(impl AddAssign, add_assign for BigDecimal, BigDecimal);
+forward_ref_op_assign!(impl SubAssign, sub_assign for BigDecimal, BigDecimal);
+forward_ref_op_assign!(impl MulAssign, mul_assign for BigDecimal, BigDecimal);
+forward_ref_op_assign!(impl DivAssign, div_assign for BigDecimal, BigDecimal);
+forward_ref_op_assign!(impl MulAssign, mul_assign for BigDecimal, BigInteger);
+forward_ref_op_assign!(impl DivAssign, div_assign for BigDecimal, BigInteger);
+
 impl Display for BigDecimal {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        std::fmt::Display::fmt(&self.0, f)
+        core::fmt::Display::fmt(&self.0, f)
     }
 }
 
-impl FromStr for BigDecimal {
-    type Err = StdError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        const DECIMAL_FRACTIONAL: Uint256 = // 1*10**18
-            Uint256::from_u128(1_000_000_000_000_000_000);
-        let mut parts_iter = s.split('.');
-
-        let whole_part = parts_iter.next().unwrap(); // split always returns at least one element
-        let whole = whole_part
-            .parse::<Uint256>()
-            .map_err(|_| StdError::generic_err("Error parsing whole"))?;
-        let mut atomics = whole
-            .checked_mul(DECIMAL_FRACTIONAL)
-            .map_err(|_| StdError::generic_err("Value too big"))?;
-
-        if let Some(fractional_part) = parts_iter.next() {
-            let fractional = fractional_part
-                .parse::<Uint256>()
-                .map_err(|_| StdError::generic_err("Error parsing fractional"))?;
-            let exp = (Decimal256::DECIMAL_PLACES.checked_sub(fractional_part.len() as u32))
-                .ok_or_else(|| {
-                    StdError::generic_err(format!(
-                        "Cannot parse more than {} fractional digits",
-                        Decimal256::DECIMAL_PLACES
-                    ))
-                })?;
-            let fractional_factor = Uint256::from(10u128).pow(exp);
-            atomics = atomics
-                .checked_add(
-                    // The inner multiplication can't overflow because
-                    // fractional < 10^DECIMAL_PLACES && fractional_factor <= 10^DECIMAL_PLACES
-                    fractional.checked_mul(fractional_factor).unwrap(),
-                )
-                .map_err(|_| StdError::generic_err("Value too big"))?;
+impl BigDecimal {
+    // Same as `from_str` with the precise parse error. Single pass over the bytes, digits are
+    // gathered in u64 chunks and only folded into the 256-bit value once per chunk. Nothing is
+    // allocated, not even on error.
+    pub fn parse_exact(s: &str) -> Result<Self, CommonError> {
+        const CHUNK_DIGITS: u32 = 19;
+        let bytes = s.as_bytes();
+        if bytes.is_empty() {
+            return Err(CommonError::EmptyInput);
         }
 
-        if parts_iter.next().is_some() {
-            return Err(StdError::generic_err("Unexpected number of dots"));
+        let mut atomics = Uint256::zero();
+        let mut chunk = 0u64;
+        let mut chunk_digits = 0u32;
+        let mut fraction_digits: Option<u32> = None;
+        for (position, byte) in bytes.iter().enumerate() {
+            match byte {
+                b'0'..=b'9' => {
+                    if let Some(digits) = fraction_digits.as_mut() {
+                        if *digits == Decimal256::DECIMAL_PLACES {
+                            return Err(CommonError::TooManyFractionDigits {
+                                max: Decimal256::DECIMAL_PLACES,
+                            });
+                        }
+                        *digits += 1;
+                    }
+                    chunk = chunk * 10 + (byte - b'0') as u64;
+                    chunk_digits += 1;
+                    if chunk_digits == CHUNK_DIGITS {
+                        atomics = push_chunk(atomics, chunk, chunk_digits)?;
+                        chunk = 0;
+                        chunk_digits = 0;
+                    }
+                }
+                // a dot needs digits on both sides
+                b'.' if fraction_digits.is_none() && position > 0 && position + 1 < bytes.len() => {
+                    fraction_digits = Some(0);
+                }
+                _ => {
+                    // every byte before `position` is ascii, so it is a char boundary
+                    let character = s[position..].chars().next().unwrap_or_default();
+                    return Err(CommonError::InvalidChar {
+                        position,
+                        character,
+                    });
+                }
+            }
         }
+        atomics = push_chunk(atomics, chunk, chunk_digits)?;
+        let scale = Decimal256::DECIMAL_PLACES - fraction_digits.unwrap_or(0);
+        Ok(Self(Decimal256::new(atomics.checked_mul(pow10(scale))?)))
+    }
+}
+
+impl FromStr for BigDecimal {
+    type Err = StdError;
 
-        Ok(Self(Decimal256::new(atomics)))
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_exact(s).map_err(Into::into)
     }
 }
 
+fn push_chunk(atomics: Uint256, chunk: u64, digits: u32) -> Result<Uint256, CommonError> {
+    Ok(atomics
+        .checked_mul(pow10(digits))?
+        .checked_add(Uint256::from(chunk))?)
+}
 
 impl Sum for BigDecimal {
     fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
@@ -213,11 +409,194 @@ impl <'a> Sum<&'a BigDecimal> for BigDecimal {
     }
 }
 
+impl JsonSchema for BigDecimal {
+    fn schema_name() -> String {
+        "BigDecimal".to_string()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        numeric_string_schema(
+            r"^[0-9]+(\.[0-9]{1,18})?$",
+            "A fixed-point decimal with 18 fractional digits encoded as a string",
+            &["0", "1.5", "0.000000000000000001"],
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::bigdecimal::BigDecimal;
     use crate::biginteger::BigInteger;
-    use cosmwasm_std::Uint256;
+    use crate::error::CommonError;
+    use crate::rounding::RoundingMode;
+    use core::str::FromStr;
+    use crate::signed_bigdecimal::SignedBigDecimal;
+    use cosmwasm_std::{Decimal, Decimal256, StdError, Uint128, Uint256};
+
+    #[test]
+    fn test_significant_fraction_digits() {
+        let digits = |s: &str| BigDecimal::from_str(s).unwrap().significant_fraction_digits();
+        assert_eq!(digits("0"), 0);
+        assert_eq!(digits("12.000"), 0);
+        assert_eq!(digits("1.2300"), 2);
+        assert_eq!(digits("0.000000000000000001"), 18);
+        assert_eq!(digits("100.1"), 1);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let d = BigDecimal::from_str("001.2300").unwrap();
+        assert_eq!(d.normalize(), "1.23");
+        assert_eq!(d.normalize(), d.to_string());
+        assert_eq!(BigDecimal::from_str("5.000").unwrap().normalize(), "5");
+        assert_eq!(BigDecimal::zero().normalize(), "0");
+
+        // the fraction keeps its leading zeros and ends at the last non-zero digit
+        let atomics = |n: u128| BigDecimal(Decimal256::new(Uint256::from(n)));
+        assert_eq!(atomics(1).normalize(), "0.000000000000000001");
+        assert_eq!(atomics(1_050_000_000_000_000_000).normalize(), "1.05");
+        assert_eq!(atomics(100_000_000_000_000_000_000).normalize(), "100");
+        assert_eq!(
+            BigDecimal::from_str("0.10").unwrap().normalize(),
+            BigDecimal::percent(10).normalize()
+        );
+        for d in [atomics(7_000_000_000_000_000), BigDecimal::MAX] {
+            assert_eq!(BigDecimal::from_str(&d.normalize()).unwrap(), d);
+        }
+    }
+
+    #[test]
+    fn test_assign_ops() {
+        let mut d = BigDecimal::percent(300);
+        d /= BigDecimal::percent(200);
+        assert_eq!(d, BigDecimal::percent(150));
+        d *= BigInteger::from(4u64);
+        assert_eq!(d, BigDecimal::percent(600));
+        d /= BigInteger::from(3u64);
+        assert_eq!(d, BigDecimal::percent(200));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_ref_ops() {
+        let a = BigDecimal::percent(150);
+        let b = BigDecimal::percent(50);
+        assert_eq!(&a + &b, BigDecimal::percent(200));
+        assert_eq!(a - &b, BigDecimal::one());
+        assert_eq!(&a * b, BigDecimal::percent(75));
+        assert_eq!(&a / &b, BigDecimal::percent(300));
+        assert_eq!(&a * &BigInteger::from(2u64), BigDecimal::percent(300));
+
+        let mut c = a;
+        c -= &b;
+        c /= &BigInteger::from(2u64);
+        assert_eq!(c, BigDecimal::percent(50));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(BigDecimal::from_str("0").unwrap(), BigDecimal::zero());
+        assert_eq!(BigDecimal::from_str("1.5").unwrap(), BigDecimal::percent(150));
+        assert_eq!(BigDecimal::from_str("007.0100").unwrap(), BigDecimal::percent(701));
+        assert_eq!(
+            BigDecimal::from_str("0.000000000000000001").unwrap(),
+            BigDecimal(Decimal256::new(Uint256::one()))
+        );
+        assert_eq!(
+            BigDecimal::from_str(&BigDecimal::MAX.to_string()).unwrap(),
+            BigDecimal::MAX
+        );
+        let long = "12345678901234567890123456789012345678.123456789012345678";
+        assert_eq!(BigDecimal::from_str(long).unwrap().to_string(), long);
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        assert_eq!(BigDecimal::parse_exact(""), Err(CommonError::EmptyInput));
+        assert_eq!(
+            BigDecimal::parse_exact("0.0000000000000000001"),
+            Err(CommonError::TooManyFractionDigits { max: 18 })
+        );
+        for (input, position, character) in [
+            ("1.2.3", 3, '.'),
+            (".5", 0, '.'),
+            ("5.", 1, '.'),
+            ("-1", 0, '-'),
+            ("1e5", 1, 'e'),
+            ("10€", 2, '€'),
+        ] {
+            assert_eq!(
+                BigDecimal::parse_exact(input),
+                Err(CommonError::InvalidChar { position, character })
+            );
+        }
+        let too_big = format!("{}0", BigDecimal::MAX.to_string().split('.').next().unwrap());
+        assert!(matches!(
+            BigDecimal::parse_exact(&too_big),
+            Err(CommonError::Overflow(_))
+        ));
+
+        // `FromStr` keeps `StdError` for `StdResult` callers, with the same message
+        let err = BigDecimal::from_str("1e5").unwrap_err();
+        assert!(matches!(err, StdError::GenericErr { .. }));
+        assert_eq!(
+            err.to_string(),
+            format!("Generic error: {}", BigDecimal::parse_exact("1e5").unwrap_err())
+        );
+    }
+
+    #[test]
+    fn test_bps() {
+        assert_eq!(BigDecimal::permille(15), BigDecimal::from_str("0.015").unwrap());
+        assert_eq!(BigDecimal::bps(30), BigDecimal::from_str("0.003").unwrap());
+        assert_eq!(
+            BigDecimal::bps(30).to_bps(RoundingMode::Floor).unwrap(),
+            BigInteger::from(30u64)
+        );
+
+        let fee = BigDecimal::from_str("0.00305").unwrap();
+        assert_eq!(fee.to_bps(RoundingMode::Floor).unwrap(), BigInteger::from(30u64));
+        assert_eq!(fee.to_bps(RoundingMode::Ceil).unwrap(), BigInteger::from(31u64));
+    }
+
+    #[test]
+    fn test_percent_change() {
+        assert_eq!(
+            BigDecimal::percent_change(BigDecimal::percent(200), BigDecimal::percent(300)).unwrap(),
+            SignedBigDecimal::percent(50)
+        );
+        assert_eq!(
+            BigDecimal::percent_change(BigDecimal::percent(200), BigDecimal::percent(150)).unwrap(),
+            SignedBigDecimal::percent(-25)
+        );
+        assert!(BigDecimal::percent_change(BigDecimal::zero(), BigDecimal::one()).is_err());
+    }
+
+    #[test]
+    fn test_try_sum() {
+        let values = vec![BigDecimal::percent(1); 1000];
+        assert_eq!(BigDecimal::try_sum(values.clone()).unwrap(), BigDecimal::percent(1000));
+        assert_eq!(BigDecimal::sum_exact(values).unwrap(), BigDecimal::percent(1000));
+        assert_eq!(BigDecimal::try_sum(vec![]).unwrap(), BigDecimal::zero());
+
+        let values = vec![BigDecimal::MAX, BigDecimal::one()];
+        assert!(BigDecimal::try_sum(values.clone()).is_err());
+        assert!(BigDecimal::sum_exact(values).is_err());
+    }
+
+    #[test]
+    fn test_decimal_interop() {
+        let decimal = Decimal::percent(150);
+        let bigdecimal: BigDecimal = decimal.into();
+        assert_eq!(bigdecimal, BigDecimal::percent(150));
+        assert_eq!(bigdecimal.try_to_decimal().unwrap(), decimal);
+        assert!(BigDecimal::MAX.try_to_decimal().is_err());
+
+        assert_eq!(bigdecimal * Uint128::new(2), BigDecimal::percent(300));
+        assert_eq!(bigdecimal / Uint128::new(3), BigDecimal::percent(50));
+        assert_eq!(bigdecimal * Decimal::percent(200), BigDecimal::percent(300));
+        assert_eq!(bigdecimal / Decimal::percent(50), BigDecimal::percent(300));
+    }
 
     #[test]
     fn test_bytes() {
@@ -1,19 +1,30 @@
 use crate::bigdecimal::BigDecimal;
+use crate::error::CommonError;
+use crate::math::u256::{isqrt, pow10};
+use crate::repr::numeric_string_schema;
+use crate::rounding::{mul_div, RoundingMode};
 use core::fmt::{Display, Formatter};
 use core::str::FromStr;
-use std::iter::Sum;
-use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{StdError, StdResult, Uint128, Uint256};
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
-
-#[cw_serde]
-#[derive(Copy, Default, Ord, PartialOrd, Eq)]
+use core::iter::Sum;
+use cosmwasm_schema::serde::{Deserialize, Serialize};
+use cosmwasm_std::{Decimal256, StdError, StdResult, Uint128, Uint256, Uint512, Uint64};
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Copy, Default, Ord, PartialOrd, Eq)]
+#[serde(crate = "cosmwasm_schema::serde")]
 pub struct BigInteger(pub Uint256);
 
 impl BigInteger {
 
     pub const MAX: Self = Self(Uint256::MAX);
     pub const MIN: Self = Self(Uint256::MIN);
+    pub const MAX_SCALE_UP_DECIMALS: u32 = 77;
+    pub const MAX_SCALE_DOWN_DECIMALS: u32 = 38;
+    // digits of `MAX`
+    pub const SORTABLE_WIDTH: usize = 78;
 
     pub const fn new(value: u128) -> Self {
         Self(Uint256::from_u128(value))
@@ -24,13 +35,49 @@ impl BigInteger {
     }
 
     pub fn scale_up(&self, decimals: u32) -> Self {
-        Self(self.0 * Uint256::from(10u64).pow(decimals))
+        Self(self.0 * pow10(decimals))
+    }
+
+    pub fn checked_scale_down(&self, decimals: u32) -> Result<BigDecimal, CommonError> {
+        BigDecimal::checked_from(*self, decimals)
+    }
+
+    pub fn checked_scale_up(&self, decimals: u32) -> Result<Self, CommonError> {
+        if decimals > Self::MAX_SCALE_UP_DECIMALS {
+            return Err(CommonError::InvalidDecimals {
+                decimals,
+                max: Self::MAX_SCALE_UP_DECIMALS,
+            });
+        }
+        Ok(Self(self.0.checked_mul(pow10(decimals))?))
+    }
+
+    // Integer part of `value` under an explicit rounding mode, unlike `From<BigDecimal>`.
+    pub fn try_from_decimal(value: BigDecimal, mode: RoundingMode) -> Result<Self, CommonError> {
+        mul_div(
+            Self(value.0.atomics()),
+            Self::one(),
+            Self(Decimal256::one().atomics()),
+            mode,
+        )
     }
 
     pub fn to_uint128(&self) -> StdResult<Uint128> {
         Ok(Uint128::try_from(self.0)?)
     }
 
+    pub fn to_uint128_saturating(&self) -> Uint128 {
+        Uint128::try_from(self.0).unwrap_or(Uint128::MAX)
+    }
+
+    pub fn to_uint64(&self) -> StdResult<Uint64> {
+        Ok(Uint64::try_from(self.0)?)
+    }
+
+    pub fn to_u64_saturating(&self) -> u64 {
+        Uint64::try_from(self.0).map_or(u64::MAX, |value| value.u64())
+    }
+
     pub fn to_uint256(&self) -> Uint256 {
         self.0
     }
@@ -55,14 +102,46 @@ impl BigInteger {
         Self(self.0.saturating_sub(rhs.0))
     }
 
+    pub fn checked_add(&self, rhs: Self) -> StdResult<Self> {
+        Ok(Self(self.0.checked_add(rhs.0)?))
+    }
+
     pub fn checked_sub(&self, rhs: Self) -> StdResult<Self> {
         Ok(Self(self.0.checked_sub(rhs.0)?))
     }
 
+    pub fn checked_mul(&self, rhs: Self) -> StdResult<Self> {
+        Ok(Self(self.0.checked_mul(rhs.0)?))
+    }
+
+    pub fn checked_div(&self, rhs: Self) -> StdResult<Self> {
+        Ok(Self(self.0.checked_div(rhs.0)?))
+    }
+
+    pub fn div_floor(&self, rhs: Self) -> Result<Self, CommonError> {
+        self.div_round(rhs, RoundingMode::Floor)
+    }
+
+    pub fn div_ceil(&self, rhs: Self) -> Result<Self, CommonError> {
+        self.div_round(rhs, RoundingMode::Ceil)
+    }
+
+    pub fn div_round(&self, rhs: Self, mode: RoundingMode) -> Result<Self, CommonError> {
+        mul_div(*self, Self::one(), rhs, mode)
+    }
+
+    pub fn ratio_of(&self, total: Self) -> Result<BigDecimal, CommonError> {
+        Ok(BigDecimal(Decimal256::checked_from_ratio(self.0, total.0)?))
+    }
+
     pub fn pow(&self, exp: u32) -> Self {
         Self(self.0.pow(exp))
     }
 
+    pub fn isqrt(&self) -> Self {
+        Self(isqrt(self.0))
+    }
+
     pub fn from_be_bytes(bytes: [u8; 32]) -> Self {
         Self(Uint256::from_be_bytes(bytes))
     }
@@ -78,6 +157,42 @@ impl BigInteger {
     pub fn to_le_bytes(&self) -> [u8; 32] {
         self.0.to_le_bytes()
     }
+
+    // Up to 32 big-endian bytes, left-padded with zeros.
+    pub fn from_be_slice(bytes: &[u8]) -> StdResult<Self> {
+        if bytes.len() > 32 {
+            return Err(StdError::generic_err(format!(
+                "Cannot read {} bytes into a 256-bit integer",
+                bytes.len()
+            )));
+        }
+        let mut padded = [0u8; 32];
+        padded[32 - bytes.len()..].copy_from_slice(bytes);
+        Ok(Self::from_be_bytes(padded))
+    }
+
+    // Big-endian bytes without leading zeros; zero is the empty slice, as in RLP.
+    pub fn to_be_bytes_trimmed(&self) -> Vec<u8> {
+        let bytes = self.to_be_bytes();
+        let first = bytes.iter().position(|b| *b != 0).unwrap_or(32);
+        bytes[first..].to_vec()
+    }
+
+    // Zero-padded to the width of `MAX`, so string order matches numeric order.
+    pub fn to_sortable_string(&self) -> String {
+        format!("{:0>width$}", self.0.to_string(), width = Self::SORTABLE_WIDTH)
+    }
+
+    pub fn from_sortable_string(s: &str) -> StdResult<Self> {
+        if s.len() != Self::SORTABLE_WIDTH || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(StdError::generic_err(format!(
+                "Sortable integer must be {} digits: {}",
+                Self::SORTABLE_WIDTH,
+                s
+            )));
+        }
+        Self::from_str(s)
+    }
 }
 
 impl From<BigInteger> for String {
@@ -94,6 +209,8 @@ impl FromStr for BigInteger {
     }
 }
 
+// Deprecated: floors silently, use `BigInteger::try_from_decimal` or `BigDecimal::to_integer_checked`
+// to choose the rounding. Kept for compatibility, as trait impls cannot carry `#[deprecated]`.
 impl From<BigDecimal> for BigInteger {
     fn from(value: BigDecimal) -> Self {
         Self(value.0.to_uint_floor())
@@ -175,6 +292,14 @@ impl Div<BigDecimal> for BigInteger {
     }
 }
 
+impl Rem<BigInteger> for BigInteger {
+    type Output = BigInteger;
+
+    fn rem(self, rhs: BigInteger) -> Self::Output {
+        Self(self.0 % rhs.0)
+    }
+}
+
 impl Mul<BigInteger> for BigInteger {
     type Output = BigInteger;
 
@@ -191,6 +316,22 @@ impl Mul<BigDecimal> for BigInteger {
     }
 }
 
+impl Mul<Uint128> for BigInteger {
+    type Output = BigInteger;
+
+    fn mul(self, rhs: Uint128) -> Self::Output {
+        self * BigInteger::from(rhs)
+    }
+}
+
+impl Div<Uint128> for BigInteger {
+    type Output = BigInteger;
+
+    fn div(self, rhs: Uint128) -> Self::Output {
+        self / BigInteger::from(rhs)
+    }
+}
+
 impl AddAssign for BigInteger {
     fn add_assign(&mut self, rhs: Self) {
         self.0 += rhs.0;
@@ -215,6 +356,25 @@ impl DivAssign for BigInteger {
     }
 }
 
+impl RemAssign for BigInteger {
+    fn rem_assign(&mut self, rhs: Self) {
+        self.0 %= rhs.0;
+    }
+}
+
+forward_ref_binop!(impl Add, add for BigInteger, BigInteger);
+forward_ref_binop!(impl Sub, sub for BigInteger, BigInteger);
+forward_ref_binop!(impl Mul, mul for BigInteger, BigInteger);
+forward_ref_binop!(impl Div, div for BigInteger, BigInteger);
+forward_ref_binop!(impl Rem, rem for BigInteger, BigInteger);
+forward_ref_binop!(impl Mul, mul for BigInteger, BigDecimal);
+forward_ref_binop!(impl Div, div for BigInteger, BigDecimal);
+forward_ref_op_assign!(impl AddAssign, add_assign for BigInteger, BigInteger);
+forward_ref_op_assign!(impl SubAssign, sub_assign for BigInteger, BigInteger);
+forward_ref_op_assign!(impl MulAssign, mul_assign for BigInteger, BigInteger);
+forward_ref_op_assign!(impl DivAssign, div_assign for BigInteger, BigInteger);
+forward_ref_op_assign!(impl RemAssign, rem_assign for BigInteger, BigInteger);
+
 impl Display for BigInteger {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         self.0.fmt(f)
@@ -233,11 +393,38 @@ impl <'a> Sum<&'a BigInteger> for BigInteger {
     }
 }
 
+// Sum of amount * weight, floored. Products and the running sum are kept in 512 bits and only
+// the final result is narrowed, so large intermediate terms cannot overflow.
+pub fn weighted_sum(values: &[(BigInteger, BigDecimal)]) -> StdResult<BigInteger> {
+    let mut sum = Uint512::zero();
+    for (amount, weight) in values {
+        sum = sum.checked_add(amount.0.full_mul(weight.0.atomics()))?;
+    }
+    let quotient = sum / Uint512::from(pow10(Decimal256::DECIMAL_PLACES));
+    Ok(BigInteger(Uint256::try_from(quotient)?))
+}
+
+impl JsonSchema for BigInteger {
+    fn schema_name() -> String {
+        "BigInteger".to_string()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        numeric_string_schema(
+            "^[0-9]+$",
+            "An unsigned 256-bit integer encoded as a decimal string",
+            &["0", "1000000"],
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::bigdecimal::BigDecimal;
-    use crate::biginteger::BigInteger;
-    use cosmwasm_std::{Decimal256, Uint256};
+    use crate::biginteger::{weighted_sum, BigInteger};
+    use crate::rounding::RoundingMode;
+    use core::str::FromStr;
+    use cosmwasm_std::{Decimal256, Uint128, Uint256, Uint64};
 
     #[test]
     fn test_scale_down() {
@@ -246,6 +433,108 @@ mod tests {
         assert_eq!(bigdecimal, BigDecimal(Decimal256::one()));
     }
 
+    #[test]
+    fn test_weighted_sum() {
+        assert_eq!(weighted_sum(&[]).unwrap(), BigInteger::zero());
+        let values = [
+            (BigInteger::from(100u64), BigDecimal::percent(50)),
+            (BigInteger::from(3u64), BigDecimal::from_str("0.333333333333333333").unwrap()),
+            (BigInteger::from(1u64), BigDecimal::from_str("0.000000000000000001").unwrap()),
+        ];
+        // 50 + 0.999999999999999999 + 0.000000000000000001, only floored once at the end
+        assert_eq!(weighted_sum(&values).unwrap(), BigInteger::from(51u64));
+
+        // neither the products nor their sum fit in 256 bits, the weighted result does
+        let half = BigDecimal::percent(50);
+        assert_eq!(
+            weighted_sum(&[(BigInteger::MAX, half), (BigInteger::MAX, half)]).unwrap(),
+            BigInteger::MAX
+        );
+        assert!(weighted_sum(&[(BigInteger::MAX, BigDecimal::percent(200))]).is_err());
+    }
+
+    #[test]
+    fn test_be_slices() {
+        assert_eq!(
+            BigInteger::from_be_slice(&[0x01, 0x00]).unwrap(),
+            BigInteger::from(256u64)
+        );
+        assert_eq!(BigInteger::from_be_slice(&[]).unwrap(), BigInteger::zero());
+        assert_eq!(BigInteger::from_be_slice(&[0xff; 32]).unwrap(), BigInteger::MAX);
+        assert!(BigInteger::from_be_slice(&[0u8; 33]).is_err());
+
+        assert_eq!(BigInteger::from(256u64).to_be_bytes_trimmed(), vec![0x01, 0x00]);
+        assert!(BigInteger::zero().to_be_bytes_trimmed().is_empty());
+        assert_eq!(BigInteger::MAX.to_be_bytes_trimmed(), vec![0xff; 32]);
+        for value in [0u64, 1, 255, 65_536, u64::MAX] {
+            let value = BigInteger::from(value);
+            assert_eq!(
+                BigInteger::from_be_slice(&value.to_be_bytes_trimmed()).unwrap(),
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_sortable_string() {
+        let values = [
+            BigInteger::zero(),
+            BigInteger::from(9u64),
+            BigInteger::from(10u64),
+            BigInteger::from(u128::MAX),
+            BigInteger::MAX,
+        ];
+        let encoded: Vec<String> = values.iter().map(|v| v.to_sortable_string()).collect();
+        assert!(encoded.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(encoded.iter().all(|s| s.len() == BigInteger::SORTABLE_WIDTH));
+        assert_eq!(encoded[4], BigInteger::MAX.to_string());
+        for (value, s) in values.iter().zip(encoded.iter()) {
+            assert_eq!(BigInteger::from_sortable_string(s).unwrap(), *value);
+        }
+
+        assert!(BigInteger::from_sortable_string("10").is_err());
+        assert!(BigInteger::from_sortable_string(&"9".repeat(78)).is_err());
+        assert!(BigInteger::from_sortable_string(&format!("+{}", "0".repeat(77))).is_err());
+    }
+
+    #[test]
+    fn test_narrowing() {
+        let small = BigInteger::from(42u64);
+        assert_eq!(small.to_uint64().unwrap(), Uint64::new(42));
+        assert_eq!(small.to_u64_saturating(), 42);
+        assert_eq!(small.to_uint128_saturating(), Uint128::new(42));
+
+        let above_u64 = BigInteger::from(u64::MAX as u128 + 1);
+        assert!(above_u64.to_uint64().is_err());
+        assert_eq!(above_u64.to_u64_saturating(), u64::MAX);
+        assert_eq!(above_u64.to_uint128_saturating(), Uint128::new(u64::MAX as u128 + 1));
+
+        assert!(BigInteger::MAX.to_uint128().is_err());
+        assert_eq!(BigInteger::MAX.to_uint128_saturating(), Uint128::MAX);
+    }
+
+    #[test]
+    fn test_try_from_decimal() {
+        let d = BigDecimal::percent(250);
+        assert_eq!(
+            BigInteger::try_from_decimal(d, RoundingMode::Floor).unwrap(),
+            BigInteger::from(2u64)
+        );
+        assert_eq!(
+            d.to_integer_checked(RoundingMode::Ceil).unwrap(),
+            BigInteger::from(3u64)
+        );
+        assert_eq!(
+            d.to_integer_checked(RoundingMode::HalfEven).unwrap(),
+            BigInteger::from(2u64)
+        );
+        assert_eq!(
+            BigDecimal::MAX.to_integer_checked(RoundingMode::Ceil).unwrap(),
+            BigInteger(Decimal256::MAX.to_uint_ceil())
+        );
+        assert_eq!(BigInteger::from(d), BigInteger::from(2u64));
+    }
+
     #[test]
     fn test_div() {
         let d = BigDecimal::from(BigInteger::from(100000000000000000000u128), 0);
@@ -253,6 +542,65 @@ mod tests {
         assert_eq!(i / d, BigDecimal::one());
     }
 
+    #[test]
+    fn test_div_rounding() {
+        let seven = BigInteger::from(7u64);
+        let two = BigInteger::from(2u64);
+        assert_eq!(seven.div_floor(two).unwrap(), BigInteger::from(3u64));
+        assert_eq!(seven.div_ceil(two).unwrap(), BigInteger::from(4u64));
+        assert_eq!(
+            seven.div_round(two, RoundingMode::HalfEven).unwrap(),
+            BigInteger::from(4u64)
+        );
+        assert_eq!(BigInteger::MAX.div_ceil(BigInteger::MAX).unwrap(), BigInteger::one());
+        assert_eq!(
+            BigInteger::MAX.div_ceil(two).unwrap(),
+            BigInteger(Uint256::MAX / Uint256::from(2u8) + Uint256::one())
+        );
+        assert!(seven.div_ceil(BigInteger::zero()).is_err());
+    }
+
+    #[test]
+    fn test_ratio_of() {
+        let part = BigInteger::from(25u64);
+        assert_eq!(part.ratio_of(BigInteger::from(100u64)).unwrap(), BigDecimal::percent(25));
+        assert!(part.ratio_of(BigInteger::zero()).is_err());
+        assert!(BigInteger::MAX.ratio_of(BigInteger::one()).is_err());
+    }
+
+    #[test]
+    fn test_uint128_ops() {
+        let i = BigInteger::from(12u64);
+        assert_eq!(i * Uint128::new(2), BigInteger::from(24u64));
+        assert_eq!(i / Uint128::new(5), BigInteger::from(2u64));
+    }
+
+    #[test]
+    fn test_rem() {
+        let mut i = BigInteger::from(17u64);
+        assert_eq!(i % BigInteger::from(5u64), BigInteger::from(2u64));
+        i %= BigInteger::from(4u64);
+        assert_eq!(i, BigInteger::one());
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_ref_ops() {
+        let a = BigInteger::from(6u64);
+        let b = BigInteger::from(4u64);
+        assert_eq!(&a + &b, BigInteger::from(10u64));
+        assert_eq!(a - &b, BigInteger::from(2u64));
+        assert_eq!(&a * b, BigInteger::from(24u64));
+        assert_eq!(&a / &b, BigInteger::one());
+        assert_eq!(&a * &BigDecimal::percent(50), BigDecimal::from_ratio(3u64, 1u64));
+
+        let mut c = a;
+        c += &b;
+        c %= &b;
+        assert_eq!(c, BigInteger::from(2u64));
+        assert_eq!([a, b].iter().fold(BigInteger::zero(), |acc, x| acc + x), BigInteger::from(10u64));
+    }
+
     #[test]
     fn test_sum() {
         let vector: Vec<BigInteger> = vec![BigInteger::from(1u64), BigInteger::from(2u64), BigInteger::from(3u64)];
@@ -260,6 +608,19 @@ mod tests {
         assert_eq!(vector.iter().sum::<BigInteger>(), BigInteger::from(6u64));
     }
 
+    #[test]
+    fn test_checked_scale() {
+        let bigint = BigInteger::from(5u64);
+        assert_eq!(bigint.checked_scale_up(2).unwrap(), BigInteger::from(500u64));
+        assert_eq!(BigInteger::one().checked_scale_up(77).unwrap(), BigInteger::one().scale_up(77));
+        assert!(BigInteger::one().checked_scale_up(78).is_err());
+        assert!(BigInteger::from(12u64).checked_scale_up(77).is_err());
+
+        assert_eq!(bigint.checked_scale_down(1).unwrap(), BigDecimal::percent(50));
+        assert!(bigint.checked_scale_down(39).is_err());
+        assert!(BigInteger::MAX.checked_scale_down(0).is_err());
+    }
+
     #[test]
     fn test_bytes() {
         let i = BigInteger(Uint256::from(1000000u64));
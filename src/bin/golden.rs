@@ -0,0 +1,17 @@
+// Regenerates the golden fixtures from the current implementation:
+// cargo run --features testing --bin golden [path]
+
+use cosmwasm_common_library::golden::default_cases;
+use std::path::PathBuf;
+
+fn main() {
+    let path = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/golden/math.json")
+        });
+    let json = serde_json::to_string_pretty(&default_cases()).expect("fixtures serialize") + "\n";
+    std::fs::write(&path, json).expect("fixtures are writable");
+    println!("wrote {}", path.display());
+}
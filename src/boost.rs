@@ -0,0 +1,149 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::number::Number;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Timestamp;
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub struct Lock {
+    pub amount: BigInteger,
+    pub end: Timestamp,
+}
+
+// Vote-escrow power: the full amount for a lock of `max_lock_seconds` or longer, decaying
+// linearly to zero at `lock_end`.
+pub fn power(
+    locked_amount: BigInteger,
+    lock_end: Timestamp,
+    now: Timestamp,
+    max_lock_seconds: u64,
+) -> Result<BigDecimal, CommonError> {
+    if max_lock_seconds == 0 || lock_end <= now {
+        return Ok(BigDecimal::zero());
+    }
+    let remaining = (lock_end.seconds() - now.seconds()).min(max_lock_seconds);
+    let weight = BigDecimal::from_ratio(remaining, max_lock_seconds);
+    BigDecimal::checked_from(locked_amount, 0)?.checked_mul(weight)
+}
+
+pub fn total_power(
+    locks: &[Lock],
+    now: Timestamp,
+    max_lock_seconds: u64,
+) -> Result<BigDecimal, CommonError> {
+    locks.iter().try_fold(BigDecimal::zero(), |total, lock| {
+        total.checked_add(power(lock.amount, lock.end, now, max_lock_seconds)?)
+    })
+}
+
+// Reward multiplier in [1, max_boost]. Linear in the user's share of the voting power, reaching
+// `max_boost` when the user holds `full_boost_share` of it or more.
+pub fn boost_multiplier(
+    user_power: BigDecimal,
+    total_power: BigDecimal,
+    full_boost_share: BigDecimal,
+    max_boost: BigDecimal,
+) -> Result<BigDecimal, CommonError> {
+    if total_power.is_zero() || full_boost_share.is_zero() || max_boost <= BigDecimal::one() {
+        return Ok(BigDecimal::one());
+    }
+    let share = user_power.checked_div(total_power)?;
+    let progress = share.checked_div(full_boost_share)?.min(BigDecimal::one());
+    let extra = (max_boost - BigDecimal::one()).checked_mul(progress)?;
+    Ok(BigDecimal::one() + extra)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::boost::{boost_multiplier, power, total_power, Lock};
+    use cosmwasm_std::Timestamp;
+
+    const YEAR: u64 = 365 * 24 * 3600;
+
+    #[test]
+    fn test_power_decay() {
+        let amount = BigInteger::from(1000u64);
+        let now = Timestamp::from_seconds(0);
+        assert_eq!(
+            power(amount, now.plus_seconds(4 * YEAR), now, 4 * YEAR).unwrap(),
+            BigDecimal::from(amount, 0)
+        );
+        assert_eq!(
+            power(amount, now.plus_seconds(8 * YEAR), now, 4 * YEAR).unwrap(),
+            BigDecimal::from(amount, 0)
+        );
+        assert_eq!(
+            power(amount, now.plus_seconds(YEAR), now, 4 * YEAR).unwrap(),
+            BigDecimal::from(BigInteger::from(250u64), 0)
+        );
+        assert_eq!(
+            power(amount, now, now.plus_seconds(1), 4 * YEAR).unwrap(),
+            BigDecimal::zero()
+        );
+        assert!(power(BigInteger::MAX, now.plus_seconds(YEAR), now, 4 * YEAR).is_err());
+    }
+
+    #[test]
+    fn test_power_is_monotonic_in_time() {
+        let end = Timestamp::from_seconds(4 * YEAR);
+        let mut last = BigDecimal::MAX;
+        for day in (0..=4 * 365).step_by(73) {
+            let now = Timestamp::from_seconds(day * 24 * 3600);
+            let current = power(BigInteger::from(1_000_000u64), end, now, 4 * YEAR).unwrap();
+            assert!(current <= last);
+            last = current;
+        }
+        assert!(last.is_zero());
+    }
+
+    #[test]
+    fn test_total_power() {
+        let now = Timestamp::from_seconds(0);
+        let locks = [
+            Lock {
+                amount: BigInteger::from(100u64),
+                end: now.plus_seconds(2 * YEAR),
+            },
+            Lock {
+                amount: BigInteger::from(100u64),
+                end: now.plus_seconds(4 * YEAR),
+            },
+        ];
+        assert_eq!(
+            total_power(&locks, now, 4 * YEAR).unwrap(),
+            BigDecimal::from(BigInteger::from(150u64), 0)
+        );
+        assert_eq!(
+            total_power(&locks, now.plus_seconds(3 * YEAR), 4 * YEAR).unwrap(),
+            BigDecimal::from(BigInteger::from(25u64), 0)
+        );
+    }
+
+    #[test]
+    fn test_boost_multiplier() {
+        let max = BigDecimal::percent(250);
+        let full = BigDecimal::percent(10);
+        let total = BigDecimal::from(BigInteger::from(1000u64), 0);
+        let boost = |user: u64| {
+            boost_multiplier(
+                BigDecimal::from(BigInteger::from(user), 0),
+                total,
+                full,
+                max,
+            )
+            .unwrap()
+        };
+        assert_eq!(boost(0), BigDecimal::one());
+        assert_eq!(boost(50), BigDecimal::percent(175));
+        assert_eq!(boost(100), max);
+        assert_eq!(boost(500), max);
+        assert_eq!(
+            boost_multiplier(BigDecimal::one(), BigDecimal::zero(), full, max).unwrap(),
+            BigDecimal::one()
+        );
+    }
+}
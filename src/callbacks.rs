@@ -0,0 +1,132 @@
+use crate::error::CommonError;
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_schema::serde::Serialize;
+use cosmwasm_std::{from_json, to_json_vec, CosmosMsg, Reply, Storage, SubMsg, SubMsgResult};
+
+// Generated ids start above the u32 range so they never collide with ids from `reply_ids!`.
+pub const CALLBACK_ID_OFFSET: u64 = 1 << 32;
+
+const CALLBACK_NAMESPACE: &[u8] = b"callback:";
+const CALLBACK_COUNTER_KEY: &[u8] = b"callback_counter";
+
+fn callback_key(id: u64) -> Vec<u8> {
+    [CALLBACK_NAMESPACE, &id.to_be_bytes()].concat()
+}
+
+pub fn is_callback_id(id: u64) -> bool {
+    id >= CALLBACK_ID_OFFSET
+}
+
+// Stores `payload` under a fresh reply id and returns the id.
+pub fn register_callback<T: Serialize>(
+    storage: &mut dyn Storage,
+    payload: &T,
+) -> Result<u64, CommonError> {
+    let counter = match storage.get(CALLBACK_COUNTER_KEY) {
+        Some(bytes) => from_json::<u64>(bytes)?,
+        None => 0,
+    };
+    storage.set(CALLBACK_COUNTER_KEY, &to_json_vec(&(counter + 1))?);
+
+    let id = CALLBACK_ID_OFFSET + counter;
+    storage.set(&callback_key(id), &to_json_vec(payload)?);
+    Ok(id)
+}
+
+pub fn callback_submsg<T: Serialize>(
+    storage: &mut dyn Storage,
+    msg: impl Into<CosmosMsg>,
+    payload: &T,
+) -> Result<SubMsg, CommonError> {
+    let id = register_callback(storage, payload)?;
+    Ok(SubMsg::reply_on_success(msg, id))
+}
+
+// Loads and removes the payload registered for `reply.id`. The payload is removed even when the
+// sub-message failed, so reply-always callers do not leak entries.
+pub fn handle_reply<T: DeserializeOwned>(
+    storage: &mut dyn Storage,
+    reply: &Reply,
+) -> Result<T, CommonError> {
+    let key = callback_key(reply.id);
+    let data = storage
+        .get(&key)
+        .ok_or(CommonError::UnknownReplyId(reply.id))?;
+    storage.remove(&key);
+    if let SubMsgResult::Err(err) = &reply.result {
+        return Err(CommonError::SubMsgFailure(err.clone()));
+    }
+    Ok(from_json(data)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::callbacks::{callback_submsg, handle_reply, is_callback_id, register_callback};
+    use crate::error::CommonError;
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::{BankMsg, Binary, Reply, ReplyOn, SubMsgResponse, SubMsgResult};
+
+    #[cw_serde]
+    struct AfterSwap {
+        recipient: String,
+        min_out: u64,
+    }
+
+    #[allow(deprecated)]
+    fn reply(id: u64, result: SubMsgResult) -> Reply {
+        Reply {
+            id,
+            payload: Binary::default(),
+            gas_used: 0,
+            result,
+        }
+    }
+
+    #[allow(deprecated)]
+    fn ok() -> SubMsgResult {
+        SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+            msg_responses: vec![],
+        })
+    }
+
+    #[test]
+    fn test_register_and_handle() {
+        let mut storage = MockStorage::default();
+        let payload = AfterSwap {
+            recipient: "alice".to_string(),
+            min_out: 10,
+        };
+        let msg = BankMsg::Burn { amount: vec![] };
+        let submsg = callback_submsg(&mut storage, msg, &payload).unwrap();
+        assert_eq!(submsg.reply_on, ReplyOn::Success);
+        assert!(is_callback_id(submsg.id));
+
+        let second = register_callback(&mut storage, &payload).unwrap();
+        assert_eq!(second, submsg.id + 1);
+
+        let loaded: AfterSwap = handle_reply(&mut storage, &reply(submsg.id, ok())).unwrap();
+        assert_eq!(loaded, payload);
+        assert_eq!(
+            handle_reply::<AfterSwap>(&mut storage, &reply(submsg.id, ok())),
+            Err(CommonError::UnknownReplyId(submsg.id))
+        );
+    }
+
+    #[test]
+    fn test_failed_reply_removes_payload() {
+        let mut storage = MockStorage::default();
+        let id = register_callback(&mut storage, &1u64).unwrap();
+        let failed = reply(id, SubMsgResult::Err("out of gas".to_string()));
+        assert_eq!(
+            handle_reply::<u64>(&mut storage, &failed),
+            Err(CommonError::SubMsgFailure("out of gas".to_string()))
+        );
+        assert_eq!(
+            handle_reply::<u64>(&mut storage, &reply(id, ok())),
+            Err(CommonError::UnknownReplyId(id))
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::rounding::{mul_div, RoundingMode};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{BlockInfo, Decimal256};
+use cw_utils::{Duration, Expiration};
+
+// Rejects prices that move more than `max_move` (relative) away from the reference price within
+// one window. The reference is reset to the latest accepted price once the window has passed.
+#[cw_serde]
+pub struct CircuitBreaker {
+    pub max_move: BigDecimal,
+    pub window: Duration,
+    pub reference_price: Option<BigDecimal>,
+    pub window_end: Expiration,
+}
+
+impl CircuitBreaker {
+    pub fn new(max_move: BigDecimal, window: Duration) -> Self {
+        Self {
+            max_move,
+            window,
+            reference_price: None,
+            window_end: Expiration::Never {},
+        }
+    }
+
+    pub fn check(&self, price: BigDecimal, block: &BlockInfo) -> Result<(), CommonError> {
+        let reference = match self.reference_price {
+            Some(reference) if !self.window_end.is_expired(block) => reference,
+            _ => return Ok(()),
+        };
+        let moved = price.0.abs_diff(reference.0);
+        let tripped = if reference.is_zero() {
+            !moved.is_zero()
+        } else {
+            Decimal256::checked_from_ratio(moved.atomics(), reference.0.atomics())?
+                > self.max_move.0
+        };
+        if tripped {
+            return Err(CommonError::CircuitBreakerTripped {
+                reference: reference.to_string(),
+                price: price.to_string(),
+                max_move: self.max_move.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn check_and_update(
+        &mut self,
+        price: BigDecimal,
+        block: &BlockInfo,
+    ) -> Result<(), CommonError> {
+        self.check(price, block)?;
+        if self.reference_price.is_none() || self.window_end.is_expired(block) {
+            self.reference_price = Some(price);
+            self.window_end = self.window.after(block);
+        }
+        Ok(())
+    }
+}
+
+// |price - reference| / reference in bps, rounded up so a deviation is never under-reported. Any
+// price deviates without bound from a zero reference, except zero itself.
+pub fn deviation_bps(price: BigDecimal, reference: BigDecimal) -> Result<BigInteger, CommonError> {
+    let moved = price.0.abs_diff(reference.0);
+    if reference.is_zero() {
+        return Ok(if moved.is_zero() {
+            BigInteger::zero()
+        } else {
+            BigInteger::MAX
+        });
+    }
+    mul_div(
+        BigInteger(moved.atomics()),
+        BigInteger::from(10_000u64),
+        BigInteger(reference.0.atomics()),
+        RoundingMode::Ceil,
+    )
+}
+
+// Guard for comparing e.g. a pool price against the oracle price before accepting a trade.
+pub fn assert_max_deviation(
+    price: BigDecimal,
+    reference: BigDecimal,
+    max_bps: u64,
+) -> Result<(), CommonError> {
+    let deviation = deviation_bps(price, reference)?;
+    if deviation > BigInteger::from(max_bps) {
+        return Err(CommonError::PriceDeviation {
+            price: price.to_string(),
+            reference: reference.to_string(),
+            deviation_bps: deviation.to_string(),
+            max_bps,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::circuit_breaker::{assert_max_deviation, deviation_bps, CircuitBreaker};
+    use crate::error::CommonError;
+    use cosmwasm_std::testing::mock_env;
+    use cw_utils::Duration;
+
+    #[test]
+    fn test_check_and_update() {
+        let mut block = mock_env().block;
+        let mut breaker = CircuitBreaker::new(BigDecimal::percent(10), Duration::Time(60));
+
+        breaker
+            .check_and_update(BigDecimal::percent(100), &block)
+            .unwrap();
+        breaker
+            .check_and_update(BigDecimal::percent(110), &block)
+            .unwrap();
+        breaker
+            .check_and_update(BigDecimal::percent(90), &block)
+            .unwrap();
+        assert!(matches!(
+            breaker.check_and_update(BigDecimal::percent(111), &block),
+            Err(CommonError::CircuitBreakerTripped { .. })
+        ));
+        assert_eq!(breaker.reference_price, Some(BigDecimal::percent(100)));
+
+        // a new window takes the latest price as reference
+        block.time = block.time.plus_seconds(60);
+        breaker
+            .check_and_update(BigDecimal::percent(111), &block)
+            .unwrap();
+        assert_eq!(breaker.reference_price, Some(BigDecimal::percent(111)));
+        assert!(breaker
+            .check_and_update(BigDecimal::percent(130), &block)
+            .is_err());
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let mut breaker = CircuitBreaker::new(BigDecimal::percent(5), Duration::Height(10));
+        breaker
+            .check_and_update(BigDecimal::one(), &mock_env().block)
+            .unwrap();
+        let json = cosmwasm_std::to_json_vec(&breaker).unwrap();
+        let parsed: CircuitBreaker = cosmwasm_std::from_json(json).unwrap();
+        assert_eq!(parsed, breaker);
+    }
+
+    #[test]
+    fn test_max_deviation() {
+        let oracle = BigDecimal::percent(10000);
+        assert_eq!(
+            deviation_bps(BigDecimal::percent(10150), oracle).unwrap(),
+            BigInteger::from(150u64)
+        );
+        assert_eq!(
+            deviation_bps(BigDecimal::percent(9850), oracle).unwrap(),
+            BigInteger::from(150u64)
+        );
+        // rounded up
+        assert_eq!(
+            deviation_bps(BigDecimal::from_ratio(1_000_001u64, 10_000u64), oracle).unwrap(),
+            BigInteger::one()
+        );
+        assert_eq!(
+            deviation_bps(BigDecimal::one(), BigDecimal::zero()).unwrap(),
+            BigInteger::MAX
+        );
+
+        assert_max_deviation(BigDecimal::percent(10150), oracle, 150).unwrap();
+        assert_eq!(
+            assert_max_deviation(BigDecimal::percent(10151), oracle, 150),
+            Err(CommonError::PriceDeviation {
+                price: "101.51".to_string(),
+                reference: "100".to_string(),
+                deviation_bps: "151".to_string(),
+                max_bps: 150,
+            })
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use cosmwasm_std::{Storage, Uint256};
+
+pub const BITS_PER_WORD: u64 = 256;
+
+// Claim flags packed 256 to a storage entry. Words are stored as raw big-endian bytes under
+// `namespace`, keyed by the big-endian word index, so a full airdrop of n claimers costs n / 256
+// entries.
+pub struct Bitmap {
+    namespace: &'static [u8],
+}
+
+impl Bitmap {
+    pub const fn new(namespace: &'static str) -> Self {
+        Self {
+            namespace: namespace.as_bytes(),
+        }
+    }
+
+    pub fn word_key(&self, word: u64) -> Vec<u8> {
+        [self.namespace, b":", &word.to_be_bytes()].concat()
+    }
+
+    // word index, and the byte and mask of `index` in the word's big-endian bytes
+    fn position(index: u64) -> (u64, usize, u8) {
+        let word = index / BITS_PER_WORD;
+        let bit = (index % BITS_PER_WORD) as usize;
+        (word, 31 - bit / 8, 1u8 << (bit % 8))
+    }
+
+    // Fails on an entry that is not 32 bytes rather than reading it as unclaimed, which would let
+    // the next `set_claimed` overwrite claims already made.
+    pub fn load_word(&self, storage: &dyn Storage, word: u64) -> Result<BigInteger, CommonError> {
+        match storage.get(&self.word_key(word)) {
+            Some(bytes) => {
+                let bytes: [u8; 32] = bytes.try_into().map_err(|bytes: Vec<u8>| {
+                    CommonError::CorruptedState(format!(
+                        "claim word {} has {} bytes, expected 32",
+                        word,
+                        bytes.len()
+                    ))
+                })?;
+                Ok(BigInteger(Uint256::from_be_bytes(bytes)))
+            }
+            None => Ok(BigInteger::zero()),
+        }
+    }
+
+    pub fn is_claimed(&self, storage: &dyn Storage, index: u64) -> Result<bool, CommonError> {
+        let (word, byte, mask) = Self::position(index);
+        Ok(self.load_word(storage, word)?.to_be_bytes()[byte] & mask != 0)
+    }
+
+    pub fn set_claimed(&self, storage: &mut dyn Storage, index: u64) -> Result<(), CommonError> {
+        let (word, byte, mask) = Self::position(index);
+        let mut bytes = self.load_word(storage, word)?.to_be_bytes();
+        if bytes[byte] & mask != 0 {
+            return Err(CommonError::AlreadyClaimed(index));
+        }
+        bytes[byte] |= mask;
+        storage.set(&self.word_key(word), &bytes);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::claims::Bitmap;
+    use crate::error::CommonError;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::{Storage, Uint256};
+
+    const CLAIMED: Bitmap = Bitmap::new("claimed");
+
+    #[test]
+    fn test_set_claimed() {
+        let mut storage = MockStorage::default();
+        for index in [0u64, 1, 255, 256, 1_000_000] {
+            assert!(!CLAIMED.is_claimed(&storage, index).unwrap());
+            CLAIMED.set_claimed(&mut storage, index).unwrap();
+            assert!(CLAIMED.is_claimed(&storage, index).unwrap());
+            assert_eq!(
+                CLAIMED.set_claimed(&mut storage, index),
+                Err(CommonError::AlreadyClaimed(index))
+            );
+        }
+        assert!(!CLAIMED.is_claimed(&storage, 2).unwrap());
+        assert!(!CLAIMED.is_claimed(&storage, 257).unwrap());
+        assert_eq!(
+            CLAIMED.load_word(&storage, 0).unwrap(),
+            BigInteger::from(3u64) + BigInteger(Uint256::one() << 255)
+        );
+    }
+
+    #[test]
+    fn test_word_packing() {
+        let mut storage = MockStorage::default();
+        for index in 0..256 {
+            CLAIMED.set_claimed(&mut storage, index).unwrap();
+        }
+        assert_eq!(storage.get(&CLAIMED.word_key(0)), Some(vec![u8::MAX; 32]));
+        assert_eq!(storage.get(&CLAIMED.word_key(1)), None);
+        assert_eq!(
+            CLAIMED.word_key(1),
+            [b"claimed:".as_slice(), &1u64.to_be_bytes()].concat()
+        );
+    }
+
+    #[test]
+    fn test_corrupted_word() {
+        let mut storage = MockStorage::default();
+        storage.set(&CLAIMED.word_key(0), &[0xff; 31]);
+        assert!(matches!(
+            CLAIMED.is_claimed(&storage, 0),
+            Err(CommonError::CorruptedState(_))
+        ));
+        assert!(matches!(
+            CLAIMED.set_claimed(&mut storage, 0),
+            Err(CommonError::CorruptedState(_))
+        ));
+        assert_eq!(storage.get(&CLAIMED.word_key(0)), Some(vec![0xff; 31]));
+    }
+}
@@ -0,0 +1,184 @@
+use crate::commit_reveal::sha256;
+use crate::error::CommonError;
+use bech32::primitives::decode::CheckedHrpstring;
+use bech32::{Bech32, Hrp};
+use core::any::type_name;
+use cosmwasm_schema::serde::Serialize;
+use cosmwasm_std::{Binary, StdError, StdResult};
+use serde_json::Value;
+
+pub fn to_base64(data: impl AsRef<[u8]>) -> String {
+    Binary::from(data.as_ref()).to_base64()
+}
+
+pub fn from_base64(encoded: &str) -> StdResult<Binary> {
+    Binary::from_base64(encoded)
+}
+
+pub fn from_base64_array<const N: usize>(encoded: &str) -> StdResult<[u8; N]> {
+    let decoded = from_base64(encoded)?;
+    decoded.to_array::<N>().map_err(|_| {
+        StdError::generic_err(format!(
+            "Invalid length: expected {} bytes, got {}",
+            N,
+            decoded.len()
+        ))
+    })
+}
+
+pub fn bech32_encode(prefix: &str, data: &[u8]) -> Result<String, CommonError> {
+    let hrp = Hrp::parse(prefix).map_err(|err| CommonError::InvalidBech32(err.to_string()))?;
+    bech32::encode::<Bech32>(hrp, data).map_err(|err| CommonError::InvalidBech32(err.to_string()))
+}
+
+pub fn bech32_decode(address: &str) -> Result<(String, Vec<u8>), CommonError> {
+    let checked = CheckedHrpstring::new::<Bech32>(address)
+        .map_err(|err| CommonError::InvalidBech32(err.to_string()))?;
+    Ok((checked.hrp().to_lowercase(), checked.byte_iter().collect()))
+}
+
+pub fn bech32_decode_with_prefix(address: &str, prefix: &str) -> Result<Vec<u8>, CommonError> {
+    let (actual, data) = bech32_decode(address)?;
+    if actual != prefix {
+        return Err(CommonError::InvalidBech32Prefix {
+            expected: prefix.to_string(),
+            actual,
+        });
+    }
+    Ok(data)
+}
+
+// cosmos1... -> osmo1..., only valid between chains that derive addresses the same way
+pub fn convert_bech32_prefix(address: &str, new_prefix: &str) -> Result<String, CommonError> {
+    let (_, data) = bech32_decode(address)?;
+    bech32_encode(new_prefix, &data)
+}
+
+// Object keys sorted, no whitespace, so the same value produces the same bytes (and digest)
+// in every contract regardless of struct field order.
+pub fn to_canonical_json<T: Serialize>(value: &T) -> StdResult<Binary> {
+    let value = serde_json::to_value(value)
+        .map_err(|err| StdError::serialize_err(type_name::<T>(), err))?;
+    let mut out = String::new();
+    write_canonical(&value, &mut out);
+    Ok(Binary::from(out.into_bytes()))
+}
+
+pub fn canonical_json_digest<T: Serialize>(value: &T) -> StdResult<[u8; 32]> {
+    Ok(sha256(to_canonical_json(value)?.as_slice()))
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::from(key.as_str()).to_string());
+                out.push(':');
+                write_canonical(item, out);
+            }
+            out.push('}');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codec::{
+        bech32_decode, bech32_decode_with_prefix, bech32_encode, canonical_json_digest,
+        convert_bech32_prefix, from_base64, from_base64_array, to_base64, to_canonical_json,
+    };
+    use crate::commit_reveal::sha256;
+    use crate::error::CommonError;
+    use cosmwasm_schema::cw_serde;
+
+    const COSMOS_ADDRESS: &str = "cosmos1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu";
+    const OSMO_ADDRESS: &str = "osmo1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5helwsw";
+
+    #[test]
+    fn test_base64() {
+        let encoded = to_base64([1u8, 2, 3]);
+        assert_eq!(encoded, "AQID");
+        assert_eq!(from_base64(&encoded).unwrap().to_vec(), vec![1, 2, 3]);
+        assert_eq!(from_base64_array::<3>(&encoded).unwrap(), [1, 2, 3]);
+        assert!(from_base64_array::<4>(&encoded).is_err());
+        assert!(from_base64("not base64!").is_err());
+    }
+
+    #[test]
+    fn test_bech32() {
+        let data: Vec<u8> = (1..=20).collect();
+        let address = bech32_encode("cosmos", &data).unwrap();
+        assert_eq!(address, COSMOS_ADDRESS);
+        assert_eq!(
+            bech32_decode(&address).unwrap(),
+            ("cosmos".to_string(), data.clone())
+        );
+        assert_eq!(bech32_decode_with_prefix(&address, "cosmos").unwrap(), data);
+        assert_eq!(
+            bech32_decode_with_prefix(&address, "osmo"),
+            Err(CommonError::InvalidBech32Prefix {
+                expected: "osmo".to_string(),
+                actual: "cosmos".to_string(),
+            })
+        );
+        assert_eq!(
+            convert_bech32_prefix(&address, "osmo").unwrap(),
+            OSMO_ADDRESS
+        );
+        assert!(bech32_decode("cosmos1invalid").is_err());
+    }
+
+    #[cw_serde]
+    struct Inner {
+        zeta: String,
+        alpha: u64,
+    }
+
+    #[cw_serde]
+    struct Outer {
+        name: String,
+        inner: Inner,
+        list: Vec<Inner>,
+    }
+
+    #[test]
+    fn test_canonical_json() {
+        let value = Outer {
+            name: "a \"quoted\" name".to_string(),
+            inner: Inner {
+                zeta: "z".to_string(),
+                alpha: 1,
+            },
+            list: vec![Inner {
+                zeta: "y".to_string(),
+                alpha: 2,
+            }],
+        };
+        let json = to_canonical_json(&value).unwrap();
+        assert_eq!(
+            String::from_utf8(json.to_vec()).unwrap(),
+            r#"{"inner":{"alpha":1,"zeta":"z"},"list":[{"alpha":2,"zeta":"y"}],"name":"a \"quoted\" name"}"#
+        );
+        assert_eq!(
+            canonical_json_digest(&value).unwrap(),
+            sha256(json.as_slice())
+        );
+    }
+}
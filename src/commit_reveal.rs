@@ -0,0 +1,110 @@
+use crate::error::CommonError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{BlockInfo, HexBinary};
+use cw_utils::Expiration;
+use sha2::{Digest, Sha256};
+
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+// The preimage length is hashed in front so that bytes cannot be shifted between the preimage and
+// the salt while keeping the same digest.
+pub fn commitment_hash(preimage: &[u8], salt: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update((preimage.len() as u64).to_be_bytes());
+    hasher.update(preimage);
+    hasher.update(salt);
+    hasher.finalize().into()
+}
+
+#[cw_serde]
+pub struct Commitment {
+    pub hash: HexBinary,
+    pub expiration: Expiration,
+}
+
+impl Commitment {
+    pub fn new(hash: impl Into<HexBinary>) -> Self {
+        Self {
+            hash: hash.into(),
+            expiration: Expiration::Never {},
+        }
+    }
+
+    pub fn from_preimage(preimage: &[u8], salt: &[u8]) -> Self {
+        Self::new(commitment_hash(preimage, salt))
+    }
+
+    pub fn with_expiration(mut self, expiration: Expiration) -> Self {
+        self.expiration = expiration;
+        self
+    }
+
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        self.expiration.is_expired(block)
+    }
+
+    pub fn verify(&self, preimage: &[u8], salt: &[u8]) -> bool {
+        self.hash.as_slice() == commitment_hash(preimage, salt)
+    }
+
+    pub fn reveal(
+        &self,
+        block: &BlockInfo,
+        preimage: &[u8],
+        salt: &[u8],
+    ) -> Result<(), CommonError> {
+        if self.is_expired(block) {
+            return Err(CommonError::CommitmentExpired(self.expiration.to_string()));
+        }
+        if !self.verify(preimage, salt) {
+            return Err(CommonError::CommitmentMismatch);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commit_reveal::{commitment_hash, sha256, Commitment};
+    use crate::error::CommonError;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::HexBinary;
+    use cw_utils::Expiration;
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            HexBinary::from(sha256(b"abc")).to_hex(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let commitment = Commitment::new(commitment_hash(b"100", b"salt"));
+        assert!(commitment.verify(b"100", b"salt"));
+        assert!(!commitment.verify(b"10", b"0salt"));
+        assert!(!commitment.verify(b"100", b"pepper"));
+    }
+
+    #[test]
+    fn test_reveal_expiration() {
+        let env = mock_env();
+        let commitment = Commitment::from_preimage(b"bid", b"salt")
+            .with_expiration(Expiration::AtHeight(env.block.height + 1));
+        assert!(commitment.reveal(&env.block, b"bid", b"salt").is_ok());
+        assert_eq!(
+            commitment.reveal(&env.block, b"bad", b"salt"),
+            Err(CommonError::CommitmentMismatch)
+        );
+
+        let mut block = env.block;
+        block.height += 1;
+        assert!(matches!(
+            commitment.reveal(&block, b"bid", b"salt"),
+            Err(CommonError::CommitmentExpired(_))
+        ));
+    }
+}
@@ -0,0 +1,105 @@
+// MessagePack for large state blobs, which store smaller than JSON. Messages and events stay JSON.
+
+use core::marker::PhantomData;
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_schema::serde::Serialize;
+use cosmwasm_std::{from_msgpack, to_msgpack_vec, StdError, StdResult, Storage};
+
+pub fn to_compact_vec<T: Serialize>(value: &T) -> StdResult<Vec<u8>> {
+    to_msgpack_vec(value)
+}
+
+pub fn from_compact<T: DeserializeOwned>(bytes: impl AsRef<[u8]>) -> StdResult<T> {
+    from_msgpack(bytes)
+}
+
+// A single value persisted under `namespace` in the compact encoding.
+pub struct CompactItem<T> {
+    namespace: &'static [u8],
+    phantom_data: PhantomData<T>,
+}
+
+impl<T> CompactItem<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    pub const fn new(namespace: &'static str) -> Self {
+        Self {
+            namespace: namespace.as_bytes(),
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn save(&self, storage: &mut dyn Storage, value: &T) -> StdResult<()> {
+        storage.set(self.namespace, &to_compact_vec(value)?);
+        Ok(())
+    }
+
+    pub fn may_load(&self, storage: &dyn Storage) -> StdResult<Option<T>> {
+        storage.get(self.namespace).map(from_compact).transpose()
+    }
+
+    pub fn load(&self, storage: &dyn Storage) -> StdResult<T> {
+        self.may_load(storage)?
+            .ok_or_else(|| StdError::not_found(core::any::type_name::<T>()))
+    }
+
+    pub fn remove(&self, storage: &mut dyn Storage) {
+        storage.remove(self.namespace);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::balances::Balances;
+    use crate::biginteger::BigInteger;
+    use crate::compact::{from_compact, to_compact_vec, CompactItem};
+    use crate::serializable_denom::SerializableDenom;
+    use crate::serializable_map::SerializableMap;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::to_json_vec;
+
+    fn balances() -> Balances {
+        let mut balances = Balances::new();
+        for i in 0..50u64 {
+            balances
+                .add(
+                    SerializableDenom::Native(format!("denom{}", i)),
+                    BigInteger::from(i * 1_000_000),
+                )
+                .unwrap();
+        }
+        balances
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let map = SerializableMap::from(vec![(2u64, "b".to_string()), (1u64, "a".to_string())]);
+        let bytes = to_compact_vec(&map).unwrap();
+        assert_eq!(
+            from_compact::<SerializableMap<u64, String>>(&bytes).unwrap(),
+            map
+        );
+
+        let balances = balances();
+        let bytes = to_compact_vec(&balances).unwrap();
+        assert_eq!(from_compact::<Balances>(&bytes).unwrap(), balances);
+        assert!(bytes.len() < to_json_vec(&balances).unwrap().len());
+
+        assert!(from_compact::<Balances>(b"not msgpack").is_err());
+    }
+
+    #[test]
+    fn test_item() {
+        const BALANCES: CompactItem<Balances> = CompactItem::new("balances");
+        let mut storage = MockStorage::new();
+        assert_eq!(BALANCES.may_load(&storage).unwrap(), None);
+        assert!(BALANCES.load(&storage).is_err());
+
+        BALANCES.save(&mut storage, &balances()).unwrap();
+        assert_eq!(BALANCES.load(&storage).unwrap(), balances());
+
+        BALANCES.remove(&mut storage);
+        assert_eq!(BALANCES.may_load(&storage).unwrap(), None);
+    }
+}
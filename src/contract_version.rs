@@ -0,0 +1,298 @@
+use crate::error::CommonError;
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_json, to_json_vec, StdError, Storage};
+
+// Same key and layout as cw2, so contracts can switch between cw2 and these helpers freely.
+pub const CONTRACT_INFO_KEY: &[u8] = b"contract_info";
+
+#[cw_serde]
+pub struct ContractVersion {
+    pub contract: String,
+    pub version: String,
+}
+
+pub fn get_contract_version(storage: &dyn Storage) -> Result<ContractVersion, CommonError> {
+    let data = storage
+        .get(CONTRACT_INFO_KEY)
+        .ok_or_else(|| StdError::not_found("ContractVersion"))?;
+    Ok(from_json(data)?)
+}
+
+pub fn set_contract_version(
+    storage: &mut dyn Storage,
+    contract: impl Into<String>,
+    version: impl Into<String>,
+) -> Result<(), CommonError> {
+    let version = version.into();
+    SemVer::from_str(&version)?;
+    let info = ContractVersion {
+        contract: contract.into(),
+        version,
+    };
+    storage.set(CONTRACT_INFO_KEY, &to_json_vec(&info)?);
+    Ok(())
+}
+
+// Guards against migrating a different contract's code over this one's state.
+pub fn assert_contract_name(storage: &dyn Storage, expected: &str) -> Result<(), CommonError> {
+    let stored = get_contract_version(storage)?;
+    if stored.contract != expected {
+        return Err(CommonError::InvalidContract {
+            expected: expected.to_string(),
+            actual: stored.contract,
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_contract_version(
+    storage: &dyn Storage,
+    contract: &str,
+    version: &str,
+) -> Result<(), CommonError> {
+    assert_contract_name(storage, contract)?;
+    let stored = get_contract_version(storage)?;
+    if SemVer::from_str(&stored.version)? != SemVer::from_str(version)? {
+        return Err(CommonError::UnsupportedVersion {
+            expected: version.to_string(),
+            actual: stored.version,
+        });
+    }
+    Ok(())
+}
+
+// For migrate entry points: same contract, and strictly newer code than what is stored.
+pub fn assert_newer_version(
+    storage: &dyn Storage,
+    contract: &str,
+    new_version: &str,
+) -> Result<(), CommonError> {
+    assert_contract_name(storage, contract)?;
+    let stored = get_contract_version(storage)?;
+    if SemVer::from_str(new_version)? <= SemVer::from_str(&stored.version)? {
+        return Err(CommonError::NotNewerVersion {
+            stored: stored.version,
+            new: new_version.to_string(),
+        });
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PreRelease {
+    Numeric(u64),
+    Alpha(String),
+}
+
+// major.minor.patch[-pre.release][+build]; build metadata is ignored for precedence.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SemVer {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: Vec<PreRelease>,
+}
+
+impl FromStr for SemVer {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || CommonError::InvalidVersion(s.to_string());
+        let without_build = s.split('+').next().unwrap_or_default();
+        let (core, pre) = match without_build.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (without_build, None),
+        };
+
+        let mut numbers = core
+            .split('.')
+            .map(|part| parse_numeric(part).ok_or_else(invalid));
+        let major = numbers.next().ok_or_else(invalid)??;
+        let minor = numbers.next().ok_or_else(invalid)??;
+        let patch = numbers.next().ok_or_else(invalid)??;
+        if numbers.next().is_some() {
+            return Err(invalid());
+        }
+
+        let pre = match pre {
+            None => vec![],
+            Some(pre) => pre
+                .split('.')
+                .map(|part| {
+                    if part.is_empty()
+                        || !part.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+                    {
+                        return Err(invalid());
+                    }
+                    if part.bytes().all(|b| b.is_ascii_digit()) {
+                        parse_numeric(part)
+                            .map(PreRelease::Numeric)
+                            .ok_or_else(invalid)
+                    } else {
+                        Ok(PreRelease::Alpha(part.to_string()))
+                    }
+                })
+                .collect::<Result<_, _>>()?,
+        };
+
+        Ok(Self {
+            major,
+            minor,
+            patch,
+            pre,
+        })
+    }
+}
+
+// Digits only, no leading zeros.
+fn parse_numeric(part: &str) -> Option<u64> {
+    if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if part.len() > 1 && part.starts_with('0') {
+        return None;
+    }
+    part.parse().ok()
+}
+
+impl Ord for SemVer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => self.pre.cmp(&other.pre),
+            })
+    }
+}
+
+impl PartialOrd for SemVer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for SemVer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        for (i, part) in self.pre.iter().enumerate() {
+            f.write_str(if i == 0 { "-" } else { "." })?;
+            match part {
+                PreRelease::Numeric(n) => write!(f, "{}", n)?,
+                PreRelease::Alpha(s) => f.write_str(s)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract_version::{
+        assert_contract_name, assert_contract_version, assert_newer_version, get_contract_version,
+        set_contract_version, ContractVersion, SemVer, CONTRACT_INFO_KEY,
+    };
+    use crate::error::CommonError;
+    use core::str::FromStr;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::Storage;
+
+    #[test]
+    fn test_get_contract_version() {
+        let mut storage = MockStorage::default();
+        assert!(get_contract_version(&storage).is_err());
+
+        storage.set(
+            CONTRACT_INFO_KEY,
+            br#"{"contract":"crates.io:vault","version":"1.2.0"}"#,
+        );
+        assert_eq!(
+            get_contract_version(&storage).unwrap(),
+            ContractVersion {
+                contract: "crates.io:vault".to_string(),
+                version: "1.2.0".to_string(),
+            }
+        );
+        assert!(assert_contract_name(&storage, "crates.io:vault").is_ok());
+        assert_eq!(
+            assert_contract_name(&storage, "crates.io:pool"),
+            Err(CommonError::InvalidContract {
+                expected: "crates.io:pool".to_string(),
+                actual: "crates.io:vault".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_semver_precedence() {
+        let ordered = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1",
+            "1.10.0",
+            "2.0.0",
+        ];
+        for pair in ordered.windows(2) {
+            assert!(
+                SemVer::from_str(pair[0]).unwrap() < SemVer::from_str(pair[1]).unwrap(),
+                "{} < {}",
+                pair[0],
+                pair[1]
+            );
+        }
+        assert_eq!(
+            SemVer::from_str("1.2.3+build.5").unwrap(),
+            SemVer::from_str("1.2.3").unwrap()
+        );
+        assert_eq!(
+            SemVer::from_str("1.0.0-rc.1").unwrap().to_string(),
+            "1.0.0-rc.1"
+        );
+        for invalid in [
+            "",
+            "1",
+            "1.2",
+            "1.2.3.4",
+            "01.2.3",
+            "1.2.x",
+            "1.2.3-",
+            "1.2.3-a..b",
+        ] {
+            assert!(SemVer::from_str(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_version_guards() {
+        let mut storage = MockStorage::default();
+        assert!(set_contract_version(&mut storage, "crates.io:vault", "latest").is_err());
+        set_contract_version(&mut storage, "crates.io:vault", "1.2.0").unwrap();
+
+        assert!(assert_contract_version(&storage, "crates.io:vault", "1.2.0").is_ok());
+        assert!(assert_contract_version(&storage, "crates.io:vault", "1.2.1").is_err());
+        assert!(assert_newer_version(&storage, "crates.io:vault", "1.3.0-rc.1").is_ok());
+        assert_eq!(
+            assert_newer_version(&storage, "crates.io:vault", "1.2.0"),
+            Err(CommonError::NotNewerVersion {
+                stored: "1.2.0".to_string(),
+                new: "1.2.0".to_string(),
+            })
+        );
+        assert!(assert_newer_version(&storage, "crates.io:vault", "1.2.0-rc.1").is_err());
+        assert!(matches!(
+            assert_newer_version(&storage, "crates.io:pool", "2.0.0"),
+            Err(CommonError::InvalidContract { .. })
+        ));
+    }
+}
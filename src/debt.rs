@@ -0,0 +1,142 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::growth_index::{Accrual, GrowthIndex};
+use crate::rounding::RoundingMode;
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub struct Repayment {
+    pub shares_burned: BigInteger,
+    // never more than the amount offered, the rest is for the caller to refund
+    pub repaid: BigInteger,
+}
+
+// Debt is tracked in shares of a growing index. Every conversion rounds in favor of the protocol:
+// borrowers get shares rounded up, owe amounts rounded up and burn shares rounded down.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub struct DebtPool {
+    pub index: GrowthIndex,
+    pub total_shares: BigInteger,
+}
+
+impl DebtPool {
+    pub fn new(accrual: Accrual) -> Self {
+        Self {
+            index: GrowthIndex::new(accrual),
+            total_shares: BigInteger::zero(),
+        }
+    }
+
+    pub fn accrue(&mut self, rate_per_second: BigDecimal, dt: u64) -> Result<(), CommonError> {
+        self.index.accrue(rate_per_second, dt)
+    }
+
+    pub fn total_owed(&self) -> Result<BigInteger, CommonError> {
+        owed(self.total_shares, &self.index)
+    }
+
+    // Returns the debt shares minted for borrowing `amount`.
+    pub fn borrow(&mut self, amount: BigInteger) -> Result<BigInteger, CommonError> {
+        let shares = self.index.normalize(amount, RoundingMode::Ceil)?;
+        self.total_shares = self.total_shares.checked_add(shares)?;
+        Ok(shares)
+    }
+
+    // Repays up to `amount` against a position of `shares`. Paying at least what is owed closes
+    // the position.
+    pub fn repay(
+        &mut self,
+        shares: BigInteger,
+        amount: BigInteger,
+    ) -> Result<Repayment, CommonError> {
+        let owed = owed(shares, &self.index)?;
+        let repayment = if amount >= owed {
+            Repayment {
+                shares_burned: shares,
+                repaid: owed,
+            }
+        } else {
+            Repayment {
+                shares_burned: self.index.normalize(amount, RoundingMode::Floor)?,
+                repaid: amount,
+            }
+        };
+        self.total_shares = self.total_shares.checked_sub(repayment.shares_burned)?;
+        Ok(repayment)
+    }
+}
+
+pub fn owed(shares: BigInteger, index: &GrowthIndex) -> Result<BigInteger, CommonError> {
+    index.denormalize(shares, RoundingMode::Ceil)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::debt::{owed, DebtPool, Repayment};
+    use crate::growth_index::Accrual;
+
+    const DAY: u64 = 24 * 3600;
+
+    #[test]
+    fn test_borrow_and_repay_round_for_protocol() {
+        let mut pool = DebtPool::new(Accrual::Linear);
+        pool.accrue(BigDecimal::percent(50), 1).unwrap();
+
+        let shares = pool.borrow(BigInteger::from(100u64)).unwrap();
+        assert_eq!(shares, BigInteger::from(67u64));
+        assert_eq!(owed(shares, &pool.index).unwrap(), BigInteger::from(101u64));
+
+        let repayment = pool.repay(shares, BigInteger::from(100u64)).unwrap();
+        assert_eq!(
+            repayment,
+            Repayment {
+                shares_burned: BigInteger::from(66u64),
+                repaid: BigInteger::from(100u64),
+            }
+        );
+        assert_eq!(pool.total_shares, BigInteger::one());
+        assert_eq!(pool.total_owed().unwrap(), BigInteger::from(2u64));
+
+        let repayment = pool
+            .repay(BigInteger::one(), BigInteger::from(10u64))
+            .unwrap();
+        assert_eq!(repayment.repaid, BigInteger::from(2u64));
+        assert!(pool.total_shares.is_zero());
+    }
+
+    #[test]
+    fn test_accrual_over_long_gaps() {
+        // ~10% a year, compounded per second
+        let rate = BigDecimal::from_ratio(3_022_265_980u64, 1_000_000_000_000_000_000u64);
+        let principal = BigInteger::from(1_000_000_000u64);
+
+        let mut once = DebtPool::new(Accrual::Compound);
+        let shares = once.borrow(principal).unwrap();
+        once.accrue(rate, 10 * 365 * DAY).unwrap();
+
+        let mut daily = DebtPool::new(Accrual::Compound);
+        daily.borrow(principal).unwrap();
+        for _ in 0..10 * 365 {
+            daily.accrue(rate, DAY).unwrap();
+        }
+
+        let owed_once = owed(shares, &once.index).unwrap();
+        let owed_daily = owed(shares, &daily.index).unwrap();
+        // 1.1^10 of the principal, whether the pool is touched daily or not
+        assert!(owed_once > BigInteger::from(2_593_000_000u64));
+        assert!(owed_once < BigInteger::from(2_594_000_000u64));
+        let drift = owed_once.max(owed_daily) - owed_once.min(owed_daily);
+        assert!(drift <= BigInteger::from(10u64));
+
+        // linear accrual is cheaper but undercharges over long gaps
+        let mut linear = DebtPool::new(Accrual::Linear);
+        linear.borrow(principal).unwrap();
+        linear.accrue(rate, 10 * 365 * DAY).unwrap();
+        assert!(owed(shares, &linear.index).unwrap() < owed_once);
+    }
+}
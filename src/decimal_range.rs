@@ -0,0 +1,97 @@
+use crate::bigdecimal::BigDecimal;
+use crate::error::CommonError;
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub struct DecimalRange {
+    pub min: BigDecimal,
+    pub max: BigDecimal,
+}
+
+impl DecimalRange {
+    pub fn new(min: BigDecimal, max: BigDecimal) -> Result<Self, CommonError> {
+        let range = Self { min, max };
+        range.validate()?;
+        Ok(range)
+    }
+
+    pub fn validate(&self) -> Result<(), CommonError> {
+        if self.min > self.max {
+            return Err(CommonError::InvalidRange {
+                min: self.min.to_string(),
+                max: self.max.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn contains(&self, value: BigDecimal) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    // a deserialized range may not have gone through `new`, and `Ord::clamp` panics on min > max
+    pub fn clamp(&self, value: BigDecimal) -> Result<BigDecimal, CommonError> {
+        self.validate()?;
+        Ok(value.clamp(self.min, self.max))
+    }
+
+    pub fn width(&self) -> BigDecimal {
+        self.max.saturating_sub(self.min)
+    }
+
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+        if min > max {
+            None
+        } else {
+            Some(Self { min, max })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::decimal_range::DecimalRange;
+    use crate::error::CommonError;
+
+    #[test]
+    fn test_range() {
+        let range = DecimalRange::new(BigDecimal::percent(50), BigDecimal::percent(150)).unwrap();
+        assert!(range.contains(BigDecimal::one()));
+        assert!(range.contains(BigDecimal::percent(150)));
+        assert!(!range.contains(BigDecimal::percent(151)));
+        assert_eq!(
+            range.clamp(BigDecimal::zero()).unwrap(),
+            BigDecimal::percent(50)
+        );
+        assert_eq!(range.width(), BigDecimal::one());
+
+        assert!(DecimalRange::new(BigDecimal::one(), BigDecimal::zero()).is_err());
+        let inverted = DecimalRange {
+            min: BigDecimal::one(),
+            max: BigDecimal::zero(),
+        };
+        assert_eq!(
+            inverted.clamp(BigDecimal::percent(50)),
+            Err(CommonError::InvalidRange {
+                min: "1".to_string(),
+                max: "0".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_intersect() {
+        let a = DecimalRange::new(BigDecimal::zero(), BigDecimal::one()).unwrap();
+        let b = DecimalRange::new(BigDecimal::percent(50), BigDecimal::percent(200)).unwrap();
+        let c = DecimalRange::new(BigDecimal::percent(300), BigDecimal::percent(400)).unwrap();
+        assert_eq!(
+            a.intersect(&b),
+            Some(DecimalRange::new(BigDecimal::percent(50), BigDecimal::one()).unwrap())
+        );
+        assert_eq!(a.intersect(&c), None);
+    }
+}
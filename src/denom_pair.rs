@@ -0,0 +1,41 @@
+use crate::serializable_denom::SerializableDenom;
+use core::fmt::{Display, Formatter};
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+#[derive(Eq, Ord, PartialOrd)]
+pub struct DenomPair {
+    pub base: SerializableDenom,
+    pub quote: SerializableDenom,
+}
+
+impl DenomPair {
+    pub fn new(base: SerializableDenom, quote: SerializableDenom) -> Self {
+        Self { base, quote }
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self::new(self.quote.clone(), self.base.clone())
+    }
+
+    pub fn contains(&self, denom: &SerializableDenom) -> bool {
+        self.base == *denom || self.quote == *denom
+    }
+
+    // the other side of the pair, `None` if `denom` is not part of it
+    pub fn other(&self, denom: &SerializableDenom) -> Option<&SerializableDenom> {
+        if self.base == *denom {
+            Some(&self.quote)
+        } else if self.quote == *denom {
+            Some(&self.base)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for DenomPair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}/{}", self.base, self.quote)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::asset::Asset;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, CosmosMsg};
+
+#[cw_serde]
+pub enum DustAction {
+    Burn,
+    // left with the contract, e.g. added to the next distribution
+    Carry,
+    SendTo(Addr),
+}
+
+// Amounts strictly below `threshold` are treated as dust instead of being paid out.
+#[cw_serde]
+pub struct DustPolicy {
+    pub threshold: BigInteger,
+    pub action: DustAction,
+}
+
+impl DustPolicy {
+    pub fn new(threshold: BigInteger, action: DustAction) -> Self {
+        Self { threshold, action }
+    }
+
+    pub fn is_dust(&self, amount: BigInteger) -> bool {
+        !amount.is_zero() && amount < self.threshold
+    }
+
+    // Returns the amount to pay out and the message disposing of the dust, if any.
+    pub fn apply(&self, asset: &Asset) -> Result<(BigInteger, Option<CosmosMsg>), CommonError> {
+        if !self.is_dust(asset.amount) {
+            return Ok((asset.amount, None));
+        }
+        let msg = match &self.action {
+            DustAction::Burn => Some(asset.burn_msg()?),
+            DustAction::Carry => None,
+            DustAction::SendTo(recipient) => Some(asset.transfer_msg(recipient)?),
+        };
+        Ok((BigInteger::zero(), msg))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::asset::Asset;
+    use crate::biginteger::BigInteger;
+    use crate::dust::{DustAction, DustPolicy};
+    use cosmwasm_std::{coins, Addr, BankMsg, CosmosMsg};
+
+    #[test]
+    fn test_apply() {
+        let threshold = BigInteger::from(10u64);
+        let payout = Asset::native("uatom", 10u64);
+        let dust = Asset::native("uatom", 9u64);
+
+        let policy = DustPolicy::new(threshold, DustAction::Carry);
+        assert_eq!(policy.apply(&payout).unwrap(), (payout.amount, None));
+        assert_eq!(policy.apply(&dust).unwrap(), (BigInteger::zero(), None));
+
+        let policy = DustPolicy::new(threshold, DustAction::Burn);
+        assert_eq!(
+            policy.apply(&dust).unwrap().1,
+            Some(CosmosMsg::Bank(BankMsg::Burn {
+                amount: coins(9, "uatom")
+            }))
+        );
+
+        let policy = DustPolicy::new(threshold, DustAction::SendTo(Addr::unchecked("treasury")));
+        assert_eq!(
+            policy.apply(&dust).unwrap().1,
+            Some(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "treasury".to_string(),
+                amount: coins(9, "uatom")
+            }))
+        );
+        assert_eq!(
+            policy.apply(&Asset::native("uatom", 0u64)).unwrap(),
+            (BigInteger::zero(), None)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "cw20")]
+    fn test_cw20_dust() {
+        use cosmwasm_std::WasmMsg;
+
+        let policy = DustPolicy::new(BigInteger::from(10u64), DustAction::Burn);
+        let (payout, msg) = policy.apply(&Asset::cw20("token", 3u64)).unwrap();
+        assert!(payout.is_zero());
+        assert!(matches!(
+            msg,
+            Some(CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. })) if contract_addr == "token"
+        ));
+    }
+}
@@ -0,0 +1,226 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{OverflowError, OverflowOperation, Uint256, Uint512};
+
+// Emission per block (or second), starting at `start`. Cumulative emission is computed in closed
+// form as the floor of the exact rational total, so splitting an interval never changes the sum.
+#[cw_serde]
+pub enum EmissionSchedule {
+    Constant {
+        start: u64,
+        end: Option<u64>,
+        rate: BigInteger,
+    },
+    // rate decreases linearly from `initial_rate` at `start` to zero at `end`
+    LinearDecay {
+        start: u64,
+        end: u64,
+        initial_rate: BigInteger,
+    },
+    // rate halves every `period`
+    Halving {
+        start: u64,
+        period: u64,
+        initial_rate: BigInteger,
+    },
+}
+
+impl EmissionSchedule {
+    pub fn validate(&self) -> Result<(), CommonError> {
+        match self {
+            EmissionSchedule::Constant {
+                start,
+                end: Some(end),
+                ..
+            }
+            | EmissionSchedule::LinearDecay { start, end, .. }
+                if end <= start =>
+            {
+                Err(CommonError::InvalidRange {
+                    min: start.to_string(),
+                    max: end.to_string(),
+                })
+            }
+            EmissionSchedule::Halving { period: 0, .. } => Err(CommonError::ZeroPeriod),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn emission_between(&self, t0: u64, t1: u64) -> Result<BigInteger, CommonError> {
+        if t1 <= t0 {
+            return Ok(BigInteger::zero());
+        }
+        let total = self.cumulative(t1)?;
+        Ok(BigInteger(total.0 - self.cumulative(t0)?.0))
+    }
+
+    // total emitted before `t`
+    pub fn cumulative(&self, t: u64) -> Result<BigInteger, CommonError> {
+        let total = match self {
+            EmissionSchedule::Constant { start, end, rate } => {
+                let t = end.map_or(t, |end| t.min(end));
+                Uint512::from(rate.0) * Uint512::from(t.saturating_sub(*start))
+            }
+            EmissionSchedule::LinearDecay {
+                start,
+                end,
+                initial_rate,
+            } => {
+                let duration = Uint512::from(end.saturating_sub(*start));
+                if duration.is_zero() {
+                    return Ok(BigInteger::zero());
+                }
+                let n = Uint512::from(t.min(*end).saturating_sub(*start));
+                // sum of rate * (duration - i) / duration for i in 0..n
+                let units =
+                    n * duration - n * n.saturating_sub(Uint512::one()) / Uint512::from(2u8);
+                Uint512::from(initial_rate.0) * units / duration
+            }
+            EmissionSchedule::Halving {
+                start,
+                period,
+                initial_rate,
+            } => {
+                if *period == 0 {
+                    return Ok(BigInteger::zero());
+                }
+                let elapsed = t.saturating_sub(*start);
+                let halvings = elapsed / period;
+                let remainder = Uint512::from(elapsed % period);
+                let rate = Uint512::from(initial_rate.0);
+                let period = Uint512::from(*period);
+                // 2 * period * rate - rate * (2 * period - remainder) / 2^halvings, rounded down
+                let limit = rate * period * Uint512::from(2u8);
+                let tail = rate * (period * Uint512::from(2u8) - remainder);
+                let shifted = match u32::try_from(halvings) {
+                    Ok(shift) if shift < 512 => {
+                        let quotient = tail >> shift;
+                        if (quotient << shift) == tail {
+                            quotient
+                        } else {
+                            quotient + Uint512::one()
+                        }
+                    }
+                    _ => Uint512::from(!tail.is_zero() as u8),
+                };
+                limit - shifted
+            }
+        };
+        Uint256::try_from(total)
+            .map(BigInteger)
+            .map_err(|_| OverflowError::new(OverflowOperation::Mul).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::emission::EmissionSchedule;
+    use crate::error::CommonError;
+
+    fn assert_additive(schedule: &EmissionSchedule, until: u64) {
+        let total = schedule.emission_between(0, until).unwrap();
+        let mut sum = BigInteger::zero();
+        for t in 0..until {
+            sum += schedule.emission_between(t, t + 1).unwrap();
+        }
+        assert_eq!(sum, total);
+    }
+
+    #[test]
+    fn test_constant() {
+        let schedule = EmissionSchedule::Constant {
+            start: 10,
+            end: Some(20),
+            rate: BigInteger::from(5u64),
+        };
+        assert_eq!(
+            schedule.emission_between(0, 15).unwrap(),
+            BigInteger::from(25u64)
+        );
+        assert_eq!(
+            schedule.emission_between(12, 100).unwrap(),
+            BigInteger::from(40u64)
+        );
+        assert_eq!(
+            schedule.emission_between(30, 20).unwrap(),
+            BigInteger::zero()
+        );
+        assert_additive(&schedule, 30);
+    }
+
+    #[test]
+    fn test_linear_decay() {
+        let initial_rate = 1_000u128;
+        let (start, end) = (5u64, 17u64);
+        let schedule = EmissionSchedule::LinearDecay {
+            start,
+            end,
+            initial_rate: BigInteger::from(initial_rate),
+        };
+        let duration = (end - start) as u128;
+        for n in 0..=20u64 {
+            // reference: sum of exact per-block rates with a common denominator
+            let numerator: u128 = (0..n.min(end - start) as u128)
+                .map(|i| initial_rate * (duration - i))
+                .sum();
+            assert_eq!(
+                schedule.cumulative(start + n).unwrap(),
+                BigInteger::from(numerator / duration)
+            );
+        }
+        assert_eq!(
+            schedule.emission_between(0, 100).unwrap(),
+            BigInteger::from(initial_rate * (duration + 1) / 2)
+        );
+        assert_additive(&schedule, 25);
+    }
+
+    #[test]
+    fn test_halving() {
+        let initial_rate = 1_000u128;
+        let period = 3u64;
+        let schedule = EmissionSchedule::Halving {
+            start: 2,
+            period,
+            initial_rate: BigInteger::from(initial_rate),
+        };
+        for n in 0..40u64 {
+            // reference: exact per-block rates scaled by 2^40
+            let numerator: u128 = (0..n).map(|i| (initial_rate << 40) >> (i / period)).sum();
+            assert_eq!(
+                schedule.cumulative(2 + n).unwrap(),
+                BigInteger::from(numerator >> 40)
+            );
+        }
+        assert_eq!(
+            schedule.cumulative(u64::MAX).unwrap(),
+            BigInteger::from(2 * period as u128 * initial_rate - 1)
+        );
+        assert_additive(&schedule, 40);
+    }
+
+    #[test]
+    fn test_validate() {
+        let schedule = EmissionSchedule::LinearDecay {
+            start: 5,
+            end: 5,
+            initial_rate: BigInteger::one(),
+        };
+        assert!(schedule.validate().is_err());
+        let schedule = EmissionSchedule::Halving {
+            start: 0,
+            period: 0,
+            initial_rate: BigInteger::one(),
+        };
+        assert_eq!(schedule.validate(), Err(CommonError::ZeroPeriod));
+        let schedule = EmissionSchedule::Constant {
+            start: 0,
+            end: None,
+            rate: BigInteger::MAX,
+        };
+        assert!(schedule.validate().is_ok());
+        assert!(schedule.emission_between(0, 2).is_err());
+    }
+}
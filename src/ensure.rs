@@ -0,0 +1,115 @@
+// Early returns for contract invariants. Each macro returns `Err(err.into())` from the enclosing
+// function, so the error type only needs `From<CommonError>` when the default error is used.
+
+// `ensure_bigint!(lhs, <=, rhs)` compares two values of the crate's number types, failing with
+// `CommonError::InvariantViolated` naming both expressions and values, or with the given error.
+#[macro_export]
+macro_rules! ensure_bigint {
+    ($lhs:expr, $op:tt, $rhs:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if !(*lhs $op *rhs) {
+            return Err(::core::convert::From::from(
+                $crate::error::CommonError::InvariantViolated(format!(
+                    "{} {} {} ({} vs {})",
+                    stringify!($lhs),
+                    stringify!($op),
+                    stringify!($rhs),
+                    lhs,
+                    rhs
+                )),
+            ));
+        }
+    }};
+    ($lhs:expr, $op:tt, $rhs:expr, $err:expr) => {
+        if !($lhs $op $rhs) {
+            return Err(::core::convert::From::from($err));
+        }
+    };
+}
+
+// Fails with `CommonError::InvalidRatio` unless the `BigDecimal` lies in [0, 1].
+#[macro_export]
+macro_rules! ensure_ratio {
+    ($value:expr) => {{
+        let value = &$value;
+        if !value.is_ratio() {
+            return Err(::core::convert::From::from(
+                $crate::error::CommonError::InvalidRatio(value.to_string()),
+            ));
+        }
+    }};
+    ($value:expr, $err:expr) => {
+        if !$value.is_ratio() {
+            return Err(::core::convert::From::from($err));
+        }
+    };
+}
+
+// Fails with `CommonError::Zero` naming the expression when it is zero.
+#[macro_export]
+macro_rules! ensure_nonzero {
+    ($value:expr) => {
+        if $value.is_zero() {
+            return Err(::core::convert::From::from(
+                $crate::error::CommonError::Zero(stringify!($value).to_string()),
+            ));
+        }
+    };
+    ($value:expr, $err:expr) => {
+        if $value.is_zero() {
+            return Err(::core::convert::From::from($err));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use cosmwasm_std::{StdError, StdResult};
+
+    fn withdraw(amount: BigInteger, balance: BigInteger) -> Result<BigInteger, CommonError> {
+        ensure_nonzero!(amount);
+        ensure_bigint!(amount, <=, balance);
+        Ok(balance - amount)
+    }
+
+    fn set_fee(fee: BigDecimal) -> StdResult<BigDecimal> {
+        ensure_ratio!(fee);
+        ensure_bigint!(fee, <, BigDecimal::percent(50), StdError::generic_err("fee too high"));
+        Ok(fee)
+    }
+
+    #[test]
+    fn test_default_errors() {
+        let ten = BigInteger::from(10u64);
+        assert_eq!(
+            withdraw(BigInteger::from(4u64), ten),
+            Ok(BigInteger::from(6u64))
+        );
+        assert_eq!(
+            withdraw(BigInteger::from(11u64), ten),
+            Err(CommonError::InvariantViolated(
+                "amount <= balance (11 vs 10)".to_string()
+            ))
+        );
+        assert_eq!(
+            withdraw(BigInteger::zero(), ten),
+            Err(CommonError::Zero("amount".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_custom_errors() {
+        assert!(set_fee(BigDecimal::percent(10)).is_ok());
+        assert_eq!(
+            set_fee(BigDecimal::percent(60)),
+            Err(StdError::generic_err("fee too high"))
+        );
+        assert!(set_fee(BigDecimal::percent(150))
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid ratio: 1.5"));
+    }
+}
@@ -0,0 +1,327 @@
+use cosmwasm_std::{
+    CheckedFromRatioError, ConversionOverflowError, DivideByZeroError, DivisionError,
+    OverflowError, SignedDecimal256RangeExceeded, StdError,
+};
+use cw_utils::ParseReplyError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum CommonError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Attribute not found: {0}")]
+    MissingAttribute(String),
+
+    #[error("{event} event has undeclared attribute {key}")]
+    UndeclaredAttribute { event: String, key: String },
+
+    #[error("Expected a {expected} event, got {actual}")]
+    UnexpectedEventType { expected: String, actual: String },
+
+    #[error("Unknown event type: {0}")]
+    UnknownEventType(String),
+
+    #[error("{0}")]
+    ParseReply(#[from] ParseReplyError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("{0}")]
+    DivideByZero(#[from] DivideByZeroError),
+
+    #[error("{0}")]
+    ConversionOverflow(#[from] ConversionOverflowError),
+
+    #[error("{0}")]
+    CheckedFromRatio(#[from] CheckedFromRatioError),
+
+    #[error("{0}")]
+    Division(#[from] DivisionError),
+
+    #[error("{0}")]
+    SignedRangeExceeded(#[from] SignedDecimal256RangeExceeded),
+
+    #[error("Cannot parse empty input")]
+    EmptyInput,
+
+    #[error("Cannot parse more than {max} fractional digits")]
+    TooManyFractionDigits { max: u32 },
+
+    #[error("Invalid character {character:?} at position {position}")]
+    InvalidChar { position: usize, character: char },
+
+    #[error("Invalid decimals: {decimals} (max {max})")]
+    InvalidDecimals { decimals: u32, max: u32 },
+
+    #[error("Invalid bech32: {0}")]
+    InvalidBech32(String),
+
+    #[error("Invalid bech32 prefix: expected {expected}, got {actual}")]
+    InvalidBech32Prefix { expected: String, actual: String },
+
+    #[error("Shortened address cannot be used as input: {0}")]
+    ShortenedAddress(String),
+
+    #[error("Invalid timeout: {0}")]
+    InvalidTimeout(String),
+
+    #[error("Unsupported version: expected {expected}, got {actual}")]
+    UnsupportedVersion { expected: String, actual: String },
+
+    #[error("No migration registered from state version {0}")]
+    MissingMigration(u32),
+
+    #[error("Invalid contract: expected {expected}, got {actual}")]
+    InvalidContract { expected: String, actual: String },
+
+    #[error("Invalid semver version: {0}")]
+    InvalidVersion(String),
+
+    #[error("Version {new} is not newer than the stored version {stored}")]
+    NotNewerVersion { stored: String, new: String },
+
+    #[error("Invalid memo: {0}")]
+    InvalidMemo(String),
+
+    #[error("Unsupported denom: {0}")]
+    UnsupportedDenom(String),
+
+    #[error("Invalid asset: {0}")]
+    InvalidAsset(String),
+
+    #[error("Checkpoint at height {height} is before the last checkpoint at {last}")]
+    InvalidCheckpoint { last: u64, height: u64 },
+
+    #[error("Invalid order: {0}")]
+    InvalidOrder(String),
+
+    #[error("Invalid bid: {0}")]
+    InvalidBid(String),
+
+    #[error("Auction has ended")]
+    AuctionEnded,
+
+    #[error("Auction has not ended yet")]
+    AuctionNotEnded,
+
+    #[error("Invalid quote: {0}")]
+    InvalidQuote(String),
+
+    #[error("No rate for {0}")]
+    MissingRate(String),
+
+    #[error("Rate for {pair} is stale: updated {age}s ago (max {max_age}s)")]
+    StaleRate {
+        pair: String,
+        age: u64,
+        max_age: u64,
+    },
+
+    #[error("Not enough price sources: {actual} (min {required})")]
+    InsufficientSources { required: usize, actual: usize },
+
+    #[error("Capacity of {max} exceeded")]
+    CapacityExceeded { max: usize },
+
+    #[error("Invalid route: {0}")]
+    InvalidRoute(String),
+
+    #[error("Invalid weights: {0}")]
+    InvalidWeights(String),
+
+    #[error("Invalid csv at line {line}: {reason}")]
+    InvalidCsv { line: usize, reason: String },
+
+    #[error("Invalid fee curve: {0}")]
+    InvalidFeeCurve(String),
+
+    #[error("Unknown job: {0}")]
+    UnknownJob(u64),
+
+    #[error("Invalid id: {0}")]
+    InvalidId(String),
+
+    #[error("Invalid public key: {0}")]
+    InvalidPublicKey(String),
+
+    #[error("Invalid signature: {0}")]
+    InvalidSignature(String),
+
+    #[error("Index {0} has already been claimed")]
+    AlreadyClaimed(u64),
+
+    #[error("Permit expired")]
+    PermitExpired,
+
+    #[error("Commitment expired ({0})")]
+    CommitmentExpired(String),
+
+    #[error("Preimage does not match commitment")]
+    CommitmentMismatch,
+
+    #[error("Invalid nonce: expected {expected}, got {actual}")]
+    InvalidNonce { expected: u64, actual: u64 },
+
+    #[error("Circuit breaker tripped: price {price} moved more than {max_move} from {reference}")]
+    CircuitBreakerTripped {
+        reference: String,
+        price: String,
+        max_move: String,
+    },
+
+    #[error("Price {price} deviates {deviation_bps} bps from {reference} (max {max_bps} bps)")]
+    PriceDeviation {
+        price: String,
+        reference: String,
+        deviation_bps: String,
+        max_bps: u64,
+    },
+
+    #[error("Unknown reply id: {0}")]
+    UnknownReplyId(u64),
+
+    #[error("Sub-message failed: {0}")]
+    SubMsgFailure(String),
+
+    #[error("Reply contains no response data")]
+    MissingReplyData,
+
+    #[error("Too many addresses: {actual} (max {max})")]
+    TooManyAddresses { max: usize, actual: usize },
+
+    #[error("Duplicate entry: {0}")]
+    Duplicate(String),
+
+    #[error("Entries are not sorted at: {0}")]
+    NotSorted(String),
+
+    #[error("Invalid symbol: {0}")]
+    InvalidSymbol(String),
+
+    #[error("Invalid name length: {actual} (expected {min}..={max})")]
+    InvalidNameLength {
+        min: usize,
+        max: usize,
+        actual: usize,
+    },
+
+    #[error("Invalid url: {0}")]
+    InvalidUrl(String),
+
+    #[error("Invalid subdenom: {0}")]
+    InvalidSubdenom(String),
+
+    #[error("Invalid range: min {min} is greater than max {max}")]
+    InvalidRange { min: String, max: String },
+
+    #[error("Invalid ratio: {0} (expected a value in [0, 1])")]
+    InvalidRatio(String),
+
+    #[error("Invalid buckets: {0}")]
+    InvalidBuckets(String),
+
+    #[error("Histogram is empty")]
+    EmptyHistogram,
+
+    #[error("Insufficient {denom} balance: available {available}, required {required}")]
+    InsufficientBalance {
+        denom: String,
+        available: String,
+        required: String,
+    },
+
+    #[error("Corrupted state: {0}")]
+    CorruptedState(String),
+
+    #[error("Invariant violated: {0}")]
+    InvariantViolated(String),
+
+    #[error("{0} must not be zero")]
+    Zero(String),
+
+    #[error("Period must not be zero")]
+    ZeroPeriod,
+
+    #[error("{context}: {source}")]
+    Context {
+        context: String,
+        source: Box<CommonError>,
+    },
+}
+
+impl CommonError {
+    // The error underneath any `Context` wrappers, for matching on the original variant.
+    pub fn root(&self) -> &CommonError {
+        match self {
+            CommonError::Context { source, .. } => source.root(),
+            err => err,
+        }
+    }
+}
+
+// `.ctx("while doing X")` on any result whose error converts into `CommonError`. The context
+// is kept in the message when the error is later turned into a `StdError`.
+pub trait ResultExt<T> {
+    fn ctx(self, context: impl Into<String>) -> Result<T, CommonError>;
+
+    // Same as `ctx`, building the context only on error.
+    fn with_ctx<F: FnOnce() -> String>(self, context: F) -> Result<T, CommonError>;
+}
+
+impl<T, E: Into<CommonError>> ResultExt<T> for Result<T, E> {
+    fn ctx(self, context: impl Into<String>) -> Result<T, CommonError> {
+        self.with_ctx(|| context.into())
+    }
+
+    fn with_ctx<F: FnOnce() -> String>(self, context: F) -> Result<T, CommonError> {
+        self.map_err(|err| CommonError::Context {
+            context: context(),
+            source: Box::new(err.into()),
+        })
+    }
+}
+
+impl From<CommonError> for StdError {
+    fn from(value: CommonError) -> Self {
+        match value {
+            CommonError::Std(err) => err,
+            err => StdError::generic_err(err.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::error::{CommonError, ResultExt};
+    use cosmwasm_std::{StdError, StdResult};
+
+    fn load_config() -> StdResult<u64> {
+        Err(StdError::not_found("Config"))
+    }
+
+    #[test]
+    fn test_ctx() {
+        let err = load_config()
+            .ctx("while loading config")
+            .with_ctx(|| format!("while executing {}", "swap"))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "while executing swap: while loading config: Config not found"
+        );
+        assert_eq!(err.root(), &CommonError::Std(StdError::not_found("Config")));
+        assert!(StdError::from(err)
+            .to_string()
+            .contains("while executing swap: while loading config"));
+
+        let overflow = BigInteger::MAX
+            .checked_add(BigInteger::one())
+            .ctx("adding rewards")
+            .unwrap_err();
+        assert!(overflow.to_string().starts_with("adding rewards: "));
+        assert_eq!(Ok::<u64, CommonError>(1).ctx("unused"), Ok(1));
+    }
+}
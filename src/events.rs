@@ -0,0 +1,411 @@
+use crate::addr_display::is_shortened;
+use crate::asset::Asset;
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::serializable_denom::SerializableDenom;
+use core::marker::PhantomData;
+use core::str::FromStr;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Attribute, Event};
+
+pub trait AttrValue: Sized {
+    // what an event schema declares for attributes of this type
+    const KIND: AttrKind = AttrKind::Text;
+
+    fn to_attr_value(&self) -> String;
+
+    fn from_attr_value(value: &str) -> Result<Self, CommonError>;
+}
+
+macro_rules! impl_attr_value {
+    ($($t:ty => $kind:ident),*) => {
+        $(
+            impl AttrValue for $t {
+                const KIND: AttrKind = AttrKind::$kind;
+
+                fn to_attr_value(&self) -> String {
+                    self.to_string()
+                }
+
+                fn from_attr_value(value: &str) -> Result<Self, CommonError> {
+                    <$t>::from_str(value).map_err(Into::into)
+                }
+            }
+        )*
+    };
+}
+
+impl_attr_value!(
+    BigInteger => BigInteger,
+    BigDecimal => BigDecimal,
+    SerializableDenom => Denom,
+    Asset => Asset
+);
+
+impl AttrValue for String {
+    fn to_attr_value(&self) -> String {
+        self.clone()
+    }
+
+    fn from_attr_value(value: &str) -> Result<Self, CommonError> {
+        Ok(value.to_string())
+    }
+}
+
+// Always the full address; use `addr_display::short_attr` for a separate display attribute.
+impl AttrValue for Addr {
+    const KIND: AttrKind = AttrKind::Addr;
+
+    fn to_attr_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_attr_value(value: &str) -> Result<Self, CommonError> {
+        if is_shortened(value) {
+            return Err(CommonError::ShortenedAddress(value.to_string()));
+        }
+        Ok(Addr::unchecked(value))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct AttrBuilder {
+    attributes: Vec<Attribute>,
+}
+
+impl AttrBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(mut self, key: impl Into<String>, value: &impl AttrValue) -> Self {
+        self.attributes
+            .push(Attribute::new(key, value.to_attr_value()));
+        self
+    }
+
+    pub fn add_opt(self, key: impl Into<String>, value: Option<&impl AttrValue>) -> Self {
+        match value {
+            Some(value) => self.add(key, value),
+            None => self,
+        }
+    }
+
+    pub fn build(self) -> Vec<Attribute> {
+        self.attributes
+    }
+
+    pub fn into_event(self, ty: impl Into<String>) -> Event {
+        Event::new(ty).add_attributes(self.attributes)
+    }
+}
+
+pub fn find_attr<'a>(attributes: &'a [Attribute], key: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.as_str())
+}
+
+pub fn parse_attr<T: AttrValue>(attributes: &[Attribute], key: &str) -> Result<T, CommonError> {
+    let value =
+        find_attr(attributes, key).ok_or_else(|| CommonError::MissingAttribute(key.to_string()))?;
+    T::from_attr_value(value)
+}
+
+pub fn parse_event_attr<T: AttrValue>(event: &Event, key: &str) -> Result<T, CommonError> {
+    parse_attr(&event.attributes, key)
+}
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum AttrKind {
+    Text,
+    BigInteger,
+    BigDecimal,
+    Denom,
+    Asset,
+    Addr,
+}
+
+impl AttrKind {
+    pub fn check(&self, value: &str) -> Result<(), CommonError> {
+        match self {
+            AttrKind::Text => Ok(()),
+            AttrKind::BigInteger => BigInteger::from_attr_value(value).map(|_| ()),
+            AttrKind::BigDecimal => BigDecimal::from_attr_value(value).map(|_| ()),
+            AttrKind::Denom => SerializableDenom::from_attr_value(value).map(|_| ()),
+            AttrKind::Asset => Asset::from_attr_value(value).map(|_| ()),
+            AttrKind::Addr => Addr::from_attr_value(value).map(|_| ()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AttrSpec {
+    pub key: &'static str,
+    pub kind: AttrKind,
+}
+
+// An attribute key tied to its value type, declared once as a constant and used both to emit
+// and to parse the attribute.
+pub struct AttrKey<T> {
+    pub key: &'static str,
+    phantom_data: PhantomData<T>,
+}
+
+impl<T: AttrValue> AttrKey<T> {
+    pub const fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub const fn spec(&self) -> AttrSpec {
+        AttrSpec {
+            key: self.key,
+            kind: T::KIND,
+        }
+    }
+
+    pub fn attr(&self, value: &T) -> Attribute {
+        Attribute::new(self.key, value.to_attr_value())
+    }
+
+    pub fn parse(&self, event: &Event) -> Result<T, CommonError> {
+        parse_event_attr(event, self.key)
+    }
+}
+
+// The attributes an event of type `ty` must carry. Keys starting with `_`, such as the
+// `_contract_address` the chain adds, are ignored when validating.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventSchema {
+    pub ty: &'static str,
+    pub attributes: &'static [AttrSpec],
+}
+
+impl EventSchema {
+    pub const fn new(ty: &'static str, attributes: &'static [AttrSpec]) -> Self {
+        Self { ty, attributes }
+    }
+
+    // Contract events reach indexers as `wasm-{ty}`, both forms are accepted.
+    pub fn matches(&self, event: &Event) -> bool {
+        event.ty == self.ty || event.ty.strip_prefix("wasm-") == Some(self.ty)
+    }
+
+    pub fn validate(&self, event: &Event) -> Result<(), CommonError> {
+        if !self.matches(event) {
+            return Err(CommonError::UnexpectedEventType {
+                expected: self.ty.to_string(),
+                actual: event.ty.clone(),
+            });
+        }
+        for spec in self.attributes {
+            let value = find_attr(&event.attributes, spec.key)
+                .ok_or_else(|| CommonError::MissingAttribute(spec.key.to_string()))?;
+            spec.kind.check(value)?;
+        }
+        if let Some(attr) = event.attributes.iter().find(|attr| {
+            !attr.key.starts_with('_') && !self.attributes.iter().any(|spec| spec.key == attr.key)
+        }) {
+            return Err(CommonError::UndeclaredAttribute {
+                event: self.ty.to_string(),
+                key: attr.key.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    // Builds the event and checks it against the schema, so a renamed or missing attribute fails
+    // in the contract's own tests instead of in an indexer.
+    pub fn emit(&self, builder: AttrBuilder) -> Result<Event, CommonError> {
+        let event = builder.into_event(self.ty);
+        self.validate(&event)?;
+        Ok(event)
+    }
+
+    pub fn describe(&self) -> EventDescription {
+        EventDescription {
+            ty: self.ty.to_string(),
+            attributes: self
+                .attributes
+                .iter()
+                .map(|spec| (spec.key.to_string(), spec.kind))
+                .collect(),
+        }
+    }
+}
+
+// Serializable form of an `EventSchema`, e.g. published for indexers next to the JSON schema.
+#[cw_serde]
+pub struct EventDescription {
+    pub ty: String,
+    pub attributes: Vec<(String, AttrKind)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventRegistry {
+    pub schemas: &'static [EventSchema],
+}
+
+impl EventRegistry {
+    pub const fn new(schemas: &'static [EventSchema]) -> Self {
+        Self { schemas }
+    }
+
+    pub fn get(&self, event: &Event) -> Option<&EventSchema> {
+        self.schemas.iter().find(|schema| schema.matches(event))
+    }
+
+    pub fn validate(&self, event: &Event) -> Result<(), CommonError> {
+        self.get(event)
+            .ok_or_else(|| CommonError::UnknownEventType(event.ty.clone()))?
+            .validate(event)
+    }
+
+    pub fn describe(&self) -> Vec<EventDescription> {
+        self.schemas.iter().map(EventSchema::describe).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::addr_display::short_attr;
+    use crate::asset::Asset;
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::events::{
+        parse_event_attr, AttrBuilder, AttrKey, AttrKind, EventRegistry, EventSchema,
+    };
+    use crate::serializable_denom::SerializableDenom;
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn test_round_trip() {
+        let asset = Asset::native("u_atom", 1_000_000u128);
+        let event = AttrBuilder::new()
+            .add("amount", &BigInteger::from(42u64))
+            .add("ratio", &BigDecimal::percent(150))
+            .add("denom", &SerializableDenom::Cw20("contract".to_string()))
+            .add("asset", &asset)
+            .into_event("swap");
+
+        assert_eq!(event.attributes[1].value, "1.5");
+        assert_eq!(event.attributes[2].value, "cw20_contract");
+        assert_eq!(event.attributes[3].value, "native_u_atom:1000000");
+
+        assert_eq!(
+            parse_event_attr::<BigInteger>(&event, "amount").unwrap(),
+            BigInteger::from(42u64)
+        );
+        assert_eq!(
+            parse_event_attr::<BigDecimal>(&event, "ratio").unwrap(),
+            BigDecimal::percent(150)
+        );
+        assert_eq!(
+            parse_event_attr::<SerializableDenom>(&event, "denom").unwrap(),
+            SerializableDenom::Cw20("contract".to_string())
+        );
+        assert_eq!(parse_event_attr::<Asset>(&event, "asset").unwrap(), asset);
+        assert_eq!(
+            parse_event_attr::<Asset>(&event, "missing"),
+            Err(CommonError::MissingAttribute("missing".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_addr_attr() {
+        let sender = Addr::unchecked("cosmwasm1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5lzv7xu");
+        let event = AttrBuilder::new()
+            .add("sender", &sender)
+            .add("display_sender", &short_attr(&sender))
+            .into_event("transfer");
+        assert_eq!(parse_event_attr::<Addr>(&event, "sender").unwrap(), sender);
+        assert!(matches!(
+            parse_event_attr::<Addr>(&event, "display_sender"),
+            Err(CommonError::ShortenedAddress(_))
+        ));
+    }
+
+    const AMOUNT: AttrKey<BigInteger> = AttrKey::new("amount");
+    const DENOM: AttrKey<SerializableDenom> = AttrKey::new("denom");
+    const SENDER: AttrKey<Addr> = AttrKey::new("sender");
+    const MEMO: AttrKey<String> = AttrKey::new("memo");
+    const DEPOSIT: EventSchema = EventSchema::new(
+        "deposit",
+        &[AMOUNT.spec(), DENOM.spec(), SENDER.spec(), MEMO.spec()],
+    );
+    const REGISTRY: EventRegistry = EventRegistry::new(&[DEPOSIT]);
+
+    fn deposit() -> AttrBuilder {
+        AttrBuilder::new()
+            .add(AMOUNT.key, &BigInteger::from(5u64))
+            .add(DENOM.key, &SerializableDenom::Native("uatom".to_string()))
+            .add(SENDER.key, &Addr::unchecked("sender"))
+            .add(MEMO.key, &"hi".to_string())
+    }
+
+    #[test]
+    fn test_event_schema() {
+        let event = DEPOSIT.emit(deposit()).unwrap();
+        assert_eq!(AMOUNT.parse(&event).unwrap(), BigInteger::from(5u64));
+        assert_eq!(MEMO.parse(&event).unwrap(), "hi");
+        assert_eq!(event.attributes[0], AMOUNT.attr(&BigInteger::from(5u64)));
+
+        // as seen by an indexer
+        let mut indexed = event.clone();
+        indexed.ty = "wasm-deposit".to_string();
+        indexed = indexed.add_attribute("_contract_address", "contract");
+        REGISTRY.validate(&indexed).unwrap();
+
+        assert_eq!(
+            DEPOSIT.emit(deposit().add("amount_renamed", &"1".to_string())),
+            Err(CommonError::UndeclaredAttribute {
+                event: "deposit".to_string(),
+                key: "amount_renamed".to_string(),
+            })
+        );
+        let missing = AttrBuilder::new().add(AMOUNT.key, &BigInteger::one());
+        assert_eq!(
+            DEPOSIT.emit(missing),
+            Err(CommonError::MissingAttribute("denom".to_string()))
+        );
+        let mistyped = cosmwasm_std::Event::new("deposit")
+            .add_attribute("amount", "five")
+            .add_attribute("denom", "native_uatom")
+            .add_attribute("sender", "sender")
+            .add_attribute("memo", "");
+        assert!(DEPOSIT.validate(&mistyped).is_err());
+        assert_eq!(
+            REGISTRY.validate(&cosmwasm_std::Event::new("withdraw")),
+            Err(CommonError::UnknownEventType("withdraw".to_string()))
+        );
+        assert_eq!(
+            DEPOSIT.validate(&cosmwasm_std::Event::new("withdraw")),
+            Err(CommonError::UnexpectedEventType {
+                expected: "deposit".to_string(),
+                actual: "withdraw".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_describe() {
+        let description = REGISTRY.describe();
+        assert_eq!(description[0].ty, "deposit");
+        assert_eq!(
+            description[0].attributes[0],
+            ("amount".to_string(), AttrKind::BigInteger)
+        );
+        assert_eq!(description[0].attributes[3].1, AttrKind::Text);
+        assert_eq!(
+            cosmwasm_std::to_json_string(&description[0].attributes[1]).unwrap(),
+            r#"["denom","denom"]"#
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::rounding::{mul_div, RoundingMode};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal256, Timestamp, Uint256};
+
+pub const MAX_EXIT_FEE_BPS: u64 = 10_000;
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum FeeDecay {
+    Linear,
+    // halves every `half_life_seconds`, then drops to zero when the lock ends
+    Exponential { half_life_seconds: u64 },
+}
+
+// Early-withdrawal penalty, `max_bps` at the start of the lock and zero once it ends.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub struct ExitFeeCurve {
+    pub max_bps: u64,
+    pub lock_seconds: u64,
+    pub decay: FeeDecay,
+}
+
+impl ExitFeeCurve {
+    pub fn validate(&self) -> Result<(), CommonError> {
+        if self.max_bps > MAX_EXIT_FEE_BPS {
+            return Err(CommonError::InvalidFeeCurve(format!(
+                "max_bps {} is above {}",
+                self.max_bps, MAX_EXIT_FEE_BPS
+            )));
+        }
+        if let FeeDecay::Exponential {
+            half_life_seconds: 0,
+        } = self.decay
+        {
+            return Err(CommonError::InvalidFeeCurve(
+                "half life must be positive".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn fee_rate(&self, elapsed_seconds: u64) -> Result<BigDecimal, CommonError> {
+        self.validate()?;
+        if elapsed_seconds >= self.lock_seconds {
+            return Ok(BigDecimal::zero());
+        }
+        let max = BigDecimal::bps(self.max_bps);
+        let factor = match self.decay {
+            FeeDecay::Linear => {
+                BigDecimal::from_ratio(self.lock_seconds - elapsed_seconds, self.lock_seconds)
+            }
+            FeeDecay::Exponential { half_life_seconds } => {
+                exponential_factor(elapsed_seconds, half_life_seconds)
+            }
+        };
+        Ok(max * factor)
+    }
+
+    // Splits `amount` into (payout, penalty). The penalty is rounded up.
+    pub fn apply(
+        &self,
+        amount: BigInteger,
+        lock_start: Timestamp,
+        now: Timestamp,
+    ) -> Result<(BigInteger, BigInteger), CommonError> {
+        let elapsed = now.seconds().saturating_sub(lock_start.seconds());
+        let rate = self.fee_rate(elapsed)?;
+        let penalty = mul_div(
+            amount,
+            BigInteger(rate.0.atomics()),
+            BigInteger(Decimal256::one().atomics()),
+            RoundingMode::Ceil,
+        )?;
+        Ok((amount - penalty, penalty))
+    }
+}
+
+// 2^(-elapsed / half_life), interpolated linearly within each half life. The chord lies above
+// the curve, so the approximation never undercharges.
+fn exponential_factor(elapsed_seconds: u64, half_life_seconds: u64) -> BigDecimal {
+    let halvings = elapsed_seconds / half_life_seconds;
+    if halvings >= 128 {
+        return BigDecimal::zero();
+    }
+    let into_half_life = elapsed_seconds % half_life_seconds;
+    let double_half_life = Uint256::from(half_life_seconds) * Uint256::from(2u8);
+    BigDecimal::from_ratio(
+        double_half_life - Uint256::from(into_half_life),
+        double_half_life << (halvings as u32),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::exit_fee::{ExitFeeCurve, FeeDecay};
+    use cosmwasm_std::Timestamp;
+
+    const DAY: u64 = 24 * 3600;
+
+    fn linear() -> ExitFeeCurve {
+        ExitFeeCurve {
+            max_bps: 1000,
+            lock_seconds: 100 * DAY,
+            decay: FeeDecay::Linear,
+        }
+    }
+
+    #[test]
+    fn test_linear() {
+        let curve = linear();
+        let start = Timestamp::from_seconds(0);
+        let amount = BigInteger::from(1_000_000u64);
+        assert_eq!(
+            curve.apply(amount, start, start).unwrap(),
+            (BigInteger::from(900_000u64), BigInteger::from(100_000u64))
+        );
+        assert_eq!(
+            curve
+                .apply(amount, start, start.plus_seconds(75 * DAY))
+                .unwrap(),
+            (BigInteger::from(975_000u64), BigInteger::from(25_000u64))
+        );
+        assert_eq!(
+            curve
+                .apply(amount, start, start.plus_seconds(100 * DAY))
+                .unwrap(),
+            (amount, BigInteger::zero())
+        );
+        // protocol-favoring: any fee on a tiny amount rounds up to one unit
+        assert_eq!(
+            curve
+                .apply(BigInteger::from(3u64), start, start.plus_seconds(99 * DAY))
+                .unwrap(),
+            (BigInteger::from(2u64), BigInteger::one())
+        );
+    }
+
+    #[test]
+    fn test_exponential() {
+        let curve = ExitFeeCurve {
+            max_bps: 1000,
+            lock_seconds: 100 * DAY,
+            decay: FeeDecay::Exponential {
+                half_life_seconds: 10 * DAY,
+            },
+        };
+        assert_eq!(curve.fee_rate(0).unwrap(), BigDecimal::percent(10));
+        assert_eq!(curve.fee_rate(10 * DAY).unwrap(), BigDecimal::percent(5));
+        assert_eq!(curve.fee_rate(20 * DAY).unwrap(), BigDecimal::bps(250));
+        assert_eq!(curve.fee_rate(5 * DAY).unwrap(), BigDecimal::bps(750));
+        assert_eq!(curve.fee_rate(100 * DAY).unwrap(), BigDecimal::zero());
+
+        let mut last = BigDecimal::one();
+        for day in 0..=100 {
+            let rate = curve.fee_rate(day * DAY).unwrap();
+            assert!(rate <= last);
+            last = rate;
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut curve = linear();
+        curve.max_bps = 10_001;
+        assert!(curve.validate().is_err());
+        curve.max_bps = 100;
+        curve.decay = FeeDecay::Exponential {
+            half_life_seconds: 0,
+        };
+        assert!(curve.fee_rate(0).is_err());
+    }
+}
@@ -0,0 +1,216 @@
+// JSON fixtures of math operations that every implementation of these types must reproduce.
+
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::number::Number;
+use crate::rounding::{mul_div, RoundingMode};
+use core::str::FromStr;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_json, StdError, StdResult};
+
+#[cw_serde]
+pub struct GoldenCase {
+    pub op: String,
+    pub inputs: Vec<String>,
+    // `None` when the operation must fail, e.g. on overflow or division by zero
+    pub expected: Option<String>,
+}
+
+#[cw_serde]
+pub struct GoldenFile {
+    pub cases: Vec<GoldenCase>,
+}
+
+pub const OPS: &[&str] = &[
+    "biginteger_add",
+    "biginteger_sub",
+    "biginteger_mul",
+    "biginteger_div",
+    "biginteger_isqrt",
+    "bigdecimal_add",
+    "bigdecimal_sub",
+    "bigdecimal_mul",
+    "bigdecimal_div",
+    "bigdecimal_parse",
+    "mul_div_floor",
+    "mul_div_ceil",
+];
+
+fn parse<T>(input: &str) -> Result<T, CommonError>
+where
+    T: FromStr,
+    CommonError: From<T::Err>,
+{
+    Ok(T::from_str(input)?)
+}
+
+fn binary<T>(op: &str, inputs: &[String]) -> Result<String, CommonError>
+where
+    T: Number + FromStr,
+    CommonError: From<T::Err>,
+{
+    let [lhs, rhs] = inputs else {
+        return Err(StdError::generic_err(format!("{} takes 2 inputs", op)).into());
+    };
+    let (lhs, rhs) = (parse::<T>(lhs)?, parse::<T>(rhs)?);
+    let result = match op.rsplit('_').next() {
+        Some("add") => lhs.checked_add(rhs)?,
+        Some("sub") => lhs.checked_sub(rhs)?,
+        Some("mul") => lhs.checked_mul(rhs)?,
+        _ => lhs.checked_div(rhs)?,
+    };
+    Ok(result.to_string())
+}
+
+pub fn evaluate(op: &str, inputs: &[String]) -> Result<String, CommonError> {
+    match op {
+        "biginteger_add" | "biginteger_sub" | "biginteger_mul" | "biginteger_div" => {
+            binary::<BigInteger>(op, inputs)
+        }
+        "bigdecimal_add" | "bigdecimal_sub" | "bigdecimal_mul" | "bigdecimal_div" => {
+            binary::<BigDecimal>(op, inputs)
+        }
+        "biginteger_isqrt" | "bigdecimal_parse" => {
+            let [input] = inputs else {
+                return Err(StdError::generic_err(format!("{} takes 1 input", op)).into());
+            };
+            if op == "biginteger_isqrt" {
+                Ok(parse::<BigInteger>(input)?.isqrt().to_string())
+            } else {
+                Ok(parse::<BigDecimal>(input)?.to_string())
+            }
+        }
+        "mul_div_floor" | "mul_div_ceil" => {
+            let [a, b, denominator] = inputs else {
+                return Err(StdError::generic_err(format!("{} takes 3 inputs", op)).into());
+            };
+            let rounding = if op == "mul_div_floor" {
+                RoundingMode::Floor
+            } else {
+                RoundingMode::Ceil
+            };
+            Ok(mul_div(parse(a)?, parse(b)?, parse(denominator)?, rounding)?.to_string())
+        }
+        _ => Err(StdError::generic_err(format!("unknown golden op {}", op)).into()),
+    }
+}
+
+// Records the current output of `op`, for generating fixtures.
+pub fn generate(op: &str, inputs: &[&str]) -> GoldenCase {
+    let inputs: Vec<String> = inputs.iter().map(|input| input.to_string()).collect();
+    GoldenCase {
+        op: op.to_string(),
+        expected: evaluate(op, &inputs).ok(),
+        inputs,
+    }
+}
+
+pub fn load(json: &str) -> StdResult<GoldenFile> {
+    from_json(json.as_bytes())
+}
+
+// Checks every case of a fixture file and panics with all mismatches at once.
+pub fn assert_golden(json: &str) {
+    let file = load(json).expect("golden file does not parse");
+    let mismatches: Vec<String> = file
+        .cases
+        .iter()
+        .filter_map(|case| {
+            if !OPS.contains(&case.op.as_str()) {
+                return Some(format!("unknown op {}", case.op));
+            }
+            let actual = evaluate(&case.op, &case.inputs).ok();
+            (actual != case.expected).then(|| {
+                format!(
+                    "{}({}): expected {:?}, got {:?}",
+                    case.op,
+                    case.inputs.join(", "),
+                    case.expected,
+                    actual
+                )
+            })
+        })
+        .collect();
+    assert!(
+        mismatches.is_empty(),
+        "golden mismatches:\n{}",
+        mismatches.join("\n")
+    );
+}
+
+// The inputs the `golden` binary writes to `fixtures/golden/math.json`.
+pub fn default_cases() -> GoldenFile {
+    let max_int = BigInteger::MAX.to_string();
+    let max_dec = BigDecimal::MAX.to_string();
+    let cases = vec![
+        generate("biginteger_add", &["1", "2"]),
+        generate("biginteger_add", &[&max_int, "1"]),
+        generate("biginteger_sub", &["10", "3"]),
+        generate("biginteger_sub", &["3", "10"]),
+        generate("biginteger_mul", &["123456789", "987654321"]),
+        generate("biginteger_mul", &[&max_int, "2"]),
+        generate("biginteger_div", &["100", "7"]),
+        generate("biginteger_div", &["1", "0"]),
+        generate("biginteger_isqrt", &["0"]),
+        generate("biginteger_isqrt", &["99"]),
+        generate("biginteger_isqrt", &[&max_int]),
+        generate("bigdecimal_add", &["0.1", "0.2"]),
+        generate("bigdecimal_add", &[&max_dec, "1"]),
+        generate("bigdecimal_sub", &["1", "0.000000000000000001"]),
+        generate("bigdecimal_sub", &["0.1", "0.2"]),
+        generate("bigdecimal_mul", &["1.5", "1.5"]),
+        generate("bigdecimal_mul", &["0.000000001", "0.000000001"]),
+        generate("bigdecimal_mul", &["0.000000001", "0.0000000001"]),
+        generate("bigdecimal_div", &["1", "3"]),
+        generate("bigdecimal_div", &["2", "3"]),
+        generate("bigdecimal_div", &["1", "0"]),
+        generate("bigdecimal_parse", &["001.2300"]),
+        generate("bigdecimal_parse", &["1.0000000000000000001"]),
+        generate("bigdecimal_parse", &["-1"]),
+        generate("mul_div_floor", &["10", "10", "3"]),
+        generate("mul_div_ceil", &["10", "10", "3"]),
+        generate("mul_div_floor", &[&max_int, &max_int, &max_int]),
+        generate("mul_div_ceil", &["1", "1", "0"]),
+    ];
+    GoldenFile { cases }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::golden::{assert_golden, default_cases, evaluate, generate};
+
+    #[test]
+    fn test_fixtures() {
+        assert_golden(include_str!("../fixtures/golden/math.json"));
+    }
+
+    #[test]
+    fn test_fixtures_are_up_to_date() {
+        let generated = serde_json::to_string_pretty(&default_cases()).unwrap() + "\n";
+        assert_eq!(
+            generated,
+            include_str!("../fixtures/golden/math.json"),
+            "regenerate with `cargo run --features testing --bin golden`"
+        );
+    }
+
+    #[test]
+    fn test_evaluate() {
+        assert_eq!(
+            evaluate("bigdecimal_div", &["1".to_string(), "4".to_string()]).unwrap(),
+            "0.25"
+        );
+        assert_eq!(generate("biginteger_div", &["1", "0"]).expected, None);
+        assert!(evaluate("biginteger_pow", &[]).is_err());
+        assert!(evaluate("biginteger_add", &["1".to_string()]).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "bigdecimal_add(0.1, 0.2): expected Some(\"0.3000001\")")]
+    fn test_mismatch_is_reported() {
+        assert_golden(
+            r#"{"cases":[{"op":"bigdecimal_add","inputs":["0.1","0.2"],"expected":"0.3000001"}]}"#,
+        );
+    }
+}
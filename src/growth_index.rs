@@ -0,0 +1,135 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::rounding::{mul_div, RoundingMode};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ConversionOverflowError, Decimal256};
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum Accrual {
+    // index *= (1 + rate)^dt
+    Compound,
+    // index *= 1 + rate * dt, cheaper and a lower bound of compounding
+    Linear,
+}
+
+// Scaled balances are stored as `amount / index` and grow with the index, as in lending tokens.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub struct GrowthIndex {
+    pub index: BigDecimal,
+    pub accrual: Accrual,
+}
+
+impl Default for GrowthIndex {
+    fn default() -> Self {
+        Self::new(Accrual::Compound)
+    }
+}
+
+impl GrowthIndex {
+    pub fn new(accrual: Accrual) -> Self {
+        Self {
+            index: BigDecimal::one(),
+            accrual,
+        }
+    }
+
+    pub fn growth_factor(
+        &self,
+        rate_per_second: BigDecimal,
+        dt: u64,
+    ) -> Result<BigDecimal, CommonError> {
+        let one = Decimal256::one();
+        let factor = match self.accrual {
+            Accrual::Compound => {
+                let exp =
+                    u32::try_from(dt).map_err(|_| ConversionOverflowError::new("u64", "u32"))?;
+                one.checked_add(rate_per_second.0)?.checked_pow(exp)?
+            }
+            Accrual::Linear => {
+                let elapsed = Decimal256::from_ratio(dt, 1u64);
+                one.checked_add(rate_per_second.0.checked_mul(elapsed)?)?
+            }
+        };
+        Ok(BigDecimal(factor))
+    }
+
+    pub fn accrue(&mut self, rate_per_second: BigDecimal, dt: u64) -> Result<(), CommonError> {
+        let factor = self.growth_factor(rate_per_second, dt)?;
+        self.index = BigDecimal(self.index.0.checked_mul(factor.0)?);
+        Ok(())
+    }
+
+    // amount -> scaled balance
+    pub fn normalize(
+        &self,
+        amount: BigInteger,
+        rounding: RoundingMode,
+    ) -> Result<BigInteger, CommonError> {
+        mul_div(
+            amount,
+            BigInteger(Decimal256::one().atomics()),
+            BigInteger(self.index.0.atomics()),
+            rounding,
+        )
+    }
+
+    // scaled balance -> amount
+    pub fn denormalize(
+        &self,
+        scaled: BigInteger,
+        rounding: RoundingMode,
+    ) -> Result<BigInteger, CommonError> {
+        mul_div(
+            scaled,
+            BigInteger(self.index.0.atomics()),
+            BigInteger(Decimal256::one().atomics()),
+            rounding,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::growth_index::{Accrual, GrowthIndex};
+    use crate::rounding::RoundingMode;
+
+    #[test]
+    fn test_accrue() {
+        let mut compound = GrowthIndex::new(Accrual::Compound);
+        compound.accrue(BigDecimal::percent(10), 2).unwrap();
+        assert_eq!(compound.index, BigDecimal::percent(121));
+
+        let mut linear = GrowthIndex::new(Accrual::Linear);
+        linear.accrue(BigDecimal::percent(10), 2).unwrap();
+        assert_eq!(linear.index, BigDecimal::percent(120));
+
+        linear.accrue(BigDecimal::zero(), 1000).unwrap();
+        assert_eq!(linear.index, BigDecimal::percent(120));
+
+        assert!(compound.accrue(BigDecimal::one(), 1000).is_err());
+        assert!(compound.accrue(BigDecimal::zero(), u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut index = GrowthIndex::default();
+        index.accrue(BigDecimal::percent(50), 1).unwrap();
+
+        let amount = BigInteger::from(100u64);
+        let scaled = index.normalize(amount, RoundingMode::Floor).unwrap();
+        assert_eq!(scaled, BigInteger::from(66u64));
+        assert_eq!(
+            index.normalize(amount, RoundingMode::Ceil).unwrap(),
+            BigInteger::from(67u64)
+        );
+        assert_eq!(
+            index.denormalize(scaled, RoundingMode::Floor).unwrap(),
+            BigInteger::from(99u64)
+        );
+    }
+}
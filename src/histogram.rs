@@ -0,0 +1,182 @@
+use crate::bigdecimal::BigDecimal;
+use crate::error::CommonError;
+use crate::number::Number;
+use cosmwasm_schema::cw_serde;
+
+// Bucket `i` covers `[edges[i - 1], edges[i])`; bucket 0 collects values below the first edge and
+// the last bucket values at or above the last edge.
+#[cw_serde]
+pub struct Histogram {
+    pub edges: Vec<BigDecimal>,
+    pub weights: Vec<BigDecimal>,
+    pub total: BigDecimal,
+}
+
+impl Histogram {
+    pub fn new(edges: Vec<BigDecimal>) -> Result<Self, CommonError> {
+        if edges.is_empty() {
+            return Err(CommonError::InvalidBuckets("no bucket edges".to_string()));
+        }
+        if edges.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(CommonError::InvalidBuckets(
+                "edges must be strictly increasing".to_string(),
+            ));
+        }
+        Ok(Self {
+            weights: vec![BigDecimal::zero(); edges.len() + 1],
+            edges,
+            total: BigDecimal::zero(),
+        })
+    }
+
+    pub fn linear(start: BigDecimal, width: BigDecimal, count: u32) -> Result<Self, CommonError> {
+        let edges = (0..count)
+            .map(|i| {
+                let offset = width.0.checked_mul(BigDecimal::from_ratio(i, 1u128).0)?;
+                Ok(BigDecimal(start.0.checked_add(offset)?))
+            })
+            .collect::<Result<_, CommonError>>()?;
+        Self::new(edges)
+    }
+
+    pub fn exponential(
+        start: BigDecimal,
+        factor: BigDecimal,
+        count: u32,
+    ) -> Result<Self, CommonError> {
+        let mut edges = Vec::with_capacity(count as usize);
+        let mut edge = start;
+        for i in 0..count {
+            if i > 0 {
+                edge = BigDecimal(edge.0.checked_mul(factor.0)?);
+            }
+            edges.push(edge);
+        }
+        Self::new(edges)
+    }
+
+    pub fn bucket_index(&self, value: BigDecimal) -> usize {
+        self.edges.partition_point(|edge| *edge <= value)
+    }
+
+    pub fn add(&mut self, value: BigDecimal, weight: BigDecimal) -> Result<(), CommonError> {
+        let index = self.bucket_index(value);
+        let bucket = self.weights[index].checked_add(weight)?;
+        self.total = self.total.checked_add(weight)?;
+        self.weights[index] = bucket;
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total.is_zero()
+    }
+
+    pub fn reset(&mut self) {
+        self.weights
+            .iter_mut()
+            .for_each(|w| *w = BigDecimal::zero());
+        self.total = BigDecimal::zero();
+    }
+
+    // Linearly interpolated inside the bucket; the open-ended outer buckets report their edge.
+    pub fn percentile(&self, q: BigDecimal) -> Result<BigDecimal, CommonError> {
+        if !q.is_ratio() {
+            return Err(CommonError::InvalidRatio(q.to_string()));
+        }
+        if self.is_empty() {
+            return Err(CommonError::EmptyHistogram);
+        }
+        let target = self.total * q;
+        let mut cumulative = BigDecimal::zero();
+        for (i, weight) in self.weights.iter().enumerate() {
+            let next = cumulative.checked_add(*weight)?;
+            if weight.is_zero() || next < target {
+                cumulative = next;
+                continue;
+            }
+            if i == 0 {
+                return Ok(self.edges[0]);
+            }
+            if i == self.edges.len() {
+                return Ok(self.edges[i - 1]);
+            }
+            let lower = self.edges[i - 1];
+            let upper = self.edges[i];
+            return Ok(lower + (upper - lower) * ((target - cumulative) / *weight));
+        }
+        Ok(self.edges[self.edges.len() - 1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::histogram::Histogram;
+    use core::str::FromStr;
+    use cosmwasm_std::Decimal256;
+
+    fn dec(value: u64) -> BigDecimal {
+        BigDecimal::from_ratio(value, 1u64)
+    }
+
+    #[test]
+    fn test_buckets() {
+        let histogram = Histogram::linear(dec(0), dec(10), 3).unwrap();
+        assert_eq!(histogram.edges, vec![dec(0), dec(10), dec(20)]);
+        assert_eq!(histogram.bucket_index(dec(5)), 1);
+        assert_eq!(histogram.bucket_index(dec(10)), 2);
+        assert_eq!(histogram.bucket_index(dec(25)), 3);
+
+        let exponential = Histogram::exponential(dec(1), dec(10), 3).unwrap();
+        assert_eq!(exponential.edges, vec![dec(1), dec(10), dec(100)]);
+
+        // the last edges fit even though the next ones would not
+        let big = BigDecimal::from_str(&format!("1{}", "0".repeat(56))).unwrap();
+        let exponential = Histogram::exponential(big, dec(10), 4).unwrap();
+        assert_eq!(exponential.edges[3], big * dec(1000));
+        assert!(Histogram::exponential(big, dec(10), 5).is_err());
+        let linear = Histogram::linear(big, big, 1000).unwrap();
+        assert_eq!(linear.edges[999], big * dec(1000));
+        assert!(Histogram::linear(big, big, 1200).is_err());
+
+        assert!(Histogram::new(vec![dec(1), dec(1)]).is_err());
+        assert!(Histogram::new(vec![]).is_err());
+    }
+
+    #[test]
+    fn test_percentile() {
+        let mut histogram = Histogram::linear(dec(0), dec(10), 3).unwrap();
+        assert!(histogram.percentile(BigDecimal::percent(50)).is_err());
+
+        histogram.add(dec(5), dec(1)).unwrap();
+        histogram.add(dec(15), dec(1)).unwrap();
+        assert_eq!(
+            histogram.percentile(BigDecimal::percent(50)).unwrap(),
+            dec(10)
+        );
+        assert_eq!(
+            histogram.percentile(BigDecimal::percent(75)).unwrap(),
+            dec(15)
+        );
+        assert_eq!(histogram.percentile(BigDecimal::one()).unwrap(), dec(20));
+        assert!(histogram.percentile(dec(2)).is_err());
+
+        histogram.reset();
+        assert!(histogram.is_empty());
+    }
+
+    #[test]
+    fn test_weight_overflow() {
+        let mut histogram = Histogram::linear(dec(0), dec(10), 3).unwrap();
+        histogram.add(dec(5), BigDecimal::MAX).unwrap();
+        assert!(histogram.add(dec(15), BigDecimal::one()).is_err());
+        assert_eq!(histogram.weights[2], BigDecimal::zero());
+        assert_eq!(histogram.total, BigDecimal::MAX);
+
+        // a deserialized histogram whose buckets sum past its total
+        let atom = BigDecimal(Decimal256::new(1u128.into()));
+        histogram.weights[1] = BigDecimal::MAX - atom;
+        histogram.weights[2] = atom + atom;
+        assert!(histogram.percentile(BigDecimal::one()).is_err());
+    }
+}
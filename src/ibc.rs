@@ -0,0 +1,129 @@
+use crate::error::CommonError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_schema::serde::Serialize;
+use cosmwasm_std::{
+    from_json, to_json_binary, Binary, BlockInfo, IbcTimeout, IbcTimeoutBlock, StdResult,
+};
+use cw_utils::Duration;
+
+// Height based durations are rejected because the timeout height refers to the counterparty
+// chain, which has no relation to the local block height.
+pub fn timeout_after(block: &BlockInfo, duration: Duration) -> Result<IbcTimeout, CommonError> {
+    match duration {
+        Duration::Time(seconds) => Ok(IbcTimeout::with_timestamp(block.time.plus_seconds(seconds))),
+        Duration::Height(_) => Err(CommonError::InvalidTimeout(
+            "height based durations cannot be used for ibc timeouts".to_string(),
+        )),
+    }
+}
+
+pub fn timeout_at_height(revision: u64, height: u64) -> IbcTimeout {
+    IbcTimeout::with_block(IbcTimeoutBlock { revision, height })
+}
+
+// Encoded as `{"result": "<base64>"}` or `{"error": "<message>"}`, the format used by ICS-20.
+#[cw_serde]
+pub enum Ack {
+    Result(Binary),
+    Error(String),
+}
+
+impl Ack {
+    pub fn success(data: &impl Serialize) -> StdResult<Self> {
+        Ok(Ack::Result(to_json_binary(data)?))
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Ack::Error(message.into())
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, Ack::Result(_))
+    }
+
+    pub fn to_binary(&self) -> StdResult<Binary> {
+        to_json_binary(self)
+    }
+
+    pub fn from_binary(data: &Binary) -> StdResult<Self> {
+        from_json(data)
+    }
+}
+
+#[cw_serde]
+pub struct PacketEnvelope<T> {
+    pub version: String,
+    pub payload: T,
+}
+
+impl<T: Serialize + DeserializeOwned> PacketEnvelope<T> {
+    pub fn new(version: impl Into<String>, payload: T) -> Self {
+        Self {
+            version: version.into(),
+            payload,
+        }
+    }
+
+    pub fn to_binary(&self) -> StdResult<Binary> {
+        to_json_binary(self)
+    }
+
+    pub fn from_binary(data: &Binary, expected_version: &str) -> Result<Self, CommonError> {
+        let envelope: Self = from_json(data)?;
+        if envelope.version != expected_version {
+            return Err(CommonError::UnsupportedVersion {
+                expected: expected_version.to_string(),
+                actual: envelope.version,
+            });
+        }
+        Ok(envelope)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::CommonError;
+    use crate::ibc::{timeout_after, Ack, PacketEnvelope};
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{to_json_string, Binary};
+    use cw_utils::Duration;
+
+    #[test]
+    fn test_timeout() {
+        let env = mock_env();
+        let timeout = timeout_after(&env.block, Duration::Time(60)).unwrap();
+        assert_eq!(timeout.timestamp(), Some(env.block.time.plus_seconds(60)));
+        assert!(timeout_after(&env.block, Duration::Height(10)).is_err());
+    }
+
+    #[test]
+    fn test_ack() {
+        let ack = Ack::success(&"ok").unwrap();
+        assert!(ack.is_success());
+        assert_eq!(to_json_string(&ack).unwrap(), r#"{"result":"Im9rIg=="}"#);
+        assert_eq!(
+            to_json_string(&Ack::error("failed")).unwrap(),
+            r#"{"error":"failed"}"#
+        );
+        assert_eq!(Ack::from_binary(&ack.to_binary().unwrap()).unwrap(), ack);
+    }
+
+    #[test]
+    fn test_envelope() {
+        let envelope = PacketEnvelope::new("v1", 42u64);
+        let data = envelope.to_binary().unwrap();
+        assert_eq!(
+            PacketEnvelope::<u64>::from_binary(&data, "v1").unwrap(),
+            envelope
+        );
+        assert_eq!(
+            PacketEnvelope::<u64>::from_binary(&data, "v2"),
+            Err(CommonError::UnsupportedVersion {
+                expected: "v2".to_string(),
+                actual: "v1".to_string(),
+            })
+        );
+        assert!(PacketEnvelope::<u64>::from_binary(&Binary::from(b"{}"), "v1").is_err());
+    }
+}
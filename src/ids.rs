@@ -0,0 +1,158 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{OverflowError, OverflowOperation};
+
+#[cw_serde]
+#[derive(Copy, Default, Eq, Hash, Ord, PartialOrd)]
+pub struct PoolId(pub u64);
+
+impl PoolId {
+    pub fn next(self) -> Result<Self, CommonError> {
+        self.0
+            .checked_add(1)
+            .map(Self)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Add).into())
+    }
+
+    // Big-endian, so storage keys sort in id order.
+    pub fn to_key_bytes(&self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    pub fn from_key_bytes(bytes: [u8; 8]) -> Self {
+        Self(u64::from_be_bytes(bytes))
+    }
+}
+
+impl Display for PoolId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for PoolId {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        u64::from_str(s)
+            .map(Self)
+            .map_err(|_| CommonError::InvalidId(s.to_string()))
+    }
+}
+
+#[cw_serde]
+#[derive(Copy, Default, Eq, Ord, PartialOrd)]
+pub struct PositionId(pub BigInteger);
+
+impl PositionId {
+    pub fn next(self) -> Result<Self, CommonError> {
+        Ok(Self(self.0.checked_add(BigInteger::one())?))
+    }
+
+    // Big-endian, so storage keys sort in id order.
+    pub fn to_key_bytes(&self) -> [u8; 32] {
+        self.0.to_be_bytes()
+    }
+
+    pub fn from_key_bytes(bytes: [u8; 32]) -> Self {
+        Self(BigInteger::from_be_bytes(bytes))
+    }
+}
+
+impl Display for PositionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for PositionId {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BigInteger::from_str(s)
+            .map(Self)
+            .map_err(|_| CommonError::InvalidId(s.to_string()))
+    }
+}
+
+// Hands out ids in increasing order, starting at zero. Keep it in contract state and save it
+// after every id issued.
+#[cw_serde]
+#[derive(Default)]
+pub struct IdCounter {
+    pub next_pool_id: PoolId,
+    pub next_position_id: PositionId,
+}
+
+impl IdCounter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn next_pool_id(&mut self) -> Result<PoolId, CommonError> {
+        let id = self.next_pool_id;
+        self.next_pool_id = id.next()?;
+        Ok(id)
+    }
+
+    pub fn next_position_id(&mut self) -> Result<PositionId, CommonError> {
+        let id = self.next_position_id;
+        self.next_position_id = id.next()?;
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::ids::{IdCounter, PoolId, PositionId};
+    use core::str::FromStr;
+
+    #[test]
+    fn test_counter() {
+        let mut counter = IdCounter::new();
+        assert_eq!(counter.next_pool_id().unwrap(), PoolId(0));
+        assert_eq!(counter.next_pool_id().unwrap(), PoolId(1));
+        assert_eq!(
+            counter.next_position_id().unwrap(),
+            PositionId(BigInteger::zero())
+        );
+        assert_eq!(counter.next_pool_id().unwrap(), PoolId(2));
+        assert_eq!(
+            cosmwasm_std::to_json_string(&counter).unwrap(),
+            r#"{"next_pool_id":3,"next_position_id":"1"}"#
+        );
+
+        counter.next_pool_id = PoolId(u64::MAX);
+        assert!(counter.next_pool_id().is_err());
+        assert_eq!(counter.next_pool_id, PoolId(u64::MAX));
+        assert!(PositionId(BigInteger::MAX).next().is_err());
+    }
+
+    #[test]
+    fn test_string_and_key_forms() {
+        assert_eq!(PoolId::from_str("42").unwrap(), PoolId(42));
+        assert_eq!(PoolId(42).to_string(), "42");
+        assert_eq!(
+            PoolId::from_str("-1"),
+            Err(CommonError::InvalidId("-1".to_string()))
+        );
+        assert_eq!(
+            PositionId::from_str("1.5"),
+            Err(CommonError::InvalidId("1.5".to_string()))
+        );
+        let position = PositionId::from_str("123456789012345678901234567890").unwrap();
+        assert_eq!(position.to_string(), "123456789012345678901234567890");
+
+        assert!(PoolId(1).to_key_bytes() < PoolId(256).to_key_bytes());
+        assert_eq!(PoolId::from_key_bytes(PoolId(7).to_key_bytes()), PoolId(7));
+        assert_eq!(
+            PositionId::from_key_bytes(position.to_key_bytes()),
+            position
+        );
+    }
+}
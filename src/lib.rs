@@ -1,6 +1,90 @@
+// The numeric core (`biginteger`, `bigdecimal`, the signed types, `math`, `rounding` and
+// `serializable_map`) only uses `core` and `alloc` paths. A `no_std` build is still blocked by
+// cosmwasm-std, which requires its `std` feature, and by `#[cw_serde]` deriving through `::std`.
 extern crate alloc;
 
+#[macro_use]
+mod macros;
+
+pub mod addr_display;
+pub mod amm;
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+pub mod asset;
+pub mod balances;
+pub mod batch_auction;
+pub mod bids;
 pub mod bigdecimal;
 pub mod biginteger;
+pub mod boost;
+pub mod callbacks;
+pub mod circuit_breaker;
+pub mod claims;
+pub mod codec;
+pub mod commit_reveal;
+pub mod compact;
+pub mod contract_version;
+pub mod debt;
+pub mod decimal_range;
+pub mod denom_pair;
+pub mod dust;
+pub mod emission;
+pub mod ensure;
+pub mod error;
+pub mod events;
+pub mod exit_fee;
+#[cfg(feature = "testing")]
+pub mod golden;
+pub mod growth_index;
+pub mod histogram;
+pub mod ibc;
+pub mod ids;
+pub mod math;
+pub mod memo;
+pub mod migration;
+pub mod number;
+pub mod orderbook;
+pub mod permit;
+pub mod price;
+pub mod quote;
+pub mod raffle;
+pub mod rand;
+pub mod rate_cache;
+pub mod rebalance;
+pub mod replies;
+pub mod repr;
+pub mod response;
+pub mod rounding;
+pub mod route;
+pub mod scheduler;
+pub mod schema_extras;
 pub mod serializable_denom;
 pub mod serializable_map;
+pub mod serializable_priority_queue;
+pub mod shares;
+pub mod signed_bigdecimal;
+pub mod signed_biginteger;
+pub mod signing;
+#[cfg(feature = "simulate")]
+pub mod simulate;
+pub mod snapshots;
+pub mod sorting;
+pub mod splits;
+#[cfg(feature = "stargate")]
+pub mod stargate;
+pub mod stats;
+pub mod storage;
+pub mod subdenom;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod tiered;
+pub mod time_buckets;
+pub mod time_in_force;
+pub mod time_weighted;
+pub mod transfers;
+pub mod unbonding;
+pub mod validation;
+pub mod valuation;
+pub mod voting;
+pub mod vwap;
+pub mod waterfall;
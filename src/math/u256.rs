@@ -0,0 +1,156 @@
+use crate::error::CommonError;
+use crate::rounding::RoundingMode;
+use cosmwasm_std::{DivideByZeroError, OverflowError, OverflowOperation, Uint256, Uint512};
+
+// 10^77 is the largest power of ten that fits in 256 bits
+pub const MAX_POW10_EXP: u32 = 77;
+
+pub const POW10: [Uint256; MAX_POW10_EXP as usize + 1] = {
+    let mut table = [Uint256::one(); MAX_POW10_EXP as usize + 1];
+    let mut i = 1;
+    while i < table.len() {
+        table[i] = Uint256::from_u128(10).pow(i as u32);
+        i += 1;
+    }
+    table
+};
+
+// Panics if `exp` is above `MAX_POW10_EXP`, same as `Uint256::pow` would.
+pub const fn pow10(exp: u32) -> Uint256 {
+    POW10[exp as usize]
+}
+
+pub fn checked_pow10(exp: u32) -> Result<Uint256, CommonError> {
+    POW10
+        .get(exp as usize)
+        .copied()
+        .ok_or_else(|| OverflowError::new(OverflowOperation::Pow).into())
+}
+
+// `rounding::mul_div` on raw `Uint256`s, for callers outside `BigInteger`
+pub fn mul_div_512(
+    a: Uint256,
+    b: Uint256,
+    denominator: Uint256,
+    rounding: RoundingMode,
+) -> Result<Uint256, CommonError> {
+    if denominator.is_zero() {
+        return Err(DivideByZeroError::new().into());
+    }
+    let product = a.full_mul(b);
+    let denominator = Uint512::from(denominator);
+    let mut quotient = product / denominator;
+    if rounding.rounds_up(quotient, product % denominator, denominator) {
+        quotient += Uint512::one();
+    }
+    Ok(Uint256::try_from(quotient)?)
+}
+
+// a * b % modulus without overflow
+pub fn mulmod(a: Uint256, b: Uint256, modulus: Uint256) -> Result<Uint256, CommonError> {
+    if modulus.is_zero() {
+        return Err(DivideByZeroError::new().into());
+    }
+    let remainder = a.full_mul(b) % Uint512::from(modulus);
+    // the remainder is below the modulus, so it fits
+    Ok(Uint256::try_from(remainder)?)
+}
+
+// (a + b) % modulus without overflow
+pub fn addmod(a: Uint256, b: Uint256, modulus: Uint256) -> Result<Uint256, CommonError> {
+    if modulus.is_zero() {
+        return Err(DivideByZeroError::new().into());
+    }
+    let remainder = (Uint512::from(a) + Uint512::from(b)) % Uint512::from(modulus);
+    Ok(Uint256::try_from(remainder)?)
+}
+
+// floor(sqrt(n)) by Newton's method, starting from a power of two above the root so the
+// iterates decrease monotonically
+pub fn isqrt(n: Uint256) -> Uint256 {
+    if n < Uint256::from(2u8) {
+        return n;
+    }
+    let bytes = n.to_be_bytes();
+    let zero_bytes = bytes.iter().take_while(|b| **b == 0).count();
+    let bits = (32 - zero_bytes as u32) * 8 - bytes[zero_bytes].leading_zeros();
+    let mut x = Uint256::one() << bits.div_ceil(2);
+    loop {
+        let y = (x + n / x) >> 1;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::u256::{
+        addmod, checked_pow10, isqrt, mul_div_512, mulmod, pow10, MAX_POW10_EXP,
+    };
+    use crate::rounding::RoundingMode;
+    use cosmwasm_std::{Uint128, Uint256};
+
+    #[test]
+    fn test_pow10() {
+        for exp in 0..=MAX_POW10_EXP {
+            assert_eq!(pow10(exp), Uint256::from(10u8).pow(exp));
+        }
+        assert!(checked_pow10(MAX_POW10_EXP).is_ok());
+        assert!(checked_pow10(MAX_POW10_EXP + 1).is_err());
+        assert!(Uint256::from(10u8).checked_pow(MAX_POW10_EXP + 1).is_err());
+    }
+
+    #[test]
+    fn test_mul_div_512() {
+        let max = Uint256::MAX;
+        assert_eq!(
+            mul_div_512(max, max, max, RoundingMode::Floor).unwrap(),
+            max
+        );
+        assert_eq!(
+            mul_div_512(
+                Uint256::from(10u8),
+                Uint256::from(10u8),
+                Uint256::from(3u8),
+                RoundingMode::Ceil
+            )
+            .unwrap(),
+            Uint256::from(34u8)
+        );
+        assert!(mul_div_512(max, max, Uint256::one(), RoundingMode::Floor).is_err());
+        assert!(mul_div_512(max, max, Uint256::zero(), RoundingMode::Floor).is_err());
+    }
+
+    #[test]
+    fn test_mod() {
+        let max = Uint256::MAX;
+        let modulus = Uint256::from(1_000_000_007u64);
+        // cross-check against u128 arithmetic on the reduced operand
+        let r = Uint128::try_from(max % modulus).unwrap().u128();
+        assert_eq!(
+            mulmod(max, max, modulus).unwrap(),
+            Uint256::from(r * r % 1_000_000_007)
+        );
+        assert_eq!(
+            addmod(max, max, modulus).unwrap(),
+            Uint256::from(2 * r % 1_000_000_007)
+        );
+        assert!(mulmod(max, max, Uint256::zero()).is_err());
+        assert!(addmod(max, max, Uint256::zero()).is_err());
+    }
+
+    #[test]
+    fn test_isqrt() {
+        for n in 0u64..1000 {
+            let root = isqrt(Uint256::from(n));
+            assert!(root * root <= Uint256::from(n));
+            assert!((root + Uint256::one()) * (root + Uint256::one()) > Uint256::from(n));
+        }
+        assert_eq!(isqrt(Uint256::MAX), Uint256::from(u128::MAX));
+        let square = Uint256::from(u128::MAX) * Uint256::from(u128::MAX);
+        assert_eq!(isqrt(square), Uint256::from(u128::MAX));
+        assert_eq!(isqrt(square - Uint256::one()), Uint256::from(u128::MAX - 1));
+    }
+}
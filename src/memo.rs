@@ -0,0 +1,187 @@
+use crate::error::CommonError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::serde::Serialize;
+use cosmwasm_std::to_json_string;
+
+// ibc-go rejects ICS-20 memos longer than this
+pub const MAX_MEMO_LENGTH: usize = 32768;
+
+// Serialized as `{"forward": {...}}` for packet-forward-middleware and `{"wasm": {...}}` for
+// ibc-hooks.
+#[cw_serde]
+pub enum Memo {
+    Forward(Forward),
+    Wasm(WasmHook),
+}
+
+#[cw_serde]
+pub struct Forward {
+    pub receiver: String,
+    pub port: String,
+    pub channel: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<Box<Memo>>,
+}
+
+#[cw_serde]
+pub struct WasmHook {
+    pub contract: String,
+    pub msg: serde_json::Value,
+}
+
+impl Memo {
+    pub fn forward(receiver: impl Into<String>, channel: impl Into<String>) -> Self {
+        Memo::Forward(Forward {
+            receiver: receiver.into(),
+            port: "transfer".to_string(),
+            channel: channel.into(),
+            timeout: None,
+            retries: None,
+            next: None,
+        })
+    }
+
+    pub fn wasm(contract: impl Into<String>, msg: &impl Serialize) -> Result<Self, CommonError> {
+        let msg =
+            serde_json::to_value(msg).map_err(|err| CommonError::InvalidMemo(err.to_string()))?;
+        if !msg.is_object() {
+            return Err(CommonError::InvalidMemo(
+                "wasm hook msg must be a JSON object".to_string(),
+            ));
+        }
+        Ok(Memo::Wasm(WasmHook {
+            contract: contract.into(),
+            msg,
+        }))
+    }
+
+    // Builds a multi-hop route, each hop given as (receiver, channel); `last` is executed by the
+    // final hop's receiver.
+    pub fn route(hops: &[(&str, &str)], last: Option<Memo>) -> Result<Self, CommonError> {
+        let mut memo = last;
+        for (receiver, channel) in hops.iter().rev() {
+            let hop = Memo::forward(*receiver, *channel);
+            memo = Some(match memo {
+                Some(next) => hop.then(next)?,
+                None => hop,
+            });
+        }
+        memo.ok_or_else(|| CommonError::InvalidMemo("empty route".to_string()))
+    }
+
+    pub fn with_timeout(mut self, timeout: impl Into<String>) -> Self {
+        if let Memo::Forward(forward) = &mut self {
+            forward.timeout = Some(timeout.into());
+        }
+        self
+    }
+
+    pub fn with_retries(mut self, retries: u8) -> Self {
+        if let Memo::Forward(forward) = &mut self {
+            forward.retries = Some(retries);
+        }
+        self
+    }
+
+    // Appends `next` after the innermost hop of this memo.
+    pub fn then(mut self, next: Memo) -> Result<Self, CommonError> {
+        let mut current = &mut self;
+        loop {
+            match current {
+                Memo::Wasm(_) => {
+                    return Err(CommonError::InvalidMemo(
+                        "nothing can follow a wasm hook".to_string(),
+                    ))
+                }
+                Memo::Forward(forward) => match forward.next {
+                    Some(ref mut inner) => current = inner.as_mut(),
+                    None => {
+                        forward.next = Some(Box::new(next));
+                        return Ok(self);
+                    }
+                },
+            }
+        }
+    }
+
+    pub fn to_memo_string(&self) -> Result<String, CommonError> {
+        let memo = to_json_string(self)?;
+        if memo.len() > MAX_MEMO_LENGTH {
+            return Err(CommonError::InvalidMemo(format!(
+                "memo length {} exceeds {}",
+                memo.len(),
+                MAX_MEMO_LENGTH
+            )));
+        }
+        Ok(memo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::CommonError;
+    use crate::memo::Memo;
+    use cosmwasm_schema::cw_serde;
+
+    #[cw_serde]
+    enum HookMsg {
+        Swap { min_out: String },
+    }
+
+    #[test]
+    fn test_forward() {
+        let memo = Memo::forward("osmo1receiver", "channel-0")
+            .with_timeout("10m")
+            .with_retries(2);
+        assert_eq!(
+            memo.to_memo_string().unwrap(),
+            r#"{"forward":{"receiver":"osmo1receiver","port":"transfer","channel":"channel-0","timeout":"10m","retries":2}}"#
+        );
+    }
+
+    #[test]
+    fn test_route_with_wasm_hook() {
+        let hook = Memo::wasm(
+            "osmo1contract",
+            &HookMsg::Swap {
+                min_out: "100".to_string(),
+            },
+        )
+        .unwrap();
+        let memo = Memo::route(
+            &[("juno1a", "channel-1"), ("osmo1contract", "channel-2")],
+            Some(hook),
+        )
+        .unwrap();
+        assert_eq!(
+            memo.to_memo_string().unwrap(),
+            r#"{"forward":{"receiver":"juno1a","port":"transfer","channel":"channel-1","next":{"forward":{"receiver":"osmo1contract","port":"transfer","channel":"channel-2","next":{"wasm":{"contract":"osmo1contract","msg":{"swap":{"min_out":"100"}}}}}}}}"#
+        );
+
+        let wasm = Memo::wasm(
+            "osmo1contract",
+            &HookMsg::Swap {
+                min_out: "1".to_string(),
+            },
+        )
+        .unwrap();
+        assert!(wasm.then(Memo::forward("a", "channel-0")).is_err());
+        assert_eq!(
+            Memo::wasm("osmo1contract", &"not an object"),
+            Err(CommonError::InvalidMemo(
+                "wasm hook msg must be a JSON object".to_string()
+            ))
+        );
+        assert!(Memo::route(&[], None).is_err());
+    }
+
+    #[test]
+    fn test_size_limit() {
+        let memo = Memo::forward("a".repeat(40_000), "channel-0");
+        assert!(memo.to_memo_string().is_err());
+    }
+}
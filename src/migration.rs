@@ -0,0 +1,195 @@
+use crate::error::CommonError;
+use core::any::type_name;
+use core::marker::PhantomData;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_schema::serde::Serialize;
+use cosmwasm_std::{to_json_vec, StdError, StdResult, Storage};
+use serde_json::Value;
+
+// State envelope carrying the shape version of `data`.
+#[cw_serde]
+pub struct Versioned<T> {
+    pub version: u32,
+    pub data: T,
+}
+
+type MigrationStep = Box<dyn Fn(Value) -> Result<Value, CommonError>>;
+
+// Upgrades stored `Versioned` payloads to `current` by running one registered step per version,
+// in order. Each step receives the JSON of version `n` and returns the JSON of version `n + 1`.
+pub struct Migrator<T> {
+    current: u32,
+    steps: Vec<(u32, MigrationStep)>,
+    phantom_data: PhantomData<T>,
+}
+
+impl<T> Migrator<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    pub fn new(current: u32) -> Self {
+        Self {
+            current,
+            steps: vec![],
+            phantom_data: PhantomData,
+        }
+    }
+
+    pub fn step(
+        mut self,
+        from: u32,
+        step: impl Fn(Value) -> Result<Value, CommonError> + 'static,
+    ) -> Self {
+        self.steps.push((from, Box::new(step)));
+        self
+    }
+
+    pub fn migrate(&self, data: &[u8]) -> Result<Versioned<T>, CommonError> {
+        self.migrate_envelope(parse_envelope::<T>(data)?)
+    }
+
+    fn migrate_envelope(&self, envelope: Versioned<Value>) -> Result<Versioned<T>, CommonError> {
+        if envelope.version > self.current {
+            return Err(CommonError::UnsupportedVersion {
+                expected: format!("<= {}", self.current),
+                actual: envelope.version.to_string(),
+            });
+        }
+
+        let mut version = envelope.version;
+        let mut value = envelope.data;
+        while version < self.current {
+            let (_, step) = self
+                .steps
+                .iter()
+                .find(|(from, _)| *from == version)
+                .ok_or(CommonError::MissingMigration(version))?;
+            value = step(value)?;
+            version += 1;
+        }
+
+        let data = serde_json::from_value(value)
+            .map_err(|err| StdError::parse_err(type_name::<T>(), err))?;
+        Ok(Versioned { version, data })
+    }
+
+    // Loads the payload under `key`, migrates it and writes it back if it was upgraded.
+    pub fn load_and_migrate(
+        &self,
+        storage: &mut dyn Storage,
+        key: &[u8],
+    ) -> Result<T, CommonError> {
+        let raw = storage
+            .get(key)
+            .ok_or_else(|| StdError::not_found(type_name::<T>()))?;
+        let envelope = parse_envelope::<T>(&raw)?;
+        let stored_version = envelope.version;
+        let migrated = self.migrate_envelope(envelope)?;
+        if migrated.version != stored_version {
+            storage.set(key, &to_json_vec(&migrated)?);
+        }
+        Ok(migrated.data)
+    }
+}
+
+fn parse_envelope<T>(data: &[u8]) -> StdResult<Versioned<Value>> {
+    serde_json::from_slice(data)
+        .map_err(|err| StdError::parse_err(type_name::<Versioned<T>>(), err))
+}
+
+pub fn save_versioned<T: Serialize>(
+    storage: &mut dyn Storage,
+    key: &[u8],
+    version: u32,
+    data: &T,
+) -> StdResult<()> {
+    storage.set(key, &to_json_vec(&Versioned { version, data })?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::migration::{save_versioned, Migrator, Versioned};
+    use crate::serializable_map::SerializableMap;
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::{from_json, StdError, Storage};
+    use serde_json::json;
+
+    #[cw_serde]
+    struct ConfigV1 {
+        owner: String,
+    }
+
+    #[cw_serde]
+    struct ConfigV3 {
+        owner: String,
+        fee_bps: u64,
+        limits: SerializableMap<String, BigInteger>,
+    }
+
+    fn migrator() -> Migrator<ConfigV3> {
+        Migrator::new(3)
+            .step(1, |mut value| {
+                value["fee_bps"] = json!(30);
+                Ok(value)
+            })
+            .step(2, |mut value| {
+                value["limits"] = json!([["uatom", "1000"]]);
+                Ok(value)
+            })
+    }
+
+    #[test]
+    fn test_load_and_migrate() {
+        let mut storage = MockStorage::default();
+        let old = ConfigV1 {
+            owner: "owner".to_string(),
+        };
+        save_versioned(&mut storage, b"config", 1, &old).unwrap();
+
+        let config = migrator()
+            .load_and_migrate(&mut storage, b"config")
+            .unwrap();
+        assert_eq!(config.fee_bps, 30);
+        assert_eq!(
+            config.limits.get(&"uatom".to_string()),
+            Some(&BigInteger::from(1000u64))
+        );
+
+        let stored: Versioned<ConfigV3> = from_json(storage.get(b"config").unwrap()).unwrap();
+        assert_eq!(stored.version, 3);
+        assert_eq!(stored.data, config);
+        assert_eq!(
+            migrator()
+                .load_and_migrate(&mut storage, b"config")
+                .unwrap(),
+            config
+        );
+    }
+
+    #[test]
+    fn test_migration_errors() {
+        let missing_step = Migrator::<ConfigV3>::new(3).step(1, Ok);
+        assert_eq!(
+            missing_step
+                .migrate(br#"{"version":1,"data":{"owner":"o"}}"#)
+                .unwrap_err(),
+            CommonError::MissingMigration(2)
+        );
+        assert!(matches!(
+            migrator().migrate(br#"{"version":4,"data":{}}"#),
+            Err(CommonError::UnsupportedVersion { .. })
+        ));
+
+        let failing = Migrator::<ConfigV3>::new(2)
+            .step(1, |_| Err(StdError::generic_err("cannot migrate").into()));
+        assert_eq!(
+            failing.migrate(br#"{"version":1,"data":{}}"#).unwrap_err(),
+            CommonError::Std(StdError::generic_err("cannot migrate"))
+        );
+    }
+}
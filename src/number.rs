@@ -0,0 +1,183 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::signed_bigdecimal::SignedBigDecimal;
+use crate::signed_biginteger::SignedBigInteger;
+use core::fmt::{Debug, Display};
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Sub};
+use cosmwasm_schema::schemars::JsonSchema;
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_schema::serde::Serialize;
+
+// Common surface of the crate's numeric types, for utilities written once over all of them.
+pub trait Number:
+    Copy
+    + Default
+    + Debug
+    + Display
+    + Ord
+    + Serialize
+    + DeserializeOwned
+    + JsonSchema
+    + Sum
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn is_zero(&self) -> bool;
+    fn checked_add(&self, rhs: Self) -> Result<Self, CommonError>;
+    fn checked_sub(&self, rhs: Self) -> Result<Self, CommonError>;
+    fn checked_mul(&self, rhs: Self) -> Result<Self, CommonError>;
+    fn checked_div(&self, rhs: Self) -> Result<Self, CommonError>;
+}
+
+impl Number for BigInteger {
+    fn zero() -> Self {
+        BigInteger::zero()
+    }
+
+    fn one() -> Self {
+        BigInteger::one()
+    }
+
+    fn is_zero(&self) -> bool {
+        BigInteger::is_zero(self)
+    }
+
+    fn checked_add(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(BigInteger::checked_add(self, rhs)?)
+    }
+
+    fn checked_sub(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(BigInteger::checked_sub(self, rhs)?)
+    }
+
+    fn checked_mul(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(BigInteger::checked_mul(self, rhs)?)
+    }
+
+    fn checked_div(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(BigInteger::checked_div(self, rhs)?)
+    }
+}
+
+impl Number for BigDecimal {
+    fn zero() -> Self {
+        BigDecimal::zero()
+    }
+
+    fn one() -> Self {
+        BigDecimal::one()
+    }
+
+    fn is_zero(&self) -> bool {
+        BigDecimal::is_zero(self)
+    }
+
+    fn checked_add(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(Self(self.0.checked_add(rhs.0)?))
+    }
+
+    fn checked_sub(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(Self(self.0.checked_sub(rhs.0)?))
+    }
+
+    fn checked_mul(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(Self(self.0.checked_mul(rhs.0)?))
+    }
+
+    fn checked_div(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(Self(self.0.checked_div(rhs.0)?))
+    }
+}
+
+macro_rules! impl_number_for_signed {
+    ($t:ty) => {
+        impl Number for $t {
+            fn zero() -> Self {
+                <$t>::zero()
+            }
+
+            fn one() -> Self {
+                <$t>::one()
+            }
+
+            fn is_zero(&self) -> bool {
+                <$t>::is_zero(self)
+            }
+
+            fn checked_add(&self, rhs: Self) -> Result<Self, CommonError> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(&self, rhs: Self) -> Result<Self, CommonError> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(&self, rhs: Self) -> Result<Self, CommonError> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_div(&self, rhs: Self) -> Result<Self, CommonError> {
+                <$t>::checked_div(self, rhs)
+            }
+        }
+    };
+}
+
+impl_number_for_signed!(SignedBigInteger);
+impl_number_for_signed!(SignedBigDecimal);
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::number::Number;
+    use crate::signed_bigdecimal::SignedBigDecimal;
+    use crate::signed_biginteger::SignedBigInteger;
+
+    fn checked_mean<T: Number>(values: &[T]) -> Result<T, CommonError> {
+        let mut total = T::zero();
+        let mut count = T::zero();
+        for value in values {
+            total = total.checked_add(*value)?;
+            count = count.checked_add(T::one())?;
+        }
+        total.checked_div(count)
+    }
+
+    #[test]
+    fn test_generic_mean() {
+        let ints = [BigInteger::from(2u64), BigInteger::from(4u64)];
+        assert_eq!(checked_mean(&ints).unwrap(), BigInteger::from(3u64));
+
+        let decimals = [BigDecimal::percent(50), BigDecimal::percent(100)];
+        assert_eq!(checked_mean(&decimals).unwrap(), BigDecimal::percent(75));
+
+        let signed = [SignedBigInteger::new(-6), SignedBigInteger::new(2)];
+        assert_eq!(checked_mean(&signed).unwrap(), SignedBigInteger::new(-2));
+
+        let signed = [
+            SignedBigDecimal::percent(-50),
+            SignedBigDecimal::percent(100),
+        ];
+        assert_eq!(
+            checked_mean(&signed).unwrap(),
+            SignedBigDecimal::percent(25)
+        );
+
+        assert!(checked_mean::<BigDecimal>(&[]).is_err());
+    }
+
+    #[test]
+    fn test_checked_overflow() {
+        assert!(Number::checked_add(&BigInteger::MAX, BigInteger::one()).is_err());
+        assert!(Number::checked_mul(&BigDecimal::MAX, BigDecimal::percent(200)).is_err());
+        assert!(Number::checked_sub(&BigDecimal::zero(), BigDecimal::one()).is_err());
+    }
+}
@@ -0,0 +1,315 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::serializable_map::SerializableMap;
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+impl Side {
+    pub fn opposite(&self) -> Side {
+        match self {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct Order {
+    pub id: u64,
+    pub price: BigDecimal,
+    pub amount: BigInteger,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct PriceLevel {
+    pub orders: Vec<Order>,
+    pub total: BigInteger,
+}
+
+#[cw_serde]
+pub struct Fill {
+    pub maker_order_id: u64,
+    pub price: BigDecimal,
+    pub amount: BigInteger,
+}
+
+#[cw_serde]
+pub struct MatchResult {
+    pub fills: Vec<Fill>,
+    pub remaining: BigInteger,
+}
+
+#[cw_serde]
+pub struct OrderBook {
+    pub tick_size: BigDecimal,
+    pub bids: SerializableMap<BigDecimal, PriceLevel>,
+    pub asks: SerializableMap<BigDecimal, PriceLevel>,
+}
+
+impl OrderBook {
+    pub fn new(tick_size: BigDecimal) -> Result<Self, CommonError> {
+        if tick_size.is_zero() {
+            return Err(CommonError::InvalidOrder(
+                "tick size must be non-zero".to_string(),
+            ));
+        }
+        Ok(Self {
+            tick_size,
+            bids: SerializableMap::new(),
+            asks: SerializableMap::new(),
+        })
+    }
+
+    // Bids are rounded down and asks up, so a maker never gets a better price than requested.
+    pub fn round_to_tick(&self, side: Side, price: BigDecimal) -> Result<BigDecimal, CommonError> {
+        // a deserialized book may not have gone through `new`
+        if self.tick_size.is_zero() {
+            return Err(CommonError::InvalidOrder(
+                "tick size must be non-zero".to_string(),
+            ));
+        }
+        let ticks = price.0.checked_div(self.tick_size.0)?;
+        let ticks = match side {
+            Side::Buy => ticks.floor(),
+            Side::Sell => ticks.checked_ceil().map_err(|_| {
+                CommonError::InvalidOrder(format!("price {} is out of range", price))
+            })?,
+        };
+        Ok(BigDecimal(ticks.checked_mul(self.tick_size.0)?))
+    }
+
+    fn levels(&self, side: Side) -> &SerializableMap<BigDecimal, PriceLevel> {
+        match side {
+            Side::Buy => &self.bids,
+            Side::Sell => &self.asks,
+        }
+    }
+
+    fn levels_mut(&mut self, side: Side) -> &mut SerializableMap<BigDecimal, PriceLevel> {
+        match side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        }
+    }
+
+    pub fn insert(&mut self, side: Side, mut order: Order) -> Result<BigDecimal, CommonError> {
+        if order.amount.is_zero() {
+            return Err(CommonError::InvalidOrder(
+                "amount must be non-zero".to_string(),
+            ));
+        }
+        let price = self.round_to_tick(side, order.price)?;
+        if price.is_zero() {
+            return Err(CommonError::InvalidOrder(
+                "price must be non-zero".to_string(),
+            ));
+        }
+        order.price = price;
+
+        let levels = self.levels_mut(side);
+        let mut level = levels.get(&price).cloned().unwrap_or_default();
+        level.total = level.total.checked_add(order.amount)?;
+        level.orders.push(order);
+        levels.set(price, level);
+        Ok(price)
+    }
+
+    pub fn best_price(&self, side: Side) -> Option<BigDecimal> {
+        match side {
+            Side::Buy => self.bids.last().map(|(price, _)| *price),
+            Side::Sell => self.asks.first().map(|(price, _)| *price),
+        }
+    }
+
+    // Walks the opposite side from the best price, filling makers in time priority, and stops at
+    // the taker's limit price. The book itself is not modified.
+    pub fn match_order(&self, side: Side, limit: BigDecimal, amount: BigInteger) -> MatchResult {
+        let crosses = |price: &BigDecimal| match side {
+            Side::Buy => *price <= limit,
+            Side::Sell => *price >= limit,
+        };
+        let levels: Box<dyn Iterator<Item = (&BigDecimal, &PriceLevel)>> = match side {
+            Side::Buy => Box::new(self.asks.iter()),
+            Side::Sell => Box::new(self.bids.iter().rev()),
+        };
+
+        let mut remaining = amount;
+        let mut fills = vec![];
+        for (price, level) in levels.take_while(|(price, _)| crosses(price)) {
+            for order in level.orders.iter() {
+                if remaining.is_zero() {
+                    return MatchResult { fills, remaining };
+                }
+                let filled = order.amount.min(remaining);
+                remaining -= filled;
+                fills.push(Fill {
+                    maker_order_id: order.id,
+                    price: *price,
+                    amount: filled,
+                });
+            }
+        }
+        MatchResult { fills, remaining }
+    }
+
+    // Removes the filled maker amounts from the side opposite to the taker.
+    pub fn apply_fills(&mut self, taker_side: Side, fills: &[Fill]) -> Result<(), CommonError> {
+        let levels = self.levels_mut(taker_side.opposite());
+        for fill in fills {
+            let mut level = levels
+                .get(&fill.price)
+                .cloned()
+                .ok_or_else(|| CommonError::InvalidOrder(format!("no level at {}", fill.price)))?;
+            let order = level
+                .orders
+                .iter_mut()
+                .find(|order| order.id == fill.maker_order_id)
+                .ok_or_else(|| {
+                    CommonError::InvalidOrder(format!("order {} not found", fill.maker_order_id))
+                })?;
+            order.amount = order.amount.checked_sub(fill.amount)?;
+            level.total = level.total.checked_sub(fill.amount)?;
+            level.orders.retain(|order| !order.amount.is_zero());
+            if level.orders.is_empty() {
+                levels.delete(&fill.price);
+            } else {
+                levels.set(fill.price, level);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn depth(&self, side: Side) -> Result<BigInteger, CommonError> {
+        self.levels(side)
+            .iter()
+            .try_fold(BigInteger::zero(), |acc, (_, level)| {
+                Ok(acc.checked_add(level.total)?)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::orderbook::{Order, OrderBook, Side};
+    use crate::serializable_map::SerializableMap;
+    use cosmwasm_std::{Decimal256, Uint256};
+
+    fn order(id: u64, price: u64, amount: u64) -> Order {
+        Order {
+            id,
+            price: BigDecimal::percent(price),
+            amount: BigInteger::from(amount),
+        }
+    }
+
+    #[test]
+    fn test_tick_rounding() {
+        let mut book = OrderBook::new(BigDecimal::percent(5)).unwrap();
+        assert_eq!(
+            book.insert(Side::Buy, order(1, 103, 10)).unwrap(),
+            BigDecimal::percent(100)
+        );
+        assert_eq!(
+            book.insert(Side::Sell, order(2, 103, 10)).unwrap(),
+            BigDecimal::percent(105)
+        );
+        assert_eq!(book.best_price(Side::Buy), Some(BigDecimal::percent(100)));
+        assert_eq!(book.best_price(Side::Sell), Some(BigDecimal::percent(105)));
+        assert!(book.insert(Side::Buy, order(3, 1, 10)).is_err());
+        assert!(book.insert(Side::Buy, order(4, 100, 0)).is_err());
+    }
+
+    #[test]
+    fn test_tick_rounding_out_of_range() {
+        let max = BigDecimal::MAX;
+        let book = OrderBook::new(BigDecimal(Decimal256::new(1u128.into()))).unwrap();
+        assert!(matches!(
+            book.round_to_tick(Side::Buy, max),
+            Err(CommonError::CheckedFromRatio(_))
+        ));
+
+        let book = OrderBook::new(BigDecimal::one()).unwrap();
+        assert_eq!(
+            book.round_to_tick(Side::Buy, max).unwrap(),
+            BigDecimal(max.0.floor())
+        );
+        assert!(matches!(
+            book.round_to_tick(Side::Sell, max),
+            Err(CommonError::InvalidOrder(_))
+        ));
+
+        // 1.15 ticks of 1e59 round up to 2e59, past the largest representable price
+        let e56 = Uint256::from(10u8).pow(56);
+        let book = OrderBook::new(
+            BigDecimal::checked_from(BigInteger(e56 * Uint256::from(1000u16)), 0).unwrap(),
+        )
+        .unwrap();
+        assert!(matches!(
+            book.round_to_tick(
+                Side::Sell,
+                BigDecimal::checked_from(BigInteger(e56 * Uint256::from(1150u16)), 0).unwrap()
+            ),
+            Err(CommonError::Overflow(_))
+        ));
+
+        let mut book = OrderBook {
+            tick_size: BigDecimal::zero(),
+            bids: SerializableMap::new(),
+            asks: SerializableMap::new(),
+        };
+        assert!(matches!(
+            book.insert(Side::Buy, order(1, 100, 10)),
+            Err(CommonError::InvalidOrder(_))
+        ));
+    }
+
+    #[test]
+    fn test_level_overflow() {
+        let mut book = OrderBook::new(BigDecimal::percent(1)).unwrap();
+        let mut large = order(1, 100, 1);
+        large.amount = BigInteger::MAX;
+        book.insert(Side::Buy, large.clone()).unwrap();
+        assert!(book.insert(Side::Buy, order(2, 100, 1)).is_err());
+
+        large.price = BigDecimal::percent(101);
+        book.insert(Side::Buy, large).unwrap();
+        assert!(book.depth(Side::Buy).is_err());
+    }
+
+    #[test]
+    fn test_match_and_apply() {
+        let mut book = OrderBook::new(BigDecimal::percent(1)).unwrap();
+        book.insert(Side::Sell, order(1, 101, 5)).unwrap();
+        book.insert(Side::Sell, order(2, 100, 3)).unwrap();
+        book.insert(Side::Sell, order(3, 100, 4)).unwrap();
+        book.insert(Side::Sell, order(4, 110, 100)).unwrap();
+
+        let result = book.match_order(Side::Buy, BigDecimal::percent(101), BigInteger::from(10u64));
+        let ids: Vec<u64> = result
+            .fills
+            .iter()
+            .map(|fill| fill.maker_order_id)
+            .collect();
+        assert_eq!(ids, vec![2, 3, 1]);
+        assert_eq!(result.fills[2].amount, BigInteger::from(3u64));
+        assert!(result.remaining.is_zero());
+
+        book.apply_fills(Side::Buy, &result.fills).unwrap();
+        assert_eq!(book.best_price(Side::Sell), Some(BigDecimal::percent(101)));
+        assert_eq!(book.depth(Side::Sell).unwrap(), BigInteger::from(102u64));
+
+        let result = book.match_order(Side::Buy, BigDecimal::percent(105), BigInteger::from(10u64));
+        assert_eq!(result.remaining, BigInteger::from(8u64));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::asset::Asset;
+use crate::codec::bech32_decode;
+use crate::error::CommonError;
+use crate::signing::{verify_typed_data, Signature, SigningDomain};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Api, Env, Storage};
+use cw_utils::Expiration;
+
+// Shared by every contract so the same wallet tooling can sign permits for all of them.
+pub const PERMIT_DOMAIN_NAME: &str = "permit";
+pub const PERMIT_DOMAIN_VERSION: &str = "1";
+
+const NONCE_NAMESPACE: &[u8] = b"permit_nonce:";
+
+#[cw_serde]
+pub struct Permit {
+    pub owner: Addr,
+    pub spender: Addr,
+    pub asset: Asset,
+    pub nonce: u64,
+    pub expiration: Expiration,
+}
+
+#[cw_serde]
+pub struct SignedPermit {
+    pub permit: Permit,
+    pub signature: Signature,
+}
+
+impl SignedPermit {
+    // Checks expiration and that the permit was signed by its owner for this contract and chain.
+    // Does not touch the nonce; see `use_permit`.
+    pub fn verify(&self, api: &dyn Api, env: &Env) -> Result<(), CommonError> {
+        let permit = &self.permit;
+        if permit.expiration.is_expired(&env.block) {
+            return Err(CommonError::PermitExpired);
+        }
+        let (prefix, _) = bech32_decode(permit.owner.as_str())?;
+        let domain = SigningDomain::new(PERMIT_DOMAIN_NAME, PERMIT_DOMAIN_VERSION, env);
+        let signer = verify_typed_data(api, &domain, permit, &self.signature, &prefix)?;
+        if signer != permit.owner {
+            return Err(CommonError::InvalidSignature(format!(
+                "signed by {}, not the permit owner {}",
+                signer, permit.owner
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn nonce_key(owner: &Addr) -> Vec<u8> {
+    [NONCE_NAMESPACE, owner.as_bytes()].concat()
+}
+
+pub fn next_nonce(storage: &dyn Storage, owner: &Addr) -> Result<u64, CommonError> {
+    match storage.get(&nonce_key(owner)) {
+        None => Ok(0),
+        Some(bytes) => Ok(u64::from_be_bytes(bytes.try_into().map_err(|_| {
+            CommonError::CorruptedState(format!("permit nonce for {}", owner))
+        })?)),
+    }
+}
+
+// Nonces are sequential per owner, so each permit can be used exactly once and in order.
+pub fn consume_nonce(storage: &mut dyn Storage, permit: &Permit) -> Result<(), CommonError> {
+    let expected = next_nonce(storage, &permit.owner)?;
+    if permit.nonce != expected {
+        return Err(CommonError::InvalidNonce {
+            expected,
+            actual: permit.nonce,
+        });
+    }
+    storage.set(&nonce_key(&permit.owner), &(expected + 1).to_be_bytes());
+    Ok(())
+}
+
+pub fn use_permit(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    env: &Env,
+    signed: &SignedPermit,
+) -> Result<Permit, CommonError> {
+    signed.verify(api, env)?;
+    consume_nonce(storage, &signed.permit)?;
+    Ok(signed.permit.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::asset::Asset;
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::permit::{
+        next_nonce, nonce_key, use_permit, Permit, SignedPermit, PERMIT_DOMAIN_NAME,
+        PERMIT_DOMAIN_VERSION,
+    };
+    use crate::serializable_denom::SerializableDenom;
+    use crate::signing::{typed_data_digest, PublicKey, Signature, SigningDomain};
+    use cosmwasm_std::testing::{mock_env, MockApi, MockStorage};
+    use cosmwasm_std::{Binary, Env, Storage};
+    use cw_utils::Expiration;
+    use k256::ecdsa::signature::hazmat::PrehashSigner;
+
+    fn sign(api: &MockApi, env: &Env, nonce: u64, expiration: Expiration) -> SignedPermit {
+        let key = k256::ecdsa::SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let public_key = PublicKey::Secp256k1(Binary::from(
+            key.verifying_key().to_encoded_point(true).as_bytes(),
+        ));
+        let permit = Permit {
+            owner: public_key.to_address(api, "cosmwasm").unwrap(),
+            spender: api.addr_make("spender"),
+            asset: Asset::new(
+                SerializableDenom::Native("uatom".to_string()),
+                BigInteger::from(100u64),
+            ),
+            nonce,
+            expiration,
+        };
+        let domain = SigningDomain::new(PERMIT_DOMAIN_NAME, PERMIT_DOMAIN_VERSION, env);
+        let digest = typed_data_digest(&domain, &permit).unwrap();
+        let signature: k256::ecdsa::Signature = key.sign_prehash(&digest).unwrap();
+        SignedPermit {
+            permit,
+            signature: Signature {
+                public_key,
+                signature: Binary::from(signature.to_bytes().as_slice()),
+            },
+        }
+    }
+
+    #[test]
+    fn test_use_permit() {
+        let api = MockApi::default();
+        let env = mock_env();
+        let mut storage = MockStorage::default();
+
+        let first = sign(&api, &env, 0, Expiration::Never {});
+        assert_eq!(next_nonce(&storage, &first.permit.owner).unwrap(), 0);
+        use_permit(&mut storage, &api, &env, &first).unwrap();
+        assert_eq!(next_nonce(&storage, &first.permit.owner).unwrap(), 1);
+        assert_eq!(
+            use_permit(&mut storage, &api, &env, &first),
+            Err(CommonError::InvalidNonce {
+                expected: 1,
+                actual: 0
+            })
+        );
+
+        let skipped = sign(&api, &env, 2, Expiration::Never {});
+        assert!(use_permit(&mut storage, &api, &env, &skipped).is_err());
+        let second = sign(&api, &env, 1, Expiration::Never {});
+        use_permit(&mut storage, &api, &env, &second).unwrap();
+
+        storage.set(&nonce_key(&second.permit.owner), b"bad");
+        assert!(matches!(
+            next_nonce(&storage, &second.permit.owner),
+            Err(CommonError::CorruptedState(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify() {
+        let api = MockApi::default();
+        let env = mock_env();
+
+        let expired = sign(&api, &env, 0, Expiration::AtHeight(env.block.height));
+        assert_eq!(expired.verify(&api, &env), Err(CommonError::PermitExpired));
+
+        let mut tampered = sign(&api, &env, 0, Expiration::Never {});
+        tampered.permit.spender = api.addr_make("attacker");
+        assert!(matches!(
+            tampered.verify(&api, &env),
+            Err(CommonError::InvalidSignature(_))
+        ));
+
+        let mut other_owner = sign(&api, &env, 0, Expiration::Never {});
+        other_owner.permit.owner = api.addr_make("owner");
+        assert!(other_owner.verify(&api, &env).is_err());
+
+        let mut other_contract = env.clone();
+        other_contract.contract.address = api.addr_make("other");
+        let signed = sign(&api, &env, 0, Expiration::Never {});
+        assert!(signed.verify(&api, &env).is_ok());
+        assert!(signed.verify(&api, &other_contract).is_err());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::math::u256::checked_pow10;
+use crate::rounding::{mul_div, RoundingMode};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal256, DivideByZeroError};
+
+// `value` is the human readable price, i.e. how many whole quote tokens one whole base token is
+// worth. The decimals are used to translate that into base-unit math.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub struct Price {
+    pub value: BigDecimal,
+    pub base_decimals: u32,
+    pub quote_decimals: u32,
+}
+
+impl Price {
+    pub fn new(value: BigDecimal, base_decimals: u32, quote_decimals: u32) -> Self {
+        Self {
+            value,
+            base_decimals,
+            quote_decimals,
+        }
+    }
+
+    pub fn convert(&self, amount_base: BigInteger) -> Result<BigInteger, CommonError> {
+        self.convert_with_rounding(amount_base, RoundingMode::Floor)
+    }
+
+    pub fn convert_with_rounding(
+        &self,
+        amount_base: BigInteger,
+        rounding: RoundingMode,
+    ) -> Result<BigInteger, CommonError> {
+        let atomics = BigInteger(self.value.0.atomics());
+        let fractional = BigInteger::one().scale_up(Decimal256::DECIMAL_PLACES);
+        if self.quote_decimals >= self.base_decimals {
+            let factor = checked_pow10(self.quote_decimals - self.base_decimals)?;
+            let numerator = atomics.0.checked_mul(factor)?;
+            mul_div(amount_base, BigInteger(numerator), fractional, rounding)
+        } else {
+            let factor = checked_pow10(self.base_decimals - self.quote_decimals)?;
+            let denominator = fractional.0.checked_mul(factor)?;
+            mul_div(amount_base, atomics, BigInteger(denominator), rounding)
+        }
+    }
+
+    pub fn invert(&self) -> Result<Self, CommonError> {
+        if self.value.is_zero() {
+            return Err(DivideByZeroError::new().into());
+        }
+        Ok(Self {
+            value: BigDecimal::one() / self.value,
+            base_decimals: self.quote_decimals,
+            quote_decimals: self.base_decimals,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::price::Price;
+    use core::str::FromStr;
+
+    #[test]
+    fn test_convert() {
+        // 1 ETH (18 decimals) = 2500.5 USDC (6 decimals)
+        let price = Price::new(BigDecimal::from_str("2500.5").unwrap(), 18, 6);
+        assert_eq!(
+            price.convert(BigInteger::create_with_scale(2, 18)).unwrap(),
+            BigInteger::from(5_001_000_000u64)
+        );
+
+        let inverted = price.invert().unwrap();
+        assert_eq!(inverted.base_decimals, 6);
+        // 1 / 2500.5 is truncated to 18 decimals, so the round trip loses a few atoms
+        assert_eq!(
+            inverted
+                .convert(BigInteger::from(2_500_500_000u64))
+                .unwrap(),
+            BigInteger::from(999_999_999_999_998_400u128)
+        );
+    }
+
+    #[test]
+    fn test_convert_more_quote_decimals() {
+        let price = Price::new(BigDecimal::percent(50), 6, 18);
+        assert_eq!(
+            price.convert(BigInteger::from(3u64)).unwrap(),
+            BigInteger::create_with_scale(15, 11)
+        );
+        assert!(Price::new(BigDecimal::zero(), 6, 6).invert().is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use crate::bigdecimal::BigDecimal;
+use crate::error::CommonError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal256, Uint256, Uint512};
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub struct Quote {
+    pub bid: BigDecimal,
+    pub ask: BigDecimal,
+}
+
+impl Quote {
+    pub fn new(bid: BigDecimal, ask: BigDecimal) -> Result<Self, CommonError> {
+        let quote = Self { bid, ask };
+        quote.validate()?;
+        Ok(quote)
+    }
+
+    pub fn validate(&self) -> Result<(), CommonError> {
+        if self.bid.is_zero() || self.ask.is_zero() {
+            return Err(CommonError::InvalidQuote(format!(
+                "bid {} and ask {} must be positive",
+                self.bid, self.ask
+            )));
+        }
+        if self.bid > self.ask {
+            return Err(CommonError::InvalidQuote(format!(
+                "bid {} is above ask {}",
+                self.bid, self.ask
+            )));
+        }
+        Ok(())
+    }
+
+    // (bid + ask) / 2 on the atomics, floored; the sum is taken in 512 bits so it cannot overflow
+    pub fn mid(&self) -> Result<BigDecimal, CommonError> {
+        let sum = Uint512::from(self.bid.0.atomics()) + Uint512::from(self.ask.0.atomics());
+        let mid = Uint256::try_from(sum / Uint512::from(2u8))?;
+        Ok(BigDecimal(Decimal256::new(mid)))
+    }
+
+    // (ask - bid) / mid in basis points
+    pub fn spread_bps(&self) -> Result<BigDecimal, CommonError> {
+        let spread = self.ask.0.checked_sub(self.bid.0)?;
+        let relative = spread.checked_div(self.mid()?.0)?;
+        Ok(BigDecimal(
+            relative.checked_mul(Decimal256::from_ratio(10_000u64, 1u64))?,
+        ))
+    }
+
+    // Averages bids and asks separately, each quote weighted by its share of the total weight.
+    pub fn merge(quotes: &[(Quote, BigDecimal)]) -> Result<Quote, CommonError> {
+        let total = BigDecimal::try_sum(quotes.iter().map(|(_, weight)| *weight))?;
+        if total.is_zero() {
+            return Err(CommonError::InvalidQuote(
+                "total weight must be positive".to_string(),
+            ));
+        }
+        let mut bid = Decimal256::zero();
+        let mut ask = Decimal256::zero();
+        for (quote, weight) in quotes {
+            quote.validate()?;
+            let share = weight.0.checked_div(total.0)?;
+            bid = bid.checked_add(quote.bid.0.checked_mul(share)?)?;
+            ask = ask.checked_add(quote.ask.0.checked_mul(share)?)?;
+        }
+        Quote::new(BigDecimal(bid), BigDecimal(ask))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::quote::Quote;
+    use core::str::FromStr;
+    use cosmwasm_std::{Decimal256, Uint256};
+
+    #[test]
+    fn test_mid_and_spread() {
+        let quote = Quote::new(BigDecimal::percent(99), BigDecimal::percent(101)).unwrap();
+        assert_eq!(quote.mid().unwrap(), BigDecimal::one());
+        assert_eq!(
+            quote.spread_bps().unwrap(),
+            BigDecimal::from_str("200").unwrap()
+        );
+
+        let max = Quote::new(BigDecimal::MAX, BigDecimal::MAX).unwrap();
+        assert_eq!(max.mid().unwrap(), BigDecimal::MAX);
+        assert!(max.spread_bps().unwrap().is_zero());
+
+        // (1e18 + (2^256 - 1)) / 2 atoms, which would overflow a 256-bit sum
+        let wide = Quote::new(BigDecimal::one(), BigDecimal::MAX).unwrap();
+        assert_eq!(
+            wide.mid().unwrap(),
+            BigDecimal(Decimal256::new(
+                Uint256::MAX / Uint256::from(2u8) + Uint256::from(500_000_000_000_000_000u64)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_odd_and_minimal_quotes() {
+        let atoms = |n: u64| BigDecimal(Decimal256::new(Uint256::from(n)));
+
+        // odd sums are floored: (3 + 4) / 2 atoms
+        let odd = Quote::new(atoms(3), atoms(4)).unwrap();
+        assert_eq!(odd.mid().unwrap(), atoms(3));
+
+        let odd = Quote::new(
+            BigDecimal::percent(99),
+            BigDecimal::from_str("1.010000000000000001").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(odd.mid().unwrap(), BigDecimal::from_str("1").unwrap());
+
+        let minimal = Quote::new(atoms(1), atoms(1)).unwrap();
+        assert_eq!(minimal.mid().unwrap(), atoms(1));
+        assert!(minimal.spread_bps().unwrap().is_zero());
+
+        let minimal = Quote::new(atoms(1), atoms(2)).unwrap();
+        assert_eq!(minimal.mid().unwrap(), atoms(1));
+        assert_eq!(
+            minimal.spread_bps().unwrap(),
+            BigDecimal::from_str("10000").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(Quote::new(BigDecimal::percent(101), BigDecimal::percent(99)).is_err());
+        assert!(Quote::new(BigDecimal::zero(), BigDecimal::one()).is_err());
+    }
+
+    #[test]
+    fn test_merge() {
+        let a = Quote::new(BigDecimal::percent(100), BigDecimal::percent(102)).unwrap();
+        let b = Quote::new(BigDecimal::percent(104), BigDecimal::percent(106)).unwrap();
+        let merged =
+            Quote::merge(&[(a, BigDecimal::percent(300)), (b, BigDecimal::one())]).unwrap();
+        assert_eq!(merged.bid, BigDecimal::percent(101));
+        assert_eq!(merged.ask, BigDecimal::percent(103));
+        assert!(Quote::merge(&[(a, BigDecimal::zero())]).is_err());
+        assert!(Quote::merge(&[]).is_err());
+    }
+}
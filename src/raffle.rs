@@ -0,0 +1,112 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::rand::Prng;
+use crate::serializable_map::SerializableMap;
+use core::ops::Bound;
+use cosmwasm_std::Addr;
+
+// Running ticket totals mapped to the entry they end at, so the entry holding ticket `t` is the
+// first key above `t`.
+fn cumulative(
+    entries: &[(Addr, BigInteger)],
+) -> Result<(SerializableMap<BigInteger, usize>, BigInteger), CommonError> {
+    let mut map = SerializableMap::new();
+    let mut total = BigInteger::zero();
+    for (i, (_, tickets)) in entries.iter().enumerate() {
+        if tickets.is_zero() {
+            continue;
+        }
+        total = total.checked_add(*tickets)?;
+        map.set(total, i);
+    }
+    Ok((map, total))
+}
+
+// Draws `k` distinct winners, each with odds proportional to its tickets among the entries not
+// drawn yet. The result depends only on the entries, their order and `seed`, so anyone can
+// replay a draw from a published seed. Fewer than `k` winners are returned when fewer entries
+// hold tickets.
+pub fn select_winners(
+    entries: &[(Addr, BigInteger)],
+    seed: [u8; 32],
+    k: usize,
+) -> Result<Vec<Addr>, CommonError> {
+    let mut sorted: Vec<&Addr> = entries.iter().map(|(addr, _)| addr).collect();
+    sorted.sort();
+    if let Some(pair) = sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Err(CommonError::Duplicate(pair[0].to_string()));
+    }
+
+    let mut remaining = entries.to_vec();
+    let mut prng = Prng::new(seed);
+    let mut winners = vec![];
+    while winners.len() < k {
+        let (map, total) = cumulative(&remaining)?;
+        if total.is_zero() {
+            break;
+        }
+        let ticket = prng.next_big_integer(total)?;
+        let (_, index) = map
+            .range((Bound::Excluded(ticket), Bound::Unbounded))
+            .next()
+            .expect("ticket is below the total");
+        winners.push(remaining.remove(*index).0);
+    }
+    Ok(winners)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::commit_reveal::sha256;
+    use crate::error::CommonError;
+    use crate::raffle::select_winners;
+    use cosmwasm_std::Addr;
+
+    fn entries(items: &[(&str, u64)]) -> Vec<(Addr, BigInteger)> {
+        items
+            .iter()
+            .map(|(addr, tickets)| (Addr::unchecked(*addr), BigInteger::from(*tickets)))
+            .collect()
+    }
+
+    #[test]
+    fn test_select_winners() {
+        let entries = entries(&[("alice", 5), ("bob", 0), ("carol", 1), ("dave", 10)]);
+        let seed = sha256(b"round 1");
+
+        let winners = select_winners(&entries, seed, 2).unwrap();
+        assert_eq!(winners.len(), 2);
+        assert_ne!(winners[0], winners[1]);
+        assert_eq!(select_winners(&entries, seed, 2).unwrap(), winners);
+
+        let all = select_winners(&entries, seed, 10).unwrap();
+        assert_eq!(all.len(), 3);
+        assert!(!all.contains(&Addr::unchecked("bob")));
+        assert_eq!(all[..2], winners[..]);
+
+        assert!(select_winners(&entries, seed, 0).unwrap().is_empty());
+        assert!(select_winners(&[], seed, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_odds_follow_tickets() {
+        let entries = entries(&[("alice", 1), ("bob", 3)]);
+        let bob_wins = (0u32..1000)
+            .filter(|i| {
+                let winners = select_winners(&entries, sha256(&i.to_be_bytes()), 1).unwrap();
+                winners[0] == Addr::unchecked("bob")
+            })
+            .count();
+        assert!((680..=820).contains(&bob_wins), "{}", bob_wins);
+    }
+
+    #[test]
+    fn test_duplicate_entries() {
+        let entries = entries(&[("alice", 1), ("bob", 1), ("alice", 2)]);
+        assert_eq!(
+            select_winners(&entries, [0u8; 32], 1),
+            Err(CommonError::Duplicate("alice".to_string()))
+        );
+    }
+}
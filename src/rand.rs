@@ -0,0 +1,171 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::commit_reveal::sha256;
+use crate::error::CommonError;
+use cosmwasm_std::{BlockInfo, Uint256};
+
+// xoshiro256**, for low-stakes randomness only. Seeds from block data and a salt are known to
+// validators and callers in advance; use commit-reveal where the outcome is worth money.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Prng {
+    state: [u64; 4],
+}
+
+impl Prng {
+    pub fn new(seed: [u8; 32]) -> Self {
+        let mut state = [0u64; 4];
+        for (i, word) in state.iter_mut().enumerate() {
+            *word = u64::from_le_bytes(seed[i * 8..(i + 1) * 8].try_into().unwrap());
+        }
+        // the all-zero state is a fixed point of xoshiro
+        if state == [0u64; 4] {
+            state[0] = 1;
+        }
+        Self { state }
+    }
+
+    pub fn from_seed_material(block_hash: &[u8], height: u64, salt: &[u8]) -> Self {
+        let mut material = Vec::with_capacity(block_hash.len() + salt.len() + 16);
+        material.extend_from_slice(&(block_hash.len() as u64).to_be_bytes());
+        material.extend_from_slice(block_hash);
+        material.extend_from_slice(&height.to_be_bytes());
+        material.extend_from_slice(salt);
+        Self::new(sha256(&material))
+    }
+
+    pub fn from_block(block: &BlockInfo, salt: &[u8]) -> Self {
+        let mut block_data = block.chain_id.as_bytes().to_vec();
+        block_data.extend_from_slice(&block.time.nanos().to_be_bytes());
+        Self::from_seed_material(&block_data, block.height, salt)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    // uniform in [0, max)
+    pub fn next_big_integer(&mut self, max: BigInteger) -> Result<BigInteger, CommonError> {
+        if max.is_zero() {
+            return Err(CommonError::Zero("random upper bound".to_string()));
+        }
+        let max_bytes = max.to_be_bytes();
+        // max is non-zero, so there is at least one non-zero byte
+        let first = max_bytes.iter().position(|b| *b != 0).unwrap();
+        let first_mask = u8::MAX >> max_bytes[first].leading_zeros();
+
+        // rejection sampling keeps the distribution uniform, each round succeeds with p > 1/2
+        loop {
+            let mut bytes = [0u8; 32];
+            for chunk in bytes.chunks_mut(8) {
+                chunk.copy_from_slice(&self.next_u64().to_be_bytes());
+            }
+            bytes[..first].fill(0);
+            bytes[first] &= first_mask;
+            let candidate = Uint256::from_be_bytes(bytes);
+            if candidate < max.0 {
+                return Ok(BigInteger(candidate));
+            }
+        }
+    }
+
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.next_index(i + 1);
+            slice.swap(i, j);
+        }
+    }
+
+    // An index with probability proportional to its weight, `None` if all weights are zero.
+    pub fn pick_weighted(&mut self, weights: &[BigDecimal]) -> Result<Option<usize>, CommonError> {
+        let total = BigDecimal::try_sum(weights.iter().copied())?;
+        if total.is_zero() {
+            return Ok(None);
+        }
+        let target = self.next_big_integer(BigInteger(total.0.atomics()))?.0;
+        let mut cumulative = Uint256::zero();
+        for (i, weight) in weights.iter().enumerate() {
+            // cannot overflow, the total fits
+            cumulative += weight.0.atomics();
+            if target < cumulative {
+                return Ok(Some(i));
+            }
+        }
+        Ok(None)
+    }
+
+    fn next_index(&mut self, len: usize) -> usize {
+        let value = self.next_big_integer(BigInteger::from(len as u64)).unwrap();
+        value.to_uint128().unwrap().u128() as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::rand::Prng;
+    use cosmwasm_std::testing::mock_env;
+
+    #[test]
+    fn test_deterministic() {
+        let env = mock_env();
+        let mut a = Prng::from_block(&env.block, b"salt");
+        let mut b = Prng::from_block(&env.block, b"salt");
+        let mut c = Prng::from_block(&env.block, b"pepper");
+        let first = a.next_u64();
+        assert_eq!(first, b.next_u64());
+        assert_ne!(first, c.next_u64());
+    }
+
+    #[test]
+    fn test_next_big_integer() {
+        let mut rng = Prng::new([7u8; 32]);
+        assert_eq!(
+            rng.next_big_integer(BigInteger::zero()),
+            Err(CommonError::Zero("random upper bound".to_string()))
+        );
+        for _ in 0..100 {
+            let value = rng.next_big_integer(BigInteger::from(10u64)).unwrap();
+            assert!(value < BigInteger::from(10u64));
+        }
+        assert_eq!(
+            rng.next_big_integer(BigInteger::one()).unwrap(),
+            BigInteger::zero()
+        );
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = Prng::new([1u8; 32]);
+        let mut items: Vec<u32> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<u32>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn test_pick_weighted() {
+        let mut rng = Prng::new([3u8; 32]);
+        assert_eq!(rng.pick_weighted(&[BigDecimal::zero()]), Ok(None));
+
+        let weights = [BigDecimal::zero(), BigDecimal::one(), BigDecimal::zero()];
+        for _ in 0..20 {
+            assert_eq!(rng.pick_weighted(&weights), Ok(Some(1)));
+        }
+        assert!(matches!(
+            rng.pick_weighted(&[BigDecimal::MAX, BigDecimal::one()]),
+            Err(CommonError::Overflow(_))
+        ));
+    }
+}
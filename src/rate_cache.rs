@@ -0,0 +1,145 @@
+use crate::bigdecimal::BigDecimal;
+use crate::denom_pair::DenomPair;
+use crate::error::CommonError;
+use crate::serializable_map::SerializableMap;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{DivideByZeroError, Timestamp};
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum StalePolicy {
+    Error,
+    // return the last known rate however old it is
+    LastKnown,
+}
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub struct CachedRate {
+    pub rate: BigDecimal,
+    pub updated_at: Timestamp,
+}
+
+// Rates are quote per base. A pair that is not cached directly is resolved through its inverse.
+#[cw_serde]
+pub struct RateCache {
+    pub stale_policy: StalePolicy,
+    pub rates: SerializableMap<DenomPair, CachedRate>,
+}
+
+impl RateCache {
+    pub fn new(stale_policy: StalePolicy) -> Self {
+        Self {
+            stale_policy,
+            rates: SerializableMap::new(),
+        }
+    }
+
+    pub fn set(&mut self, pair: DenomPair, rate: BigDecimal, now: Timestamp) {
+        // drop the inverse so a pair never has two competing entries
+        self.rates.delete(&pair.inverse());
+        self.rates.set(
+            pair,
+            CachedRate {
+                rate,
+                updated_at: now,
+            },
+        );
+    }
+
+    pub fn get(&self, pair: &DenomPair) -> Result<CachedRate, CommonError> {
+        if let Some(cached) = self.rates.get(pair) {
+            return Ok(*cached);
+        }
+        let cached = self
+            .rates
+            .get(&pair.inverse())
+            .ok_or_else(|| CommonError::MissingRate(pair.to_string()))?;
+        if cached.rate.is_zero() {
+            return Err(DivideByZeroError::new().into());
+        }
+        Ok(CachedRate {
+            rate: BigDecimal::one() / cached.rate,
+            updated_at: cached.updated_at,
+        })
+    }
+
+    pub fn get_fresh(
+        &self,
+        pair: &DenomPair,
+        now: Timestamp,
+        max_age: u64,
+    ) -> Result<BigDecimal, CommonError> {
+        let cached = self.get(pair)?;
+        let age = now.seconds().saturating_sub(cached.updated_at.seconds());
+        if age > max_age && self.stale_policy == StalePolicy::Error {
+            return Err(CommonError::StaleRate {
+                pair: pair.to_string(),
+                age,
+                max_age,
+            });
+        }
+        Ok(cached.rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::denom_pair::DenomPair;
+    use crate::error::CommonError;
+    use crate::rate_cache::{RateCache, StalePolicy};
+    use crate::serializable_denom::SerializableDenom;
+    use cosmwasm_std::Timestamp;
+
+    fn pair() -> DenomPair {
+        DenomPair::new(
+            SerializableDenom::Native("uatom".to_string()),
+            SerializableDenom::Native("uusdc".to_string()),
+        )
+    }
+
+    #[test]
+    fn test_get_fresh() {
+        let now = Timestamp::from_seconds(1_000);
+        let mut cache = RateCache::new(StalePolicy::Error);
+        cache.set(pair(), BigDecimal::percent(400), now);
+
+        assert_eq!(
+            cache.get_fresh(&pair(), now.plus_seconds(60), 60).unwrap(),
+            BigDecimal::percent(400)
+        );
+        assert_eq!(
+            cache.get_fresh(&pair().inverse(), now, 60).unwrap(),
+            BigDecimal::percent(25)
+        );
+        assert!(matches!(
+            cache.get_fresh(&pair(), now.plus_seconds(61), 60),
+            Err(CommonError::StaleRate { age: 61, .. })
+        ));
+
+        cache.stale_policy = StalePolicy::LastKnown;
+        assert_eq!(
+            cache.get_fresh(&pair(), now.plus_seconds(61), 60).unwrap(),
+            BigDecimal::percent(400)
+        );
+    }
+
+    #[test]
+    fn test_missing_and_inverse_update() {
+        let now = Timestamp::from_seconds(1_000);
+        let mut cache = RateCache::new(StalePolicy::Error);
+        assert!(matches!(
+            cache.get_fresh(&pair(), now, 60),
+            Err(CommonError::MissingRate(_))
+        ));
+
+        cache.set(pair(), BigDecimal::percent(400), now);
+        cache.set(pair().inverse(), BigDecimal::percent(50), now);
+        assert_eq!(cache.rates.len(), 1);
+        assert_eq!(
+            cache.get_fresh(&pair(), now, 60).unwrap(),
+            BigDecimal::percent(200)
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use crate::balances::Balances;
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::number::Number;
+use crate::serializable_denom::SerializableDenom;
+use crate::serializable_map::SerializableMap;
+use core::cmp::Reverse;
+use cosmwasm_schema::cw_serde;
+use std::collections::BTreeSet;
+
+#[cw_serde]
+pub struct RebalanceLeg {
+    pub sell: SerializableDenom,
+    pub buy: SerializableDenom,
+    // amount of `sell` to swap, in its base units
+    pub amount: BigInteger,
+}
+
+struct Gap<'a> {
+    denom: &'a SerializableDenom,
+    value: BigDecimal,
+    price: BigDecimal,
+}
+
+// Swaps that move `balances` to the target weights. `prices` value one base unit of each denom in
+// a common quote; denoms whose share deviates from its target by at most `tolerance` are left
+// alone. The largest surplus is matched against the largest deficit first, ties in denom order,
+// so the same inputs always give the same legs and no more than (sellers + buyers - 1) of them.
+pub fn rebalance(
+    balances: &Balances,
+    targets: &SerializableMap<SerializableDenom, BigDecimal>,
+    prices: &SerializableMap<SerializableDenom, BigDecimal>,
+    tolerance: BigDecimal,
+) -> Result<Vec<RebalanceLeg>, CommonError> {
+    let total_weight = BigDecimal::try_sum(targets.iter().map(|(_, weight)| *weight))?;
+    if total_weight != BigDecimal::one() {
+        return Err(CommonError::InvalidWeights(format!(
+            "target weights sum to {}",
+            total_weight
+        )));
+    }
+
+    let denoms: BTreeSet<&SerializableDenom> = targets
+        .iter()
+        .map(|(denom, _)| denom)
+        .chain(balances.iter().map(|(denom, _)| denom))
+        .collect();
+    let mut holdings = vec![];
+    for denom in denoms {
+        let balance = balances.get(denom);
+        let weight = targets.get(denom).copied().unwrap_or_default();
+        if balance.is_zero() && weight.is_zero() {
+            continue;
+        }
+        let price = *prices
+            .get(denom)
+            .ok_or_else(|| CommonError::MissingRate(denom.to_string()))?;
+        let value = BigDecimal::checked_from(balance, 0)?.checked_mul(price)?;
+        holdings.push((denom, weight, value, price));
+    }
+
+    let total = BigDecimal::try_sum(holdings.iter().map(|(_, _, value, _)| *value))?;
+    if total.is_zero() {
+        return Ok(vec![]);
+    }
+    let band = total.checked_mul(tolerance)?;
+
+    let mut surpluses = vec![];
+    let mut deficits = vec![];
+    for (denom, weight, value, price) in holdings {
+        let target = total.checked_mul(weight)?;
+        if value > target && value - target > band {
+            surpluses.push(Gap {
+                denom,
+                value: value - target,
+                price,
+            });
+        } else if target > value && target - value > band {
+            deficits.push(Gap {
+                denom,
+                value: target - value,
+                price,
+            });
+        }
+    }
+    // stable sorts keep denom order between equal gaps
+    surpluses.sort_by_key(|gap| Reverse(gap.value));
+    deficits.sort_by_key(|gap| Reverse(gap.value));
+
+    let mut legs = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < surpluses.len() && j < deficits.len() {
+        let value = surpluses[i].value.min(deficits[j].value);
+        let amount = value.checked_div(surpluses[i].price)?.scale_up(0);
+        if !amount.is_zero() {
+            legs.push(RebalanceLeg {
+                sell: surpluses[i].denom.clone(),
+                buy: deficits[j].denom.clone(),
+                amount,
+            });
+        }
+        surpluses[i].value -= value;
+        deficits[j].value -= value;
+        if surpluses[i].value.is_zero() {
+            i += 1;
+        }
+        if deficits[j].value.is_zero() {
+            j += 1;
+        }
+    }
+    Ok(legs)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::balances::Balances;
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::rebalance::{rebalance, RebalanceLeg};
+    use crate::serializable_denom::SerializableDenom;
+    use crate::serializable_map::SerializableMap;
+
+    fn denom(name: &str) -> SerializableDenom {
+        SerializableDenom::Native(name.to_string())
+    }
+
+    fn balances(items: &[(&str, u64)]) -> Balances {
+        let mut balances = Balances::new();
+        for (name, amount) in items {
+            balances
+                .add(denom(name), BigInteger::from(*amount))
+                .unwrap();
+        }
+        balances
+    }
+
+    fn map(items: &[(&str, BigDecimal)]) -> SerializableMap<SerializableDenom, BigDecimal> {
+        SerializableMap::from(
+            items
+                .iter()
+                .map(|(name, value)| (denom(name), *value))
+                .collect(),
+        )
+    }
+
+    fn leg(sell: &str, buy: &str, amount: u64) -> RebalanceLeg {
+        RebalanceLeg {
+            sell: denom(sell),
+            buy: denom(buy),
+            amount: BigInteger::from(amount),
+        }
+    }
+
+    #[test]
+    fn test_two_assets() {
+        let targets = map(&[
+            ("a", BigDecimal::percent(50)),
+            ("b", BigDecimal::percent(50)),
+        ]);
+        let prices = map(&[("a", BigDecimal::one()), ("b", BigDecimal::percent(200))]);
+        assert_eq!(
+            rebalance(
+                &balances(&[("a", 100)]),
+                &targets,
+                &prices,
+                BigDecimal::zero()
+            )
+            .unwrap(),
+            vec![leg("a", "b", 50)]
+        );
+        // amounts are in the sold denom: a surplus worth 15 is 7 b at a price of 2
+        assert_eq!(
+            rebalance(
+                &balances(&[("a", 10), ("b", 20)]),
+                &targets,
+                &prices,
+                BigDecimal::zero()
+            )
+            .unwrap(),
+            vec![leg("b", "a", 7)]
+        );
+    }
+
+    #[test]
+    fn test_tolerance_band() {
+        let targets = map(&[
+            ("a", BigDecimal::percent(50)),
+            ("b", BigDecimal::percent(50)),
+        ]);
+        let prices = map(&[("a", BigDecimal::one()), ("b", BigDecimal::percent(200))]);
+        let current = balances(&[("a", 54), ("b", 23)]);
+        assert!(
+            rebalance(&current, &targets, &prices, BigDecimal::percent(5))
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            rebalance(&current, &targets, &prices, BigDecimal::percent(3)).unwrap(),
+            vec![leg("a", "b", 4)]
+        );
+    }
+
+    #[test]
+    fn test_untargeted_denoms_are_sold() {
+        let targets = map(&[
+            ("b", BigDecimal::percent(50)),
+            ("c", BigDecimal::percent(50)),
+        ]);
+        let prices = map(&[
+            ("a", BigDecimal::one()),
+            ("b", BigDecimal::one()),
+            ("c", BigDecimal::one()),
+        ]);
+        assert_eq!(
+            rebalance(
+                &balances(&[("a", 300), ("b", 100)]),
+                &targets,
+                &prices,
+                BigDecimal::zero()
+            )
+            .unwrap(),
+            vec![leg("a", "c", 200), leg("a", "b", 100)]
+        );
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let prices = map(&[("a", BigDecimal::one())]);
+        assert!(matches!(
+            rebalance(
+                &balances(&[("a", 1)]),
+                &map(&[("a", BigDecimal::percent(90))]),
+                &prices,
+                BigDecimal::zero()
+            ),
+            Err(CommonError::InvalidWeights(_))
+        ));
+        assert_eq!(
+            rebalance(
+                &balances(&[("a", 1)]),
+                &map(&[
+                    ("a", BigDecimal::percent(50)),
+                    ("b", BigDecimal::percent(50))
+                ]),
+                &prices,
+                BigDecimal::zero()
+            ),
+            Err(CommonError::MissingRate(denom("b").to_string()))
+        );
+
+        let mut huge = Balances::new();
+        huge.add(denom("a"), BigInteger::MAX).unwrap();
+        assert!(rebalance(
+            &huge,
+            &map(&[("a", BigDecimal::one())]),
+            &prices,
+            BigDecimal::zero()
+        )
+        .is_err());
+    }
+}
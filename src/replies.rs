@@ -0,0 +1,134 @@
+use crate::error::CommonError;
+use cosmwasm_std::{Binary, Reply, SubMsgResult};
+use cw_utils::{
+    parse_instantiate_response_data, MsgExecuteContractResponse, MsgInstantiateContractResponse,
+};
+
+pub trait ReplyId: Sized {
+    fn to_reply_id(&self) -> u64;
+
+    fn from_reply_id(id: u64) -> Result<Self, CommonError>;
+
+    fn from_reply(reply: &Reply) -> Result<Self, CommonError> {
+        Self::from_reply_id(reply.id)
+    }
+}
+
+#[macro_export]
+macro_rules! reply_ids {
+    ($vis:vis enum $name:ident { $($variant:ident = $id:expr),* $(,)? }) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        $vis enum $name {
+            $($variant),*
+        }
+
+        impl $crate::replies::ReplyId for $name {
+            fn to_reply_id(&self) -> u64 {
+                match self {
+                    $($name::$variant => $id),*
+                }
+            }
+
+            fn from_reply_id(id: u64) -> Result<Self, $crate::error::CommonError> {
+                match id {
+                    $($id => Ok($name::$variant),)*
+                    _ => Err($crate::error::CommonError::UnknownReplyId(id)),
+                }
+            }
+        }
+    };
+}
+
+// wasmd 0.50+ fills msg_responses, older chains only set the deprecated data field
+pub fn reply_data(reply: &Reply) -> Result<Binary, CommonError> {
+    match &reply.result {
+        SubMsgResult::Err(err) => Err(CommonError::SubMsgFailure(err.clone())),
+        SubMsgResult::Ok(response) => {
+            if let Some(msg_response) = response.msg_responses.first() {
+                return Ok(msg_response.value.clone());
+            }
+            #[allow(deprecated)]
+            response.data.clone().ok_or(CommonError::MissingReplyData)
+        }
+    }
+}
+
+pub fn parse_reply_instantiate_data(
+    reply: &Reply,
+) -> Result<MsgInstantiateContractResponse, CommonError> {
+    Ok(parse_instantiate_response_data(&reply_data(reply)?)?)
+}
+
+// Same name as the cw_utils helper it wraps, which takes the raw data instead of the reply.
+pub fn parse_execute_response_data(
+    reply: &Reply,
+) -> Result<MsgExecuteContractResponse, CommonError> {
+    Ok(cw_utils::parse_execute_response_data(&reply_data(reply)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::CommonError;
+    use crate::replies::{parse_execute_response_data, parse_reply_instantiate_data, ReplyId};
+    use cosmwasm_std::{Binary, MsgResponse, Reply, SubMsgResponse, SubMsgResult};
+
+    reply_ids!(
+        enum TestReply {
+            Instantiate = 1,
+            Execute = 2,
+        }
+    );
+
+    #[allow(deprecated)]
+    fn reply(id: u64, value: Vec<u8>) -> Reply {
+        Reply {
+            id,
+            payload: Binary::default(),
+            gas_used: 0,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+                msg_responses: vec![MsgResponse {
+                    type_url: "/cosmwasm.wasm.v1.MsgInstantiateContractResponse".to_string(),
+                    value: Binary::new(value),
+                }],
+            }),
+        }
+    }
+
+    #[test]
+    fn test_reply_id() {
+        assert_eq!(TestReply::Execute.to_reply_id(), 2);
+        assert_eq!(TestReply::from_reply_id(1).unwrap(), TestReply::Instantiate);
+        assert_eq!(
+            TestReply::from_reply_id(3),
+            Err(CommonError::UnknownReplyId(3))
+        );
+    }
+
+    #[test]
+    fn test_parse_instantiate() {
+        let mut data = vec![0x0a, 8];
+        data.extend_from_slice(b"contract");
+        data.extend_from_slice(&[0x12, 2, 0xbe, 0xef]);
+
+        let response = parse_reply_instantiate_data(&reply(1, data)).unwrap();
+        assert_eq!(response.contract_address, "contract");
+        assert_eq!(response.data, Some(Binary::new(vec![0xbe, 0xef])));
+    }
+
+    #[test]
+    fn test_parse_execute() {
+        let response = parse_execute_response_data(&reply(2, vec![0x0a, 1, 0x01])).unwrap();
+        assert_eq!(response.data, Some(Binary::new(vec![0x01])));
+
+        let failed = Reply {
+            result: SubMsgResult::Err("out of gas".to_string()),
+            ..reply(2, vec![])
+        };
+        assert_eq!(
+            parse_execute_response_data(&failed),
+            Err(CommonError::SubMsgFailure("out of gas".to_string()))
+        );
+    }
+}
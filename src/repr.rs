@@ -0,0 +1,322 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use core::fmt::Formatter;
+use core::str::FromStr;
+use cosmwasm_schema::serde::de::{Error, Visitor};
+use cosmwasm_schema::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use cosmwasm_std::{Decimal256, Uint256, Uint64};
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{
+    InstanceType, Metadata, Schema, SchemaObject, SingleOrVec, StringValidation,
+};
+use schemars::JsonSchema;
+
+// Serialized as a JSON number when the value fits in u64, otherwise as a decimal string.
+// Deserialization accepts both forms.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct BigIntegerRaw(pub BigInteger);
+
+// Serialized as the string of the raw 10^-18 atomics, e.g. "1500000000000000000" for 1.5.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct BigDecimalAtomics(pub BigDecimal);
+
+// Serialized with exactly `PLACES` fractional digits, e.g. "1.500000000000000000" for 1.5, for
+// consumers that need fixed-width fractions. Serializing a value with more significant digits
+// than `PLACES` fails rather than rounding. Deserialization accepts any decimal string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct BigDecimalFixed<const PLACES: u32 = 18>(pub BigDecimal);
+
+impl From<BigInteger> for BigIntegerRaw {
+    fn from(value: BigInteger) -> Self {
+        Self(value)
+    }
+}
+
+impl From<BigIntegerRaw> for BigInteger {
+    fn from(value: BigIntegerRaw) -> Self {
+        value.0
+    }
+}
+
+impl From<BigDecimal> for BigDecimalAtomics {
+    fn from(value: BigDecimal) -> Self {
+        Self(value)
+    }
+}
+
+impl From<BigDecimalAtomics> for BigDecimal {
+    fn from(value: BigDecimalAtomics) -> Self {
+        value.0
+    }
+}
+
+impl Serialize for BigIntegerRaw {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match Uint64::try_from(self.0 .0) {
+            Ok(value) => serializer.serialize_u64(value.u64()),
+            Err(_) => serializer.serialize_str(&self.0.to_string()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BigIntegerRaw {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(BigIntegerRawVisitor)
+    }
+}
+
+struct BigIntegerRawVisitor;
+
+impl<'de> Visitor<'de> for BigIntegerRawVisitor {
+    type Value = BigIntegerRaw;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("an unsigned integer or a string-encoded integer")
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(BigIntegerRaw(BigInteger::from(v)))
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        u64::try_from(v)
+            .map(|v| BigIntegerRaw(BigInteger::from(v)))
+            .map_err(|_| E::custom(format!("negative integer: {}", v)))
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        BigInteger::from_str(v)
+            .map(BigIntegerRaw)
+            .map_err(|err| E::custom(err.to_string()))
+    }
+}
+
+impl<const PLACES: u32> From<BigDecimal> for BigDecimalFixed<PLACES> {
+    fn from(value: BigDecimal) -> Self {
+        Self(value)
+    }
+}
+
+impl<const PLACES: u32> From<BigDecimalFixed<PLACES>> for BigDecimal {
+    fn from(value: BigDecimalFixed<PLACES>) -> Self {
+        value.0
+    }
+}
+
+impl<const PLACES: u32> Serialize for BigDecimalFixed<PLACES> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.0.significant_fraction_digits() > PLACES {
+            return Err(cosmwasm_schema::serde::ser::Error::custom(format!(
+                "{} has more than {} fractional digits",
+                self.0, PLACES
+            )));
+        }
+        let atomics = self.0 .0.atomics();
+        let one = Decimal256::one().atomics();
+        let places = PLACES as usize;
+        let mut fraction = format!("{:0>18}", (atomics % one).to_string());
+        if places < fraction.len() {
+            fraction.truncate(places);
+        } else {
+            fraction.push_str(&"0".repeat(places - fraction.len()));
+        }
+        let integer = atomics / one;
+        if fraction.is_empty() {
+            serializer.serialize_str(&integer.to_string())
+        } else {
+            serializer.serialize_str(&format!("{}.{}", integer, fraction))
+        }
+    }
+}
+
+impl<'de, const PLACES: u32> Deserialize<'de> for BigDecimalFixed<PLACES> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        BigDecimal::from_str(&value)
+            .map(Self)
+            .map_err(|err| D::Error::custom(err.to_string()))
+    }
+}
+
+impl Serialize for BigDecimalAtomics {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0 .0.atomics().to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for BigDecimalAtomics {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let atomics = String::deserialize(deserializer)?;
+        let atomics =
+            Uint256::from_str(&atomics).map_err(|err| D::Error::custom(err.to_string()))?;
+        Ok(Self(BigDecimal(Decimal256::new(atomics))))
+    }
+}
+
+// String schema for numbers encoded as decimal strings, so codegen emits a typed string
+// instead of `any`.
+pub(crate) fn numeric_string_schema(pattern: &str, description: &str, examples: &[&str]) -> Schema {
+    Schema::Object(SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        string: Some(Box::new(StringValidation {
+            pattern: Some(pattern.to_string()),
+            ..Default::default()
+        })),
+        metadata: Some(Box::new(Metadata {
+            description: Some(description.to_string()),
+            examples: examples
+                .iter()
+                .map(|e| serde_json::Value::from(*e))
+                .collect(),
+            ..Default::default()
+        })),
+        ..Default::default()
+    })
+}
+
+impl JsonSchema for BigIntegerRaw {
+    fn schema_name() -> String {
+        "BigIntegerRaw".to_string()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        Schema::Object(SchemaObject {
+            instance_type: Some(SingleOrVec::Vec(vec![
+                InstanceType::Integer,
+                InstanceType::String,
+            ])),
+            ..Default::default()
+        })
+    }
+}
+
+impl JsonSchema for BigDecimalAtomics {
+    fn schema_name() -> String {
+        "BigDecimalAtomics".to_string()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        Schema::Object(SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            ..Default::default()
+        })
+    }
+}
+
+impl<const PLACES: u32> JsonSchema for BigDecimalFixed<PLACES> {
+    fn schema_name() -> String {
+        format!("BigDecimalFixed{}", PLACES)
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let pattern = match PLACES {
+            0 => "^[0-9]+$".to_string(),
+            places => format!(r"^[0-9]+\.[0-9]{{{}}}$", places),
+        };
+        numeric_string_schema(
+            &pattern,
+            &format!("Decimal string with exactly {} fractional digits", PLACES),
+            &[],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::repr::{BigDecimalAtomics, BigDecimalFixed, BigIntegerRaw};
+    use cosmwasm_std::{from_json, to_json_string};
+
+    #[test]
+    fn test_big_integer_raw() {
+        let small = BigIntegerRaw(BigInteger::from(42u64));
+        assert_eq!(to_json_string(&small).unwrap(), "42");
+        assert_eq!(from_json::<BigIntegerRaw>("42").unwrap(), small);
+        assert_eq!(from_json::<BigIntegerRaw>("\"42\"").unwrap(), small);
+
+        let large = BigIntegerRaw(BigInteger::from(u64::MAX) + BigInteger::one());
+        assert_eq!(to_json_string(&large).unwrap(), "\"18446744073709551616\"");
+        assert_eq!(
+            from_json::<BigIntegerRaw>("\"18446744073709551616\"").unwrap(),
+            large
+        );
+        assert!(from_json::<BigIntegerRaw>("-1").is_err());
+    }
+
+    #[test]
+    fn test_big_decimal_atomics() {
+        let value = BigDecimalAtomics(BigDecimal::percent(150));
+        assert_eq!(to_json_string(&value).unwrap(), "\"1500000000000000000\"");
+        assert_eq!(
+            from_json::<BigDecimalAtomics>("\"1500000000000000000\"").unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn test_big_decimal_fixed() {
+        let value: BigDecimalFixed = BigDecimal::percent(150).into();
+        assert_eq!(to_json_string(&value).unwrap(), "\"1.500000000000000000\"");
+        assert_eq!(
+            to_json_string(&BigDecimalFixed::<18>(BigDecimal::zero())).unwrap(),
+            "\"0.000000000000000000\""
+        );
+        assert_eq!(
+            to_json_string(&BigDecimalFixed::<4>(BigDecimal::percent(150))).unwrap(),
+            "\"1.5000\""
+        );
+        assert_eq!(
+            to_json_string(&BigDecimalFixed::<20>(BigDecimal::percent(150))).unwrap(),
+            "\"1.50000000000000000000\""
+        );
+        assert_eq!(
+            to_json_string(&BigDecimalFixed::<0>(BigDecimal::percent(300))).unwrap(),
+            "\"3\""
+        );
+        assert!(to_json_string(&BigDecimalFixed::<1>(BigDecimal::percent(125))).is_err());
+
+        assert_eq!(
+            from_json::<BigDecimalFixed>("\"1.500000000000000000\"").unwrap(),
+            value
+        );
+        assert_eq!(from_json::<BigDecimalFixed>("\"1.5\"").unwrap(), value);
+
+        let schema = serde_json::to_value(schemars::schema_for!(BigDecimalFixed<6>)).unwrap();
+        assert_eq!(schema["pattern"], r"^[0-9]+\.[0-9]{6}$");
+    }
+
+    #[test]
+    fn test_numeric_schemas() {
+        let schema = serde_json::to_value(schemars::schema_for!(BigInteger)).unwrap();
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["pattern"], "^[0-9]+$");
+        assert_eq!(schema["examples"][1], "1000000");
+
+        let schema = serde_json::to_value(schemars::schema_for!(BigDecimal)).unwrap();
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["pattern"], r"^[0-9]+(\.[0-9]{1,18})?$");
+        assert!(schema["description"]
+            .as_str()
+            .unwrap()
+            .contains("18 fractional digits"));
+    }
+}
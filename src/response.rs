@@ -0,0 +1,157 @@
+use crate::events::AttrValue;
+use cosmwasm_std::{Attribute, Binary, CosmosMsg, Empty, Event, Response, SubMsg};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResponseBuilder<T = Empty> {
+    messages: Vec<SubMsg<T>>,
+    attributes: Vec<Attribute>,
+    events: Vec<Event>,
+    data: Option<Binary>,
+}
+
+impl<T> Default for ResponseBuilder<T> {
+    fn default() -> Self {
+        Self {
+            messages: vec![],
+            attributes: vec![],
+            events: vec![],
+            data: None,
+        }
+    }
+}
+
+impl<T> ResponseBuilder<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_msg(mut self, msg: impl Into<CosmosMsg<T>>) -> Self {
+        self.messages.push(SubMsg::new(msg));
+        self
+    }
+
+    pub fn add_msg_if(self, condition: bool, msg: impl Into<CosmosMsg<T>>) -> Self {
+        if condition {
+            self.add_msg(msg)
+        } else {
+            self
+        }
+    }
+
+    pub fn add_msgs<M: Into<CosmosMsg<T>>>(mut self, msgs: impl IntoIterator<Item = M>) -> Self {
+        self.messages.extend(msgs.into_iter().map(SubMsg::new));
+        self
+    }
+
+    pub fn add_submsg(mut self, msg: SubMsg<T>) -> Self {
+        self.messages.push(msg);
+        self
+    }
+
+    pub fn add_submsg_if(self, condition: bool, msg: SubMsg<T>) -> Self {
+        if condition {
+            self.add_submsg(msg)
+        } else {
+            self
+        }
+    }
+
+    pub fn add_reply_on_success(self, msg: impl Into<CosmosMsg<T>>, id: u64) -> Self {
+        self.add_submsg(SubMsg::reply_on_success(msg, id))
+    }
+
+    pub fn add_reply_on_error(self, msg: impl Into<CosmosMsg<T>>, id: u64) -> Self {
+        self.add_submsg(SubMsg::reply_on_error(msg, id))
+    }
+
+    pub fn add_reply_always(self, msg: impl Into<CosmosMsg<T>>, id: u64) -> Self {
+        self.add_submsg(SubMsg::reply_always(msg, id))
+    }
+
+    pub fn add_attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.push(Attribute::new(key, value));
+        self
+    }
+
+    pub fn add_attribute_if(
+        self,
+        condition: bool,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        if condition {
+            self.add_attribute(key, value)
+        } else {
+            self
+        }
+    }
+
+    pub fn add_typed_attribute(self, key: impl Into<String>, value: &impl AttrValue) -> Self {
+        self.add_attribute(key, value.to_attr_value())
+    }
+
+    pub fn add_attributes(mut self, attributes: impl IntoIterator<Item = Attribute>) -> Self {
+        self.attributes.extend(attributes);
+        self
+    }
+
+    pub fn add_event(mut self, event: Event) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    pub fn add_event_if(self, condition: bool, event: Event) -> Self {
+        if condition {
+            self.add_event(event)
+        } else {
+            self
+        }
+    }
+
+    pub fn set_data(mut self, data: impl Into<Binary>) -> Self {
+        self.data = Some(data.into());
+        self
+    }
+
+    pub fn build(self) -> Response<T> {
+        let mut response = Response::new()
+            .add_submessages(self.messages)
+            .add_attributes(self.attributes)
+            .add_events(self.events);
+        if let Some(data) = self.data {
+            response = response.set_data(data);
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::response::ResponseBuilder;
+    use cosmwasm_std::{coins, BankMsg, Event, ReplyOn, Response};
+
+    #[test]
+    fn test_build() {
+        let send = BankMsg::Send {
+            to_address: "recipient".to_string(),
+            amount: coins(100, "uatom"),
+        };
+        let response: Response = ResponseBuilder::new()
+            .add_msg(send.clone())
+            .add_msg_if(false, send.clone())
+            .add_reply_on_success(send, 7)
+            .add_attribute("action", "swap")
+            .add_attribute_if(false, "skipped", "true")
+            .add_typed_attribute("amount", &BigInteger::from(100u64))
+            .add_event_if(true, Event::new("swapped"))
+            .build();
+
+        assert_eq!(response.messages.len(), 2);
+        assert_eq!(response.messages[1].id, 7);
+        assert_eq!(response.messages[1].reply_on, ReplyOn::Success);
+        assert_eq!(response.attributes.len(), 2);
+        assert_eq!(response.attributes[1].value, "100");
+        assert_eq!(response.events.len(), 1);
+    }
+}
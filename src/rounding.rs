@@ -0,0 +1,95 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::math::u256::mul_div_512;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint512;
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum RoundingMode {
+    Floor,
+    Ceil,
+    // round to nearest, ties away from zero
+    HalfUp,
+    // round to nearest, ties to even
+    HalfEven,
+}
+
+impl RoundingMode {
+    // whether a floored quotient with the given remainder should be incremented
+    pub(crate) fn rounds_up(
+        self,
+        quotient: Uint512,
+        remainder: Uint512,
+        denominator: Uint512,
+    ) -> bool {
+        if remainder.is_zero() {
+            return false;
+        }
+        let rest = denominator - remainder;
+        match self {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => true,
+            RoundingMode::HalfUp => remainder >= rest,
+            RoundingMode::HalfEven => {
+                remainder > rest
+                    || (remainder == rest && !(quotient % Uint512::from(2u8)).is_zero())
+            }
+        }
+    }
+}
+
+// a * b / denominator with a 512-bit intermediate product
+pub fn mul_div(
+    a: BigInteger,
+    b: BigInteger,
+    denominator: BigInteger,
+    mode: RoundingMode,
+) -> Result<BigInteger, CommonError> {
+    Ok(BigInteger(mul_div_512(a.0, b.0, denominator.0, mode)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::rounding::{mul_div, RoundingMode};
+
+    #[test]
+    fn test_mul_div() {
+        let seven = BigInteger::from(7u64);
+        let two = BigInteger::from(2u64);
+        assert_eq!(
+            mul_div(seven, BigInteger::one(), two, RoundingMode::Floor).unwrap(),
+            BigInteger::from(3u64)
+        );
+        assert_eq!(
+            mul_div(seven, BigInteger::one(), two, RoundingMode::Ceil).unwrap(),
+            BigInteger::from(4u64)
+        );
+        assert_eq!(
+            mul_div(BigInteger::MAX, two, two, RoundingMode::Floor).unwrap(),
+            BigInteger::MAX
+        );
+        assert!(mul_div(BigInteger::MAX, two, BigInteger::one(), RoundingMode::Floor).is_err());
+        assert!(mul_div(seven, two, BigInteger::zero(), RoundingMode::Floor).is_err());
+    }
+
+    #[test]
+    fn test_half_rounding() {
+        let round = |a: u64, b: u64, mode| {
+            mul_div(
+                BigInteger::from(a),
+                BigInteger::one(),
+                BigInteger::from(b),
+                mode,
+            )
+            .unwrap()
+        };
+        assert_eq!(round(5, 2, RoundingMode::HalfUp), BigInteger::from(3u64));
+        assert_eq!(round(5, 2, RoundingMode::HalfEven), BigInteger::from(2u64));
+        assert_eq!(round(7, 2, RoundingMode::HalfEven), BigInteger::from(4u64));
+        assert_eq!(round(4, 3, RoundingMode::HalfUp), BigInteger::from(1u64));
+        assert_eq!(round(5, 3, RoundingMode::HalfUp), BigInteger::from(2u64));
+        assert_eq!(round(5, 3, RoundingMode::HalfEven), BigInteger::from(2u64));
+    }
+}
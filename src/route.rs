@@ -0,0 +1,153 @@
+use crate::amm::{constant_product_out, PoolState};
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::denom_pair::DenomPair;
+use crate::error::CommonError;
+use crate::serializable_denom::SerializableDenom;
+use crate::serializable_map::SerializableMap;
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct Hop {
+    pub pair: DenomPair,
+    pub fee: BigDecimal,
+}
+
+#[cw_serde]
+pub struct Route {
+    pub offer: SerializableDenom,
+    pub hops: Vec<Hop>,
+}
+
+impl Route {
+    pub fn new(offer: SerializableDenom, hops: Vec<Hop>) -> Result<Self, CommonError> {
+        let route = Self { offer, hops };
+        route.validate()?;
+        Ok(route)
+    }
+
+    // Each hop has to start from the previous hop's output and no denom may be visited twice.
+    pub fn validate(&self) -> Result<(), CommonError> {
+        if self.hops.is_empty() {
+            return Err(CommonError::InvalidRoute("route has no hops".to_string()));
+        }
+        let denoms = self.denoms()?;
+        for (i, denom) in denoms.iter().enumerate() {
+            if denoms[..i].contains(denom) {
+                return Err(CommonError::InvalidRoute(format!(
+                    "cycle through {}",
+                    denom
+                )));
+            }
+        }
+        for hop in &self.hops {
+            if !hop.fee.is_ratio() {
+                return Err(CommonError::InvalidRatio(hop.fee.to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn ask(&self) -> Result<SerializableDenom, CommonError> {
+        Ok(self.denoms()?.pop().unwrap_or_else(|| self.offer.clone()))
+    }
+
+    // offer denom followed by the output of every hop
+    fn denoms(&self) -> Result<Vec<SerializableDenom>, CommonError> {
+        let mut denoms = vec![self.offer.clone()];
+        for hop in &self.hops {
+            let current = denoms.last().unwrap();
+            let next = hop.pair.other(current).ok_or_else(|| {
+                CommonError::InvalidRoute(format!("{} is not connected to {}", hop.pair, current))
+            })?;
+            denoms.push(next.clone());
+        }
+        Ok(denoms)
+    }
+
+    // Pool states are looked up by the pair exactly as stored in the hop.
+    pub fn estimate_out(
+        &self,
+        amount_in: BigInteger,
+        pool_states: &SerializableMap<DenomPair, PoolState>,
+    ) -> Result<BigInteger, CommonError> {
+        let denoms = self.denoms()?;
+        let mut amount = amount_in;
+        for (hop, denom_in) in self.hops.iter().zip(denoms.iter()) {
+            let pool = pool_states
+                .get(&hop.pair)
+                .ok_or_else(|| CommonError::InvalidRoute(format!("no pool for {}", hop.pair)))?;
+            let (reserve_in, reserve_out) = if hop.pair.base == *denom_in {
+                (pool.base_reserve, pool.quote_reserve)
+            } else {
+                (pool.quote_reserve, pool.base_reserve)
+            };
+            amount = constant_product_out(amount, reserve_in, reserve_out, hop.fee)?;
+        }
+        Ok(amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::amm::PoolState;
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::denom_pair::DenomPair;
+    use crate::error::CommonError;
+    use crate::route::{Hop, Route};
+    use crate::serializable_denom::SerializableDenom;
+    use crate::serializable_map::SerializableMap;
+
+    fn native(denom: &str) -> SerializableDenom {
+        SerializableDenom::Native(denom.to_string())
+    }
+
+    fn hop(base: &str, quote: &str) -> Hop {
+        Hop {
+            pair: DenomPair::new(native(base), native(quote)),
+            fee: BigDecimal::zero(),
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let route = Route::new(native("a"), vec![hop("a", "b"), hop("c", "b")]).unwrap();
+        assert_eq!(route.ask().unwrap(), native("c"));
+
+        assert!(matches!(
+            Route::new(native("a"), vec![hop("a", "b"), hop("c", "d")]),
+            Err(CommonError::InvalidRoute(_))
+        ));
+        assert!(matches!(
+            Route::new(native("a"), vec![hop("a", "b"), hop("b", "a")]),
+            Err(CommonError::InvalidRoute(_))
+        ));
+        assert!(Route::new(native("a"), vec![]).is_err());
+    }
+
+    #[test]
+    fn test_estimate_out() {
+        let route = Route::new(native("a"), vec![hop("a", "b"), hop("c", "b")]).unwrap();
+        let mut pools = SerializableMap::new();
+        pools.set(
+            hop("a", "b").pair,
+            PoolState::new(BigInteger::from(1_000u64), BigInteger::from(2_000u64)),
+        );
+        // 100 a -> 181 b
+        let out = route.estimate_out(BigInteger::from(100u64), &pools);
+        assert!(matches!(out, Err(CommonError::InvalidRoute(_))));
+
+        pools.set(
+            hop("c", "b").pair,
+            PoolState::new(BigInteger::from(500u64), BigInteger::from(500u64)),
+        );
+        // 181 b -> 132 c
+        assert_eq!(
+            route
+                .estimate_out(BigInteger::from(100u64), &pools)
+                .unwrap(),
+            BigInteger::from(132u64)
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use crate::error::CommonError;
+use crate::serializable_map::SerializableMap;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::serde::Serialize;
+use cosmwasm_std::Timestamp;
+
+#[cw_serde]
+pub enum Job<T> {
+    Once(T),
+    // rescheduled `interval_seconds` after its due time every time it runs
+    Recurring { payload: T, interval_seconds: u64 },
+}
+
+impl<T> Job<T> {
+    pub fn payload(&self) -> &T {
+        match self {
+            Job::Once(payload) => payload,
+            Job::Recurring { payload, .. } => payload,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct DueJob<T> {
+    pub id: u64,
+    pub due: Timestamp,
+    pub payload: T,
+}
+
+// Jobs indexed by (due time, id), so due jobs come out in due order and ties in schedule order.
+#[cw_serde]
+pub struct Scheduler<T>
+where
+    T: Serialize,
+{
+    pub next_id: u64,
+    pub jobs: SerializableMap<(Timestamp, u64), Job<T>>,
+}
+
+impl<T> Default for Scheduler<T>
+where
+    T: Serialize,
+{
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            jobs: SerializableMap::new(),
+        }
+    }
+}
+
+impl<T> Scheduler<T>
+where
+    T: Clone + Serialize,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    pub fn schedule(&mut self, due: Timestamp, job: Job<T>) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.jobs.set((due, id), job);
+        id
+    }
+
+    pub fn next_due(&self) -> Option<Timestamp> {
+        self.jobs.first().map(|((due, _), _)| *due)
+    }
+
+    fn find(&self, id: u64) -> Option<(Timestamp, u64)> {
+        self.jobs
+            .iter()
+            .map(|(key, _)| *key)
+            .find(|(_, job_id)| *job_id == id)
+    }
+
+    pub fn cancel(&mut self, id: u64) -> Result<Job<T>, CommonError> {
+        let key = self.find(id).ok_or(CommonError::UnknownJob(id))?;
+        let job = self
+            .jobs
+            .get(&key)
+            .cloned()
+            .ok_or(CommonError::UnknownJob(id))?;
+        self.jobs.delete(&key);
+        Ok(job)
+    }
+
+    pub fn reschedule(&mut self, id: u64, due: Timestamp) -> Result<(), CommonError> {
+        let job = self.cancel(id)?;
+        self.jobs.set((due, id), job);
+        Ok(())
+    }
+
+    // Jobs due at `now` in due order, without removing them.
+    pub fn peek_due(&self, now: Timestamp, limit: usize) -> Vec<DueJob<T>> {
+        self.jobs
+            .range(..=(now, u64::MAX))
+            .take(limit)
+            .map(|((due, id), job)| DueJob {
+                id: *id,
+                due: *due,
+                payload: job.payload().clone(),
+            })
+            .collect()
+    }
+
+    // Takes up to `limit` due jobs. One-off jobs are removed, recurring jobs move to their first
+    // slot after `now` so a keeper that fell behind runs each job once rather than catching up.
+    pub fn due_jobs(&mut self, now: Timestamp, limit: usize) -> Vec<DueJob<T>> {
+        let due = self.peek_due(now, limit);
+        for job in due.iter() {
+            let key = (job.due, job.id);
+            if let Some(Job::Recurring {
+                payload,
+                interval_seconds,
+            }) = self.jobs.get(&key).cloned()
+            {
+                let next = next_slot(job.due, interval_seconds, now);
+                self.jobs.set(
+                    (next, job.id),
+                    Job::Recurring {
+                        payload,
+                        interval_seconds,
+                    },
+                );
+            }
+            self.jobs.delete(&key);
+        }
+        due
+    }
+}
+
+fn next_slot(due: Timestamp, interval_seconds: u64, now: Timestamp) -> Timestamp {
+    // in nanos, so a sub-second due time keeps its offset instead of skipping a slot
+    let interval = interval_seconds.saturating_mul(1_000_000_000);
+    if interval == 0 {
+        return Timestamp::from_nanos(now.nanos().saturating_add(1));
+    }
+    let elapsed = now.nanos().saturating_sub(due.nanos());
+    let missed = elapsed / interval + 1;
+    Timestamp::from_nanos(due.nanos().saturating_add(missed.saturating_mul(interval)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::CommonError;
+    use crate::scheduler::{Job, Scheduler};
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::Timestamp;
+
+    #[cw_serde]
+    enum Task {
+        Harvest,
+        SettleFunding,
+    }
+
+    #[test]
+    fn test_due_jobs() {
+        let mut scheduler = Scheduler::new();
+        let settle =
+            scheduler.schedule(Timestamp::from_seconds(20), Job::Once(Task::SettleFunding));
+        let harvest = scheduler.schedule(
+            Timestamp::from_seconds(10),
+            Job::Recurring {
+                payload: Task::Harvest,
+                interval_seconds: 100,
+            },
+        );
+        assert_eq!(scheduler.next_due(), Some(Timestamp::from_seconds(10)));
+        assert!(scheduler
+            .due_jobs(Timestamp::from_seconds(5), 10)
+            .is_empty());
+
+        let due = scheduler.due_jobs(Timestamp::from_seconds(20), 1);
+        assert_eq!(due.len(), 1);
+        assert_eq!(
+            (due[0].id, due[0].payload.clone()),
+            (harvest, Task::Harvest)
+        );
+
+        let due = scheduler.due_jobs(Timestamp::from_seconds(20), 10);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].id, settle);
+        assert_eq!(scheduler.len(), 1);
+        assert_eq!(scheduler.next_due(), Some(Timestamp::from_seconds(110)));
+
+        // a keeper that missed several intervals runs the job once
+        let due = scheduler.due_jobs(Timestamp::from_seconds(350), 10);
+        assert_eq!(due.len(), 1);
+        assert_eq!(scheduler.next_due(), Some(Timestamp::from_seconds(410)));
+    }
+
+    #[test]
+    fn test_sub_second_due_times() {
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(
+            Timestamp::from_nanos(100_700_000_000),
+            Job::Recurring {
+                payload: Task::Harvest,
+                interval_seconds: 10,
+            },
+        );
+        let due = scheduler.due_jobs(Timestamp::from_nanos(110_300_000_000), 10);
+        assert_eq!(due.len(), 1);
+        assert_eq!(
+            scheduler.next_due(),
+            Some(Timestamp::from_nanos(110_700_000_000))
+        );
+
+        let due = scheduler.due_jobs(Timestamp::from_nanos(110_700_000_000), 10);
+        assert_eq!(due.len(), 1);
+        assert_eq!(
+            scheduler.next_due(),
+            Some(Timestamp::from_nanos(120_700_000_000))
+        );
+    }
+
+    #[test]
+    fn test_reschedule_and_cancel() {
+        let mut scheduler = Scheduler::new();
+        let id = scheduler.schedule(Timestamp::from_seconds(10), Job::Once(Task::Harvest));
+        scheduler
+            .reschedule(id, Timestamp::from_seconds(50))
+            .unwrap();
+        assert!(scheduler
+            .peek_due(Timestamp::from_seconds(10), 10)
+            .is_empty());
+        assert_eq!(
+            scheduler.peek_due(Timestamp::from_seconds(50), 10)[0].id,
+            id
+        );
+
+        assert_eq!(scheduler.cancel(id).unwrap(), Job::Once(Task::Harvest));
+        assert_eq!(scheduler.cancel(id), Err(CommonError::UnknownJob(id)));
+        assert_eq!(
+            scheduler.reschedule(7, Timestamp::from_seconds(1)),
+            Err(CommonError::UnknownJob(7))
+        );
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(Timestamp::from_seconds(10), Job::Once(Task::Harvest));
+        let json = cosmwasm_std::to_json_vec(&scheduler).unwrap();
+        assert_eq!(
+            cosmwasm_std::from_json::<Scheduler<Task>>(&json).unwrap(),
+            scheduler
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::repr::numeric_string_schema;
+use crate::signed_bigdecimal::SignedBigDecimal;
+use crate::signed_biginteger::SignedBigInteger;
+use core::fmt::{Display, Formatter};
+use cosmwasm_schema::serde::{Deserialize, Serialize};
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+
+// Query response wrappers that serialize exactly like the wrapped number, but carry their own
+// schema name and an `x-ts-brand` extension so frontend codegen can emit a branded string type
+// (e.g. `string & { __brand: "BigDecimalString" }`) instead of a plain `string`.
+macro_rules! branded_string {
+    ($name:ident, $inner:ty, $pattern:expr, $description:expr, $examples:expr) => {
+        #[derive(
+            Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Default,
+        )]
+        #[serde(crate = "cosmwasm_schema::serde", transparent)]
+        pub struct $name(pub $inner);
+
+        impl From<$inner> for $name {
+            fn from(value: $inner) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for $inner {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl JsonSchema for $name {
+            fn schema_name() -> String {
+                stringify!($name).to_string()
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                let mut schema = numeric_string_schema($pattern, $description, $examples);
+                if let Schema::Object(object) = &mut schema {
+                    object
+                        .extensions
+                        .insert("x-ts-brand".to_string(), stringify!($name).into());
+                }
+                schema
+            }
+        }
+    };
+}
+
+branded_string!(
+    BigIntegerString,
+    BigInteger,
+    "^[0-9]+$",
+    "An unsigned 256-bit integer encoded as a decimal string",
+    &["1000000"]
+);
+branded_string!(
+    BigDecimalString,
+    BigDecimal,
+    r"^[0-9]+(\.[0-9]{1,18})?$",
+    "A fixed-point decimal with 18 fractional digits encoded as a string",
+    &["1.5"]
+);
+branded_string!(
+    SignedBigIntegerString,
+    SignedBigInteger,
+    "^-?[0-9]+$",
+    "A signed 256-bit integer encoded as a decimal string",
+    &["-1000000"]
+);
+branded_string!(
+    SignedBigDecimalString,
+    SignedBigDecimal,
+    r"^-?[0-9]+(\.[0-9]{1,18})?$",
+    "A signed fixed-point decimal with 18 fractional digits encoded as a string",
+    &["-1.5"]
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::schema_extras::{BigDecimalString, SignedBigIntegerString};
+    use crate::signed_biginteger::SignedBigInteger;
+    use cosmwasm_std::{from_json, to_json_string};
+
+    #[test]
+    fn test_serializes_like_inner() {
+        let value = BigDecimalString::from(BigDecimal::percent(150));
+        assert_eq!(to_json_string(&value).unwrap(), "\"1.5\"");
+        assert_eq!(from_json::<BigDecimalString>("\"1.5\"").unwrap(), value);
+
+        let value = SignedBigIntegerString::from(SignedBigInteger::new(-7));
+        assert_eq!(to_json_string(&value).unwrap(), "\"-7\"");
+        assert_eq!(value.to_string(), "-7");
+    }
+
+    #[test]
+    fn test_schema_brand() {
+        let schema = serde_json::to_value(schemars::schema_for!(BigDecimalString)).unwrap();
+        assert_eq!(schema["title"], "BigDecimalString");
+        assert_eq!(schema["type"], "string");
+        assert_eq!(schema["x-ts-brand"], "BigDecimalString");
+    }
+}
@@ -1,6 +1,10 @@
 use core::fmt::Display;
+use core::str::FromStr;
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::StdError;
+#[cfg(feature = "cw20")]
 use cosmwasm_std::{Api, StdResult};
+#[cfg(feature = "cw20")]
 use cw20::Denom;
 
 #[cw_serde]
@@ -22,6 +26,21 @@ impl Display for SerializableDenom {
     }
 }
 
+impl FromStr for SerializableDenom {
+    type Err = StdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(denom) = s.strip_prefix("native_") {
+            Ok(SerializableDenom::Native(denom.to_string()))
+        } else if let Some(contract_address) = s.strip_prefix("cw20_") {
+            Ok(SerializableDenom::Cw20(contract_address.to_string()))
+        } else {
+            Err(StdError::generic_err(format!("Invalid denom: {}", s)))
+        }
+    }
+}
+
+#[cfg(feature = "cw20")]
 impl From<Denom> for SerializableDenom {
     fn from(value: Denom) -> Self {
         match value {
@@ -31,6 +50,7 @@ impl From<Denom> for SerializableDenom {
     }
 }
 
+#[cfg(feature = "cw20")]
 impl SerializableDenom {
     pub fn to_denom(&self, api: &dyn Api) -> StdResult<Denom> {
         Ok(match self {
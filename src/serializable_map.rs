@@ -1,23 +1,26 @@
-use alloc::collections::btree_map::Iter;
+use alloc::collections::btree_map::{Iter, Range};
+use alloc::collections::BTreeMap;
 use core::fmt::Formatter;
-use cosmwasm_schema::serde::de::{SeqAccess, Visitor};
-use cosmwasm_schema::serde::ser::SerializeSeq;
+use core::marker::PhantomData;
+use core::ops::RangeBounds;
+use cosmwasm_schema::serde::de::{DeserializeSeed, Error, SeqAccess, Visitor};
+use cosmwasm_schema::serde::ser::{SerializeSeq, SerializeTuple};
 use cosmwasm_schema::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
 use schemars::JsonSchema;
-use std::collections::BTreeMap;
-use std::marker::PhantomData;
 
-#[derive(Clone, Debug, PartialEq, Default, JsonSchema)]
-pub struct SerializableMap<K, V>(BTreeMap<K, V>)
-where
-    K: Ord + Serialize,
-    V: Serialize;
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SerializableMap<K, V>(BTreeMap<K, V>);
 
-impl<K, V> SerializableMap<K, V>
-where
-    K: Ord + Serialize,
-    V: Serialize,
-{
+// Not derived, which would require `K: Default` and `V: Default`.
+impl<K, V> Default for SerializableMap<K, V> {
+    fn default() -> Self {
+        Self(BTreeMap::new())
+    }
+}
+
+impl<K: Ord, V> SerializableMap<K, V> {
     pub fn new() -> SerializableMap<K, V> {
         Self(BTreeMap::new())
     }
@@ -38,6 +41,11 @@ where
         self.0.remove(key).is_some()
     }
 
+    // Removes the entry and returns its value.
+    pub fn take(&mut self, key: &K) -> Option<V> {
+        self.0.remove(key)
+    }
+
     pub fn get(&self, key: &K) -> Option<&V> {
         self.0.get(key)
     }
@@ -50,9 +58,101 @@ where
         self.0.iter()
     }
 
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K, V> {
+        self.0.range(range)
+    }
+
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.0.first_key_value()
+    }
+
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.0.last_key_value()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    // Borrowed entries for `keys`, missing keys skipped, e.g. to answer a query for a few entries
+    // of a large map.
+    pub fn view<'a>(&'a self, keys: &[K]) -> MapView<'a, K, V> {
+        MapView(
+            keys.iter()
+                .filter_map(|key| self.0.get_key_value(key))
+                .collect(),
+        )
+    }
+
+    // Owned copies of the entries for `keys`; only those entries are cloned.
+    pub fn clone_subset(&self, keys: &[K]) -> SerializableMap<K, V>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        self.view(keys).to_map()
+    }
+}
+
+// A key-ordered subset of a `SerializableMap` by reference. It serializes exactly like an owned
+// map with the same entries.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MapView<'a, K, V>(BTreeMap<&'a K, &'a V>);
+
+impl<'a, K: Ord, V> MapView<'a, K, V> {
+    pub fn get(&self, key: &K) -> Option<&'a V> {
+        self.0.get(key).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'a K, &'a V)> + '_ {
+        self.0.iter().map(|(k, v)| (*k, *v))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn to_map(&self) -> SerializableMap<K, V>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        SerializableMap(self.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+    }
+}
+
+impl<K, V> Serialize for MapView<'_, K, V>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_seq(Some(self.0.len()))?;
+        for (k, v) in self.0.iter() {
+            s.serialize_element(&Entry(*k, *v))?;
+        }
+        s.end()
+    }
+}
+
+impl<K, V> IntoIterator for SerializableMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = alloc::collections::btree_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
 }
 
 impl<K, V> Serialize for SerializableMap<K, V>
@@ -66,12 +166,31 @@ where
     {
         let mut s = serializer.serialize_seq(Some(self.0.len()))?;
         for (k, v) in self.0.iter() {
-            s.serialize_element(&(k, v))?;
+            s.serialize_element(&Entry(k, v))?;
         }
         s.end()
     }
 }
 
+// Serializes like the tuple `(k, v)` without building it.
+struct Entry<'a, K, V>(&'a K, &'a V);
+
+impl<K, V> Serialize for Entry<'_, K, V>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_tuple(2)?;
+        s.serialize_element(self.0)?;
+        s.serialize_element(self.1)?;
+        s.end()
+    }
+}
+
 impl<'d, K, V> Deserialize<'d> for SerializableMap<K, V>
 where
     K: Ord + Serialize + Deserialize<'d>,
@@ -85,11 +204,43 @@ where
     }
 }
 
+// Deserializes a map with at most `max_entries` entries, e.g. for untrusted message input:
+// `SerializableMapSeed::new(100).deserialize(&mut serde_json::Deserializer::from_slice(data))`.
+pub struct SerializableMapSeed<K, V> {
+    pub max_entries: usize,
+    phantom_data: PhantomData<(K, V)>,
+}
+
+impl<K, V> SerializableMapSeed<K, V> {
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<'d, K, V> DeserializeSeed<'d> for SerializableMapSeed<K, V>
+where
+    K: Ord + Serialize + Deserialize<'d>,
+    V: Serialize + Deserialize<'d>,
+{
+    type Value = SerializableMap<K, V>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'d>,
+    {
+        deserializer.deserialize_seq(SerializableMapVisitor::with_max_entries(self.max_entries))
+    }
+}
+
 struct SerializableMapVisitor<K, V>
 where
     K: Ord + Serialize,
     V: Serialize,
 {
+    max_entries: usize,
     phantom_data: PhantomData<(K, V)>,
 }
 
@@ -99,7 +250,12 @@ where
     V: Serialize,
 {
     pub fn new() -> SerializableMapVisitor<K, V> {
+        Self::with_max_entries(usize::MAX)
+    }
+
+    pub fn with_max_entries(max_entries: usize) -> SerializableMapVisitor<K, V> {
         SerializableMapVisitor {
+            max_entries,
             phantom_data: PhantomData,
         }
     }
@@ -113,17 +269,186 @@ where
     type Value = SerializableMap<K, V>;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
-        formatter.write_str("struct SerializableMap")
+        if self.max_entries == usize::MAX {
+            formatter.write_str("struct SerializableMap")
+        } else {
+            write!(
+                formatter,
+                "struct SerializableMap with at most {} entries",
+                self.max_entries
+            )
+        }
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
+        // formats that know the length up front are rejected before anything is parsed
+        if let Some(len) = seq.size_hint() {
+            if len > self.max_entries {
+                return Err(A::Error::invalid_length(len, &self));
+            }
+        }
         let mut map: SerializableMap<K, V> = SerializableMap::new();
+        let mut count = 0usize;
         while let Some(element) = seq.next_element::<(K, V)>()? {
+            count += 1;
+            if count > self.max_entries {
+                return Err(A::Error::invalid_length(count, &self));
+            }
             map.set(element.0, element.1);
         }
         Ok(map)
     }
 }
+
+impl<K, V> JsonSchema for SerializableMap<K, V>
+where
+    K: Ord + Serialize + JsonSchema,
+    V: Serialize + JsonSchema,
+{
+    fn schema_name() -> String {
+        format!(
+            "SerializableMap_for_{}_and_{}",
+            K::schema_name(),
+            V::schema_name()
+        )
+    }
+
+    // the wire format is a key-ordered sequence of `[key, value]` pairs, not a JSON object
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        let mut schema = Vec::<(K, V)>::json_schema(gen);
+        if let Schema::Object(object) = &mut schema {
+            object.metadata().description =
+                Some("Map encoded as an array of [key, value] pairs sorted by key".to_string());
+        }
+        schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::serializable_map::{SerializableMap, SerializableMapSeed};
+    use cosmwasm_schema::serde::de::DeserializeSeed;
+
+    #[test]
+    fn test_serde_format() {
+        let map = SerializableMap::from(vec![(2u64, "b".to_string()), (1u64, "a".to_string())]);
+        let json = cosmwasm_std::to_json_string(&map).unwrap();
+        assert_eq!(json, r#"[[1,"a"],[2,"b"]]"#);
+        assert_eq!(
+            cosmwasm_std::from_json::<SerializableMap<u64, String>>(&json).unwrap(),
+            map
+        );
+    }
+
+    #[test]
+    fn test_view() {
+        let map = SerializableMap::from((0u64..100).map(|i| (i, i.to_string())).collect());
+        let view = map.view(&[42, 7, 1000, 7]);
+        assert_eq!(view.len(), 2);
+        assert_eq!(view.get(&7), Some(&"7".to_string()));
+        assert_eq!(view.get(&8), None);
+        assert_eq!(
+            view.iter().map(|(k, _)| *k).collect::<Vec<_>>(),
+            vec![7, 42]
+        );
+        assert_eq!(
+            cosmwasm_std::to_json_string(&view).unwrap(),
+            r#"[[7,"7"],[42,"42"]]"#
+        );
+
+        let subset = map.clone_subset(&[42, 7, 1000]);
+        assert_eq!(
+            subset,
+            SerializableMap::from(vec![(7, "7".to_string()), (42, "42".to_string())])
+        );
+        assert_eq!(
+            cosmwasm_std::to_json_string(&subset).unwrap(),
+            cosmwasm_std::to_json_string(&view).unwrap()
+        );
+        assert!(map.view(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_max_entries() {
+        let json = br#"[[1,"a"],[2,"b"],[3,"c"]]"#;
+        let seed = SerializableMapSeed::<u64, String>::new(3);
+        let map = seed
+            .deserialize(&mut serde_json::Deserializer::from_slice(json))
+            .unwrap();
+        assert_eq!(map.len(), 3);
+
+        let seed = SerializableMapSeed::<u64, String>::new(2);
+        let err = seed
+            .deserialize(&mut serde_json::Deserializer::from_slice(json))
+            .unwrap_err();
+        assert!(err.to_string().contains("at most 2 entries"));
+    }
+
+    #[test]
+    fn test_ordering() {
+        use core::hash::{Hash, Hasher};
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::BTreeSet;
+
+        let a = SerializableMap::from(vec![(1u64, 10u64), (2, 20)]);
+        let b = SerializableMap::from(vec![(2u64, 20u64), (1, 10)]);
+        let c = SerializableMap::from(vec![(1u64, 11u64)]);
+        assert_eq!(a, b);
+        assert!(a < c);
+        assert!(SerializableMap::<u64, u64>::default() < a);
+
+        let hash = |map: &SerializableMap<u64, u64>| {
+            let mut hasher = DefaultHasher::new();
+            map.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&a), hash(&b));
+
+        let set: BTreeSet<SerializableMap<u64, u64>> = [a, b, c].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_values_without_serialize() {
+        struct Opaque(u8);
+        let map = SerializableMap::from(vec![("a", Opaque(1)), ("b", Opaque(2))]);
+        assert_eq!(map.get(&"b").map(|v| v.0), Some(2));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = schemars::schema_for!(SerializableMap<String, u64>);
+        let json = serde_json::to_value(&schema).unwrap();
+        assert_eq!(json["type"], "array");
+        assert_eq!(json["items"]["type"], "array");
+        assert_eq!(json["items"]["minItems"], 2);
+        assert_eq!(json["items"]["maxItems"], 2);
+        assert_eq!(json["items"]["items"][0]["type"], "string");
+        assert_eq!(json["items"]["items"][1]["type"], "integer");
+        assert!(json["description"]
+            .as_str()
+            .unwrap()
+            .contains("[key, value] pairs"));
+    }
+
+    #[test]
+    fn test_schema_as_field() {
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct Config {
+            weights: SerializableMap<String, u64>,
+        }
+        let schema = schemars::schema_for!(Config);
+        let json = serde_json::to_value(&schema).unwrap();
+        assert_eq!(
+            json["properties"]["weights"]["$ref"],
+            "#/definitions/SerializableMap_for_String_and_uint64"
+        );
+        let definition = &json["definitions"]["SerializableMap_for_String_and_uint64"];
+        assert_eq!(definition["type"], "array");
+    }
+}
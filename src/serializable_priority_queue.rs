@@ -0,0 +1,210 @@
+use crate::error::CommonError;
+use cosmwasm_schema::serde::de::Error;
+use cosmwasm_schema::serde::{Deserialize, Deserializer, Serialize, Serializer};
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+// Min-heap kept in memory as a binary heap and persisted as an ascending Vec, so a single state
+// item can hold e.g. a liquidation queue ordered by health.
+#[derive(Clone, Debug)]
+pub struct SerializablePriorityQueue<T>
+where
+    T: Ord,
+{
+    heap: BinaryHeap<Reverse<T>>,
+    capacity: Option<usize>,
+}
+
+impl<T> Default for SerializablePriorityQueue<T>
+where
+    T: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SerializablePriorityQueue<T>
+where
+    T: Ord,
+{
+    pub fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            capacity: None,
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            heap: BinaryHeap::with_capacity(capacity),
+            capacity: Some(capacity),
+        }
+    }
+
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    pub fn push(&mut self, item: T) -> Result<(), CommonError> {
+        if let Some(max) = self.capacity {
+            if self.heap.len() >= max {
+                return Err(CommonError::CapacityExceeded { max });
+            }
+        }
+        self.heap.push(Reverse(item));
+        Ok(())
+    }
+
+    // removes the smallest item
+    pub fn pop(&mut self) -> Option<T> {
+        self.heap.pop().map(|item| item.0)
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.heap.peek().map(|item| &item.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn sorted(&self) -> Vec<&T> {
+        let mut items: Vec<&T> = self.heap.iter().map(|item| &item.0).collect();
+        items.sort();
+        items
+    }
+}
+
+impl<T> PartialEq for SerializablePriorityQueue<T>
+where
+    T: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.capacity == other.capacity && self.sorted() == other.sorted()
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+struct PriorityQueueRef<'a, T> {
+    capacity: Option<usize>,
+    items: Vec<&'a T>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(crate = "cosmwasm_schema::serde", deny_unknown_fields)]
+struct PriorityQueueRepr<T> {
+    capacity: Option<usize>,
+    items: Vec<T>,
+}
+
+impl<T> Serialize for SerializablePriorityQueue<T>
+where
+    T: Ord + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        PriorityQueueRef {
+            capacity: self.capacity,
+            items: self.sorted(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'d, T> Deserialize<'d> for SerializablePriorityQueue<T>
+where
+    T: Ord + Deserialize<'d>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'d>,
+    {
+        let repr = PriorityQueueRepr::<T>::deserialize(deserializer)?;
+        if let Some(max) = repr.capacity {
+            if repr.items.len() > max {
+                return Err(D::Error::custom(CommonError::CapacityExceeded { max }));
+            }
+        }
+        Ok(Self {
+            heap: repr.items.into_iter().map(Reverse).collect(),
+            capacity: repr.capacity,
+        })
+    }
+}
+
+impl<T> JsonSchema for SerializablePriorityQueue<T>
+where
+    T: Ord + JsonSchema,
+{
+    fn schema_name() -> String {
+        format!("SerializablePriorityQueue_for_{}", T::schema_name())
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        PriorityQueueRepr::<T>::json_schema(gen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::serializable_priority_queue::SerializablePriorityQueue;
+
+    #[test]
+    fn test_push_pop() {
+        let mut queue = SerializablePriorityQueue::new();
+        for value in [5u64, 1, 4, 2, 3] {
+            queue.push(BigInteger::from(value)).unwrap();
+        }
+        assert_eq!(queue.peek(), Some(&BigInteger::one()));
+        let popped: Vec<BigInteger> = std::iter::from_fn(|| queue.pop()).collect();
+        assert_eq!(
+            popped,
+            (1u64..=5)
+                .map(BigInteger::from)
+                .collect::<Vec<BigInteger>>()
+        );
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_capacity() {
+        let mut queue = SerializablePriorityQueue::with_capacity(2);
+        queue.push((2u64, "b".to_string())).unwrap();
+        queue.push((1u64, "a".to_string())).unwrap();
+        assert_eq!(
+            queue.push((0u64, "c".to_string())),
+            Err(CommonError::CapacityExceeded { max: 2 })
+        );
+        assert_eq!(queue.pop(), Some((1u64, "a".to_string())));
+        queue.push((0u64, "c".to_string())).unwrap();
+        assert_eq!(queue.len(), 2);
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut queue = SerializablePriorityQueue::with_capacity(3);
+        for value in [3u64, 1, 2] {
+            queue.push(value).unwrap();
+        }
+        let json = cosmwasm_std::to_json_string(&queue).unwrap();
+        assert_eq!(json, r#"{"capacity":3,"items":[1,2,3]}"#);
+        let parsed: SerializablePriorityQueue<u64> = cosmwasm_std::from_json(&json).unwrap();
+        assert_eq!(parsed, queue);
+
+        let overfull = r#"{"capacity":1,"items":[1,2]}"#;
+        assert!(cosmwasm_std::from_json::<SerializablePriorityQueue<u64>>(overfull).is_err());
+    }
+}
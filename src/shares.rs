@@ -0,0 +1,204 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::rounding::{mul_div, RoundingMode};
+use cosmwasm_schema::cw_serde;
+
+// Virtual shares and assets are added to both totals so that the first depositor cannot inflate
+// the share price by donating assets. A larger virtual share offset makes that attack costlier.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub struct Shares {
+    pub virtual_shares: BigInteger,
+    pub virtual_assets: BigInteger,
+}
+
+impl Default for Shares {
+    fn default() -> Self {
+        Self {
+            virtual_shares: BigInteger::one(),
+            virtual_assets: BigInteger::one(),
+        }
+    }
+}
+
+impl Shares {
+    pub fn new(virtual_shares: BigInteger, virtual_assets: BigInteger) -> Self {
+        Self {
+            virtual_shares,
+            virtual_assets,
+        }
+    }
+
+    pub fn with_decimals_offset(decimals_offset: u32) -> Result<Self, CommonError> {
+        Ok(Self::new(
+            BigInteger::one().checked_scale_up(decimals_offset)?,
+            BigInteger::one(),
+        ))
+    }
+
+    pub fn to_shares(
+        &self,
+        assets: BigInteger,
+        total_assets: BigInteger,
+        total_shares: BigInteger,
+        rounding: RoundingMode,
+    ) -> Result<BigInteger, CommonError> {
+        mul_div(
+            assets,
+            with_offset(total_shares, self.virtual_shares)?,
+            with_offset(total_assets, self.virtual_assets)?,
+            rounding,
+        )
+    }
+
+    pub fn to_assets(
+        &self,
+        shares: BigInteger,
+        total_assets: BigInteger,
+        total_shares: BigInteger,
+        rounding: RoundingMode,
+    ) -> Result<BigInteger, CommonError> {
+        mul_div(
+            shares,
+            with_offset(total_assets, self.virtual_assets)?,
+            with_offset(total_shares, self.virtual_shares)?,
+            rounding,
+        )
+    }
+
+    // deposit an exact amount of assets, shares are rounded down
+    pub fn shares_to_mint(
+        &self,
+        deposit: BigInteger,
+        total_assets: BigInteger,
+        total_shares: BigInteger,
+    ) -> Result<BigInteger, CommonError> {
+        self.to_shares(deposit, total_assets, total_shares, RoundingMode::Floor)
+    }
+
+    // redeem an exact amount of shares, assets are rounded down
+    pub fn assets_to_redeem(
+        &self,
+        shares: BigInteger,
+        total_assets: BigInteger,
+        total_shares: BigInteger,
+    ) -> Result<BigInteger, CommonError> {
+        self.to_assets(shares, total_assets, total_shares, RoundingMode::Floor)
+    }
+
+    // mint an exact amount of shares, required assets are rounded up
+    pub fn assets_to_deposit(
+        &self,
+        shares: BigInteger,
+        total_assets: BigInteger,
+        total_shares: BigInteger,
+    ) -> Result<BigInteger, CommonError> {
+        self.to_assets(shares, total_assets, total_shares, RoundingMode::Ceil)
+    }
+
+    // withdraw an exact amount of assets, burned shares are rounded up
+    pub fn shares_to_burn(
+        &self,
+        withdraw: BigInteger,
+        total_assets: BigInteger,
+        total_shares: BigInteger,
+    ) -> Result<BigInteger, CommonError> {
+        self.to_shares(withdraw, total_assets, total_shares, RoundingMode::Ceil)
+    }
+}
+
+fn with_offset(total: BigInteger, offset: BigInteger) -> Result<BigInteger, CommonError> {
+    Ok(BigInteger(total.0.checked_add(offset.0)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::rounding::RoundingMode;
+    use crate::shares::Shares;
+
+    #[test]
+    fn test_round_trip() {
+        let shares = Shares::default();
+        let total_assets = BigInteger::from(1_000u64);
+        let total_shares = BigInteger::from(300u64);
+        let deposit = BigInteger::from(100u64);
+
+        let minted = shares
+            .shares_to_mint(deposit, total_assets, total_shares)
+            .unwrap();
+        assert_eq!(minted, BigInteger::from(30u64));
+        let redeemed = shares
+            .assets_to_redeem(minted, total_assets, total_shares)
+            .unwrap();
+        assert!(redeemed <= deposit);
+
+        assert!(
+            shares
+                .shares_to_burn(deposit, total_assets, total_shares)
+                .unwrap()
+                >= minted
+        );
+        assert!(
+            shares
+                .assets_to_deposit(minted, total_assets, total_shares)
+                .unwrap()
+                >= redeemed
+        );
+    }
+
+    #[test]
+    fn test_donation_attack() {
+        let shares = Shares::with_decimals_offset(6).unwrap();
+        let donation = BigInteger::create_with_scale(1, 18);
+
+        // attacker deposits 1 unit into the empty vault and donates directly to it
+        let attacker_shares = shares
+            .shares_to_mint(BigInteger::one(), BigInteger::zero(), BigInteger::zero())
+            .unwrap();
+        let total_assets = BigInteger::one() + donation;
+        let total_shares = attacker_shares;
+
+        let victim_deposit = BigInteger::create_with_scale(2, 18);
+        let victim_shares = shares
+            .shares_to_mint(victim_deposit, total_assets, total_shares)
+            .unwrap();
+        assert!(!victim_shares.is_zero());
+
+        let total_assets = total_assets + victim_deposit;
+        let total_shares = total_shares + victim_shares;
+        let victim_assets = shares
+            .assets_to_redeem(victim_shares, total_assets, total_shares)
+            .unwrap();
+        let attacker_assets = shares
+            .assets_to_redeem(attacker_shares, total_assets, total_shares)
+            .unwrap();
+
+        // the victim loses less than 0.0001% and the attack is unprofitable
+        assert!(victim_deposit - victim_assets < victim_deposit / BigInteger::from(1_000_000u64));
+        assert!(attacker_assets < donation);
+    }
+
+    #[test]
+    fn test_totals_overflow() {
+        let shares = Shares::default();
+        let max = BigInteger::MAX;
+        let one = BigInteger::one();
+        assert!(matches!(
+            shares.to_shares(one, max, one, RoundingMode::Floor),
+            Err(CommonError::Overflow(_))
+        ));
+        assert!(matches!(
+            shares.to_assets(one, one, max, RoundingMode::Floor),
+            Err(CommonError::Overflow(_))
+        ));
+        assert_eq!(
+            Shares::with_decimals_offset(78),
+            Err(CommonError::InvalidDecimals {
+                decimals: 78,
+                max: 77
+            })
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use crate::bigdecimal::BigDecimal;
+use crate::error::CommonError;
+use crate::signed_biginteger::SignedBigInteger;
+use core::fmt::{Display, Formatter};
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal256, SignedDecimal256, StdError};
+
+#[cw_serde]
+#[derive(Copy, Default, Ord, PartialOrd, Eq)]
+pub struct SignedBigDecimal(pub SignedDecimal256);
+
+impl SignedBigDecimal {
+    pub const MAX: Self = Self(SignedDecimal256::MAX);
+    pub const MIN: Self = Self(SignedDecimal256::MIN);
+
+    pub fn new(atomics: SignedBigInteger) -> Self {
+        Self(SignedDecimal256::new(atomics.0))
+    }
+
+    pub fn zero() -> Self {
+        Self(SignedDecimal256::zero())
+    }
+
+    pub fn one() -> Self {
+        Self(SignedDecimal256::one())
+    }
+
+    pub fn percent(x: i64) -> Self {
+        Self(SignedDecimal256::percent(x))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    pub fn abs(&self) -> BigDecimal {
+        BigDecimal(self.0.abs_diff(SignedDecimal256::zero()))
+    }
+
+    pub fn checked_add(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(Self(self.0.checked_add(rhs.0)?))
+    }
+
+    pub fn checked_sub(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(Self(self.0.checked_sub(rhs.0)?))
+    }
+
+    pub fn checked_mul(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(Self(self.0.checked_mul(rhs.0)?))
+    }
+
+    pub fn checked_div(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(Self(self.0.checked_div(rhs.0)?))
+    }
+}
+
+impl TryFrom<BigDecimal> for SignedBigDecimal {
+    type Error = CommonError;
+
+    fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+        Ok(Self(SignedDecimal256::try_from(value.0)?))
+    }
+}
+
+impl TryFrom<SignedBigDecimal> for BigDecimal {
+    type Error = CommonError;
+
+    fn try_from(value: SignedBigDecimal) -> Result<Self, Self::Error> {
+        Ok(Self(Decimal256::new(value.0.atomics().try_into()?)))
+    }
+}
+
+impl Add for SignedBigDecimal {
+    type Output = SignedBigDecimal;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for SignedBigDecimal {
+    type Output = SignedBigDecimal;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul for SignedBigDecimal {
+    type Output = SignedBigDecimal;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl Div for SignedBigDecimal {
+    type Output = SignedBigDecimal;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self(self.0 / rhs.0)
+    }
+}
+
+impl Neg for SignedBigDecimal {
+    type Output = SignedBigDecimal;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl AddAssign for SignedBigDecimal {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for SignedBigDecimal {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for SignedBigDecimal {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for SignedBigDecimal {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+forward_ref_binop!(impl Add, add for SignedBigDecimal, SignedBigDecimal);
+forward_ref_binop!(impl Sub, sub for SignedBigDecimal, SignedBigDecimal);
+forward_ref_binop!(impl Mul, mul for SignedBigDecimal, SignedBigDecimal);
+forward_ref_binop!(impl Div, div for SignedBigDecimal, SignedBigDecimal);
+
+impl Display for SignedBigDecimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for SignedBigDecimal {
+    type Err = StdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(SignedDecimal256::from_str(s)?))
+    }
+}
+
+impl Sum for SignedBigDecimal {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::signed_bigdecimal::SignedBigDecimal;
+    use core::str::FromStr;
+
+    #[test]
+    fn test_signed_ops() {
+        let a = SignedBigDecimal::percent(150);
+        let b = SignedBigDecimal::percent(-50);
+        assert_eq!(a + b, SignedBigDecimal::one());
+        assert_eq!(b - a, SignedBigDecimal::percent(-200));
+        assert_eq!(a * b, SignedBigDecimal::percent(-75));
+        assert_eq!(a / b, SignedBigDecimal::percent(-300));
+        assert_eq!(-b, SignedBigDecimal::percent(50));
+        assert_eq!(b.abs(), BigDecimal::percent(50));
+        assert!(a.checked_div(SignedBigDecimal::zero()).is_err());
+    }
+
+    #[test]
+    fn test_conversion() {
+        let value = SignedBigDecimal::try_from(BigDecimal::percent(25)).unwrap();
+        assert_eq!(value, SignedBigDecimal::percent(25));
+        assert!(SignedBigDecimal::try_from(BigDecimal::MAX).is_err());
+        assert!(BigDecimal::try_from(SignedBigDecimal::percent(-1)).is_err());
+        assert_eq!(
+            SignedBigDecimal::from_str("-1.25").unwrap(),
+            SignedBigDecimal::percent(-125)
+        );
+    }
+}
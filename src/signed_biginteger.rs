@@ -0,0 +1,208 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use core::fmt::{Display, Formatter};
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Int256, StdError};
+
+#[cw_serde]
+#[derive(Copy, Default, Ord, PartialOrd, Eq)]
+pub struct SignedBigInteger(pub Int256);
+
+impl SignedBigInteger {
+    pub const MAX: Self = Self(Int256::MAX);
+    pub const MIN: Self = Self(Int256::MIN);
+
+    pub const fn new(value: i128) -> Self {
+        Self(Int256::from_i128(value))
+    }
+
+    pub fn zero() -> Self {
+        Self(Int256::zero())
+    }
+
+    pub fn one() -> Self {
+        Self(Int256::one())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    pub fn abs(&self) -> BigInteger {
+        BigInteger(self.0.unsigned_abs())
+    }
+
+    pub fn checked_add(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(Self(self.0.checked_add(rhs.0)?))
+    }
+
+    pub fn checked_sub(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(Self(self.0.checked_sub(rhs.0)?))
+    }
+
+    pub fn checked_mul(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(Self(self.0.checked_mul(rhs.0)?))
+    }
+
+    pub fn checked_div(&self, rhs: Self) -> Result<Self, CommonError> {
+        Ok(Self(self.0.checked_div(rhs.0)?))
+    }
+}
+
+impl TryFrom<BigInteger> for SignedBigInteger {
+    type Error = CommonError;
+
+    fn try_from(value: BigInteger) -> Result<Self, Self::Error> {
+        Ok(Self(Int256::try_from(value.0)?))
+    }
+}
+
+impl TryFrom<SignedBigInteger> for BigInteger {
+    type Error = CommonError;
+
+    fn try_from(value: SignedBigInteger) -> Result<Self, Self::Error> {
+        Ok(Self(value.0.try_into()?))
+    }
+}
+
+impl From<i128> for SignedBigInteger {
+    fn from(value: i128) -> Self {
+        Self(Int256::from(value))
+    }
+}
+
+impl From<i64> for SignedBigInteger {
+    fn from(value: i64) -> Self {
+        Self(Int256::from(value))
+    }
+}
+
+impl Add for SignedBigInteger {
+    type Output = SignedBigInteger;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for SignedBigInteger {
+    type Output = SignedBigInteger;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul for SignedBigInteger {
+    type Output = SignedBigInteger;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl Div for SignedBigInteger {
+    type Output = SignedBigInteger;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self(self.0 / rhs.0)
+    }
+}
+
+impl Neg for SignedBigInteger {
+    type Output = SignedBigInteger;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl AddAssign for SignedBigInteger {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for SignedBigInteger {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for SignedBigInteger {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for SignedBigInteger {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+forward_ref_binop!(impl Add, add for SignedBigInteger, SignedBigInteger);
+forward_ref_binop!(impl Sub, sub for SignedBigInteger, SignedBigInteger);
+forward_ref_binop!(impl Mul, mul for SignedBigInteger, SignedBigInteger);
+forward_ref_binop!(impl Div, div for SignedBigInteger, SignedBigInteger);
+
+impl Display for SignedBigInteger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for SignedBigInteger {
+    type Err = StdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(Int256::from_str(s)?))
+    }
+}
+
+impl Sum for SignedBigInteger {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::signed_biginteger::SignedBigInteger;
+    use core::str::FromStr;
+
+    #[test]
+    fn test_signed_ops() {
+        let a = SignedBigInteger::from(5i64);
+        let b = SignedBigInteger::from(-8i64);
+        assert_eq!(a + b, SignedBigInteger::from(-3i64));
+        assert_eq!(a - b, SignedBigInteger::from(13i64));
+        assert_eq!(a * b, SignedBigInteger::from(-40i64));
+        assert_eq!(b / a, SignedBigInteger::from(-1i64));
+        assert_eq!(-b, SignedBigInteger::from(8i64));
+        assert!(b.is_negative());
+        assert_eq!(b.abs(), BigInteger::from(8u64));
+        assert!(SignedBigInteger::MAX.checked_add(a).is_err());
+        assert!(a.checked_div(SignedBigInteger::zero()).is_err());
+    }
+
+    #[test]
+    fn test_conversion() {
+        let value = SignedBigInteger::try_from(BigInteger::from(7u64)).unwrap();
+        assert_eq!(value, SignedBigInteger::new(7));
+        assert!(SignedBigInteger::try_from(BigInteger::MAX).is_err());
+        assert!(BigInteger::try_from(SignedBigInteger::new(-1)).is_err());
+        assert_eq!(
+            SignedBigInteger::from_str("-42").unwrap().to_string(),
+            "-42"
+        );
+    }
+}
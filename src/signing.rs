@@ -0,0 +1,321 @@
+use crate::codec::{bech32_encode, canonical_json_digest};
+use crate::commit_reveal::sha256;
+use crate::error::CommonError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::serde::Serialize;
+use cosmwasm_std::{Addr, Api, Binary, Env};
+
+// Prefix of the signed digest, like EIP-712's `\x19\x01`, so a typed-data signature can never be
+// replayed as a signature over a raw transaction or another encoding.
+const DIGEST_PREFIX: &[u8] = b"\x19\x01";
+
+// Binds a signature to one contract on one chain, and to a version of its message format.
+#[cw_serde]
+pub struct SigningDomain {
+    pub name: String,
+    pub version: String,
+    pub chain_id: String,
+    pub verifying_contract: Addr,
+}
+
+impl SigningDomain {
+    pub fn new(name: impl Into<String>, version: impl Into<String>, env: &Env) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            chain_id: env.block.chain_id.clone(),
+            verifying_contract: env.contract.address.clone(),
+        }
+    }
+}
+
+#[cw_serde]
+pub enum PublicKey {
+    // 33-byte compressed key
+    Secp256k1(Binary),
+    Ed25519(Binary),
+}
+
+impl PublicKey {
+    // Cosmos SDK account address bytes: ripemd160(sha256(key)) for secp256k1 and the first
+    // 20 bytes of sha256(key) for ed25519.
+    pub fn address_bytes(&self) -> Result<[u8; 20], CommonError> {
+        match self {
+            PublicKey::Secp256k1(key) => {
+                if key.len() != 33 {
+                    return Err(CommonError::InvalidPublicKey(format!(
+                        "expected a 33-byte compressed secp256k1 key, got {} bytes",
+                        key.len()
+                    )));
+                }
+                Ok(ripemd160(&sha256(key)))
+            }
+            PublicKey::Ed25519(key) => {
+                if key.len() != 32 {
+                    return Err(CommonError::InvalidPublicKey(format!(
+                        "expected a 32-byte ed25519 key, got {} bytes",
+                        key.len()
+                    )));
+                }
+                let mut address = [0u8; 20];
+                address.copy_from_slice(&sha256(key)[..20]);
+                Ok(address)
+            }
+        }
+    }
+
+    pub fn to_address(&self, api: &dyn Api, prefix: &str) -> Result<Addr, CommonError> {
+        let address = bech32_encode(prefix, &self.address_bytes()?)?;
+        Ok(api.addr_validate(&address)?)
+    }
+}
+
+#[cw_serde]
+pub struct Signature {
+    pub public_key: PublicKey,
+    pub signature: Binary,
+}
+
+// sha256(0x19 0x01 || digest(domain) || digest(message)), digests over canonical JSON.
+pub fn typed_data_digest<T: Serialize>(
+    domain: &SigningDomain,
+    message: &T,
+) -> Result<[u8; 32], CommonError> {
+    let mut preimage = Vec::with_capacity(DIGEST_PREFIX.len() + 64);
+    preimage.extend_from_slice(DIGEST_PREFIX);
+    preimage.extend_from_slice(&canonical_json_digest(domain)?);
+    preimage.extend_from_slice(&canonical_json_digest(message)?);
+    Ok(sha256(&preimage))
+}
+
+// Verifies `signature` over the typed-data digest of `message` and returns the signer's address.
+pub fn verify_typed_data<T: Serialize>(
+    api: &dyn Api,
+    domain: &SigningDomain,
+    message: &T,
+    signature: &Signature,
+    prefix: &str,
+) -> Result<Addr, CommonError> {
+    let digest = typed_data_digest(domain, message)?;
+    let valid = match &signature.public_key {
+        PublicKey::Secp256k1(key) => api.secp256k1_verify(&digest, &signature.signature, key),
+        PublicKey::Ed25519(key) => api.ed25519_verify(&digest, &signature.signature, key),
+    }
+    .map_err(|err| CommonError::InvalidSignature(err.to_string()))?;
+    if !valid {
+        return Err(CommonError::InvalidSignature(
+            "signature does not match the message".to_string(),
+        ));
+    }
+    signature.public_key.to_address(api, prefix)
+}
+
+const RIPEMD_LEFT_WORDS: [usize; 80] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9, 5,
+    2, 14, 11, 8, 3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12, 1, 9, 11, 10, 0, 8, 12, 4,
+    13, 3, 7, 15, 14, 5, 6, 2, 4, 0, 5, 9, 7, 12, 2, 10, 14, 1, 3, 8, 11, 6, 15, 13,
+];
+const RIPEMD_RIGHT_WORDS: [usize; 80] = [
+    5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12, 6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8, 12,
+    4, 9, 1, 2, 15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13, 8, 6, 4, 1, 3, 11, 15, 0, 5,
+    12, 2, 13, 9, 7, 10, 14, 12, 15, 10, 4, 1, 5, 8, 7, 6, 2, 13, 14, 0, 3, 9, 11,
+];
+const RIPEMD_LEFT_SHIFTS: [u32; 80] = [
+    11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8, 7, 6, 8, 13, 11, 9, 7, 15, 7, 12, 15,
+    9, 11, 7, 13, 12, 11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5, 11, 12, 14, 15, 14,
+    15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12, 9, 15, 5, 11, 6, 8, 13, 12, 5, 12, 13, 14, 11, 8, 5, 6,
+];
+const RIPEMD_RIGHT_SHIFTS: [u32; 80] = [
+    8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6, 9, 13, 15, 7, 12, 8, 9, 11, 7, 7, 12,
+    7, 6, 15, 13, 11, 9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5, 15, 5, 8, 11, 14, 14,
+    6, 14, 6, 9, 12, 9, 12, 5, 15, 8, 8, 5, 12, 9, 12, 5, 14, 6, 8, 13, 6, 5, 15, 13, 11, 11,
+];
+const RIPEMD_LEFT_CONSTANTS: [u32; 5] =
+    [0x00000000, 0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xa953fd4e];
+const RIPEMD_RIGHT_CONSTANTS: [u32; 5] =
+    [0x50a28be6, 0x5c4dd124, 0x6d703ef3, 0x7a6d76e9, 0x00000000];
+
+fn ripemd_f(round: usize, x: u32, y: u32, z: u32) -> u32 {
+    match round {
+        0 => x ^ y ^ z,
+        1 => (x & y) | (!x & z),
+        2 => (x | !y) ^ z,
+        3 => (x & z) | (y & !z),
+        _ => x ^ (y | !z),
+    }
+}
+
+// Only used to derive secp256k1 account addresses, so it is kept here instead of pulling in
+// another hashing dependency.
+fn ripemd160(data: &[u8]) -> [u8; 20] {
+    let mut h: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64).wrapping_mul(8)).to_le_bytes());
+
+    for block in message.chunks_exact(64) {
+        let mut x = [0u32; 16];
+        for (i, word) in block.chunks_exact(4).enumerate() {
+            x[i] = u32::from_le_bytes([word[0], word[1], word[2], word[3]]);
+        }
+
+        let [mut al, mut bl, mut cl, mut dl, mut el] = h;
+        let [mut ar, mut br, mut cr, mut dr, mut er] = h;
+        for j in 0..80 {
+            let round = j / 16;
+            let t = al
+                .wrapping_add(ripemd_f(round, bl, cl, dl))
+                .wrapping_add(x[RIPEMD_LEFT_WORDS[j]])
+                .wrapping_add(RIPEMD_LEFT_CONSTANTS[round])
+                .rotate_left(RIPEMD_LEFT_SHIFTS[j])
+                .wrapping_add(el);
+            al = el;
+            el = dl;
+            dl = cl.rotate_left(10);
+            cl = bl;
+            bl = t;
+
+            let t = ar
+                .wrapping_add(ripemd_f(4 - round, br, cr, dr))
+                .wrapping_add(x[RIPEMD_RIGHT_WORDS[j]])
+                .wrapping_add(RIPEMD_RIGHT_CONSTANTS[round])
+                .rotate_left(RIPEMD_RIGHT_SHIFTS[j])
+                .wrapping_add(er);
+            ar = er;
+            er = dr;
+            dr = cr.rotate_left(10);
+            cr = br;
+            br = t;
+        }
+
+        let t = h[1].wrapping_add(cl).wrapping_add(dr);
+        h[1] = h[2].wrapping_add(dl).wrapping_add(er);
+        h[2] = h[3].wrapping_add(el).wrapping_add(ar);
+        h[3] = h[4].wrapping_add(al).wrapping_add(br);
+        h[4] = h[0].wrapping_add(bl).wrapping_add(cr);
+        h[0] = t;
+    }
+
+    let mut out = [0u8; 20];
+    for (i, word) in h.iter().enumerate() {
+        out[i * 4..i * 4 + 4].copy_from_slice(&word.to_le_bytes());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::CommonError;
+    use crate::signing::{
+        ripemd160, typed_data_digest, verify_typed_data, PublicKey, Signature, SigningDomain,
+    };
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::testing::{mock_env, MockApi};
+    use cosmwasm_std::{Binary, HexBinary};
+    use k256::ecdsa::signature::hazmat::PrehashSigner;
+
+    #[cw_serde]
+    struct Transfer {
+        recipient: String,
+        amount: u64,
+    }
+
+    fn transfer() -> Transfer {
+        Transfer {
+            recipient: "bob".to_string(),
+            amount: 100,
+        }
+    }
+
+    #[test]
+    fn test_ripemd160() {
+        assert_eq!(
+            HexBinary::from(ripemd160(b"")).to_hex(),
+            "9c1185a5c5e9fc54612808977ee8f548b2258d31"
+        );
+        assert_eq!(
+            HexBinary::from(ripemd160(b"abc")).to_hex(),
+            "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"
+        );
+        assert_eq!(
+            HexBinary::from(ripemd160(
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            ))
+            .to_hex(),
+            "12a053384a9c0c88e405a06c27dcf49ada62eb2b"
+        );
+    }
+
+    #[test]
+    fn test_domain_separation() {
+        let env = mock_env();
+        let domain = SigningDomain::new("permit", "1", &env);
+        let mut other = domain.clone();
+        other.chain_id = "other-chain".to_string();
+        assert_ne!(
+            typed_data_digest(&domain, &transfer()).unwrap(),
+            typed_data_digest(&other, &transfer()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_secp256k1() {
+        let api = MockApi::default().with_prefix("cosmos");
+        let domain = SigningDomain::new("permit", "1", &mock_env());
+        let digest = typed_data_digest(&domain, &transfer()).unwrap();
+
+        let key = k256::ecdsa::SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let signature: k256::ecdsa::Signature = key.sign_prehash(&digest).unwrap();
+        let public_key = key.verifying_key().to_encoded_point(true);
+        let signature = Signature {
+            public_key: PublicKey::Secp256k1(Binary::from(public_key.as_bytes())),
+            signature: Binary::from(signature.to_bytes().as_slice()),
+        };
+
+        let signer = verify_typed_data(&api, &domain, &transfer(), &signature, "cosmos").unwrap();
+        assert_eq!(
+            signer,
+            signature.public_key.to_address(&api, "cosmos").unwrap()
+        );
+        assert!(signer.as_str().starts_with("cosmos1"));
+
+        let mut tampered = transfer();
+        tampered.amount = 101;
+        assert!(matches!(
+            verify_typed_data(&api, &domain, &tampered, &signature, "cosmos"),
+            Err(CommonError::InvalidSignature(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_ed25519() {
+        let api = MockApi::default().with_prefix("cosmos");
+        let domain = SigningDomain::new("permit", "1", &mock_env());
+        let digest = typed_data_digest(&domain, &transfer()).unwrap();
+
+        let key = ed25519_zebra::SigningKey::from([9u8; 32]);
+        let signature = Signature {
+            public_key: PublicKey::Ed25519(Binary::from(
+                ed25519_zebra::VerificationKeyBytes::from(&key).as_ref(),
+            )),
+            signature: Binary::from(&<[u8; 64]>::from(key.sign(&digest))),
+        };
+        assert!(verify_typed_data(&api, &domain, &transfer(), &signature, "cosmos").is_ok());
+
+        let other = SigningDomain::new("permit", "2", &mock_env());
+        assert!(verify_typed_data(&api, &other, &transfer(), &signature, "cosmos").is_err());
+    }
+
+    #[test]
+    fn test_invalid_public_key() {
+        let key = PublicKey::Secp256k1(Binary::from(vec![2u8; 32]));
+        assert!(matches!(
+            key.address_bytes(),
+            Err(CommonError::InvalidPublicKey(_))
+        ));
+    }
+}
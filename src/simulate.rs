@@ -0,0 +1,229 @@
+// Off-chain runners replaying the contracts' math over CSV paths, for tuning parameters.
+
+use crate::amm::lp_vs_hold;
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::debt::DebtPool;
+use crate::error::CommonError;
+use crate::growth_index::Accrual;
+use crate::number::Number;
+use core::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PathPoint {
+    pub timestamp: u64,
+    pub value: BigDecimal,
+}
+
+// `timestamp,value` rows in increasing timestamp order. Blank lines and a leading header row are
+// skipped.
+pub fn parse_path(csv: &str) -> Result<Vec<PathPoint>, CommonError> {
+    let mut points: Vec<PathPoint> = vec![];
+    for (i, line) in csv.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || (points.is_empty() && line.starts_with("timestamp")) {
+            continue;
+        }
+        let invalid = |reason: String| CommonError::InvalidCsv {
+            line: i + 1,
+            reason,
+        };
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        if fields.len() != 2 {
+            return Err(invalid(format!("expected 2 fields, got {}", fields.len())));
+        }
+        let timestamp = u64::from_str(fields[0]).map_err(|err| invalid(err.to_string()))?;
+        let value = BigDecimal::from_str(fields[1]).map_err(|err| invalid(err.to_string()))?;
+        if let Some(last) = points.last() {
+            if timestamp <= last.timestamp {
+                return Err(CommonError::NotSorted(timestamp.to_string()));
+            }
+        }
+        points.push(PathPoint { timestamp, value });
+    }
+    Ok(points)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Table {
+    pub header: Vec<&'static str>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn to_csv(&self) -> String {
+        let mut csv = self.header.join(",");
+        csv.push('\n');
+        for row in self.rows.iter() {
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+fn elapsed(from: &PathPoint, to: &PathPoint) -> Result<u64, CommonError> {
+    to.timestamp
+        .checked_sub(from.timestamp)
+        .ok_or_else(|| CommonError::NotSorted(to.timestamp.to_string()))
+}
+
+// A balanced LP position opened at the first price of `prices` and valued at every point.
+// Fee income accrues linearly at `fee_return_per_second` of the position value. The points must be
+// in increasing timestamp order, as `parse_path` returns them.
+pub fn simulate_lp(
+    prices: &[PathPoint],
+    deposit_value: BigDecimal,
+    fee_return_per_second: BigDecimal,
+) -> Result<Table, CommonError> {
+    let mut table = Table {
+        header: vec!["timestamp", "price", "lp_value", "hold_value", "relative"],
+        rows: vec![],
+    };
+    let Some(entry) = prices.first() else {
+        return Ok(table);
+    };
+    let mut previous = entry;
+    for point in prices {
+        elapsed(previous, point)?;
+        let elapsed = BigDecimal::checked_from(BigInteger::from(elapsed(entry, point)?), 0)?;
+        let fee_return = fee_return_per_second.checked_mul(elapsed)?;
+        let result = lp_vs_hold(deposit_value, entry.value, point.value, fee_return)?;
+        table.rows.push(vec![
+            point.timestamp.to_string(),
+            point.value.to_string(),
+            result.lp_value.to_string(),
+            result.hold_value.to_string(),
+            result.relative.to_string(),
+        ]);
+        previous = point;
+    }
+    Ok(table)
+}
+
+// Debt of `principal` borrowed at the first point, where each row's per-second rate applies until
+// the next row. The points must be in increasing timestamp order.
+pub fn simulate_interest(
+    rates: &[PathPoint],
+    accrual: Accrual,
+    principal: BigInteger,
+) -> Result<Table, CommonError> {
+    let mut table = Table {
+        header: vec!["timestamp", "rate", "index", "owed"],
+        rows: vec![],
+    };
+    let mut pool = DebtPool::new(accrual);
+    pool.borrow(principal)?;
+    let mut previous: Option<&PathPoint> = None;
+    for point in rates {
+        if let Some(previous) = previous {
+            pool.accrue(previous.value, elapsed(previous, point)?)?;
+        }
+        table.rows.push(vec![
+            point.timestamp.to_string(),
+            point.value.to_string(),
+            pool.index.index.to_string(),
+            pool.total_owed()?.to_string(),
+        ]);
+        previous = Some(point);
+    }
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::growth_index::Accrual;
+    use crate::simulate::{parse_path, simulate_interest, simulate_lp, PathPoint};
+
+    #[test]
+    fn test_parse_path() {
+        let path = parse_path("timestamp,value\n0,1.5\n\n10, 2\n").unwrap();
+        assert_eq!(path.len(), 2);
+        assert_eq!(path[1].timestamp, 10);
+        assert_eq!(path[1].value, BigDecimal::percent(200));
+
+        assert_eq!(
+            parse_path("0,1\n0,2"),
+            Err(CommonError::NotSorted("0".to_string()))
+        );
+        assert!(matches!(
+            parse_path("0,1\n5"),
+            Err(CommonError::InvalidCsv { line: 2, .. })
+        ));
+        assert!(matches!(
+            parse_path("x,1"),
+            Err(CommonError::InvalidCsv { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_simulate_lp() {
+        let path = parse_path("0,1\n100,4\n").unwrap();
+        let table = simulate_lp(
+            &path,
+            BigDecimal::from(BigInteger::from(100u64), 0),
+            BigDecimal::zero(),
+        )
+        .unwrap();
+        assert_eq!(
+            table.to_csv(),
+            "timestamp,price,lp_value,hold_value,relative\n\
+             0,1,100,100,0\n\
+             100,4,200,250,-0.2\n"
+        );
+
+        let unsorted = vec![path[1].clone(), path[0].clone()];
+        assert_eq!(
+            simulate_lp(&unsorted, BigDecimal::one(), BigDecimal::zero()),
+            Err(CommonError::NotSorted("0".to_string()))
+        );
+
+        // every point is after the entry, but not after the one before it
+        let unsorted = vec![
+            PathPoint {
+                timestamp: 0,
+                value: BigDecimal::one(),
+            },
+            PathPoint {
+                timestamp: 100,
+                value: BigDecimal::one(),
+            },
+            PathPoint {
+                timestamp: 50,
+                value: BigDecimal::one(),
+            },
+        ];
+        assert_eq!(
+            simulate_lp(&unsorted, BigDecimal::one(), BigDecimal::zero()),
+            Err(CommonError::NotSorted("50".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_simulate_interest() {
+        let path = parse_path("0,0.1\n2,0\n10,0").unwrap();
+        let table = simulate_interest(&path, Accrual::Compound, BigInteger::from(100u64)).unwrap();
+        assert_eq!(
+            table.to_csv(),
+            "timestamp,rate,index,owed\n0,0.1,1,100\n2,0,1.21,121\n10,0,1.21,121\n"
+        );
+
+        let unsorted = vec![
+            PathPoint {
+                timestamp: 5,
+                value: BigDecimal::zero(),
+            },
+            PathPoint {
+                timestamp: 1,
+                value: BigDecimal::zero(),
+            },
+        ];
+        assert_eq!(
+            simulate_interest(&unsorted, Accrual::Compound, BigInteger::one()),
+            Err(CommonError::NotSorted("1".to_string()))
+        );
+    }
+}
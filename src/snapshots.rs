@@ -0,0 +1,94 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::serializable_map::SerializableMap;
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+#[derive(Default)]
+pub struct Snapshots {
+    pub checkpoints: SerializableMap<u64, BigInteger>,
+}
+
+impl Snapshots {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Checkpoints are append-only, recording at the latest height again overwrites it.
+    pub fn record(&mut self, height: u64, value: BigInteger) -> Result<(), CommonError> {
+        if let Some((last, _)) = self.checkpoints.last() {
+            if height < *last {
+                return Err(CommonError::InvalidCheckpoint {
+                    last: *last,
+                    height,
+                });
+            }
+        }
+        self.checkpoints.set(height, value);
+        Ok(())
+    }
+
+    pub fn value_at(&self, height: u64) -> Option<BigInteger> {
+        self.checkpoints
+            .range(..=height)
+            .next_back()
+            .map(|(_, value)| *value)
+    }
+
+    pub fn latest(&self) -> Option<BigInteger> {
+        self.checkpoints.last().map(|(_, value)| *value)
+    }
+
+    // Drops checkpoints that can no longer answer queries at or after `height`.
+    pub fn prune_before(&mut self, height: u64) {
+        let keep = self
+            .checkpoints
+            .range(..=height)
+            .next_back()
+            .map(|(h, _)| *h);
+        let stale: Vec<u64> = self
+            .checkpoints
+            .range(..height)
+            .map(|(h, _)| *h)
+            .filter(|h| Some(*h) != keep)
+            .collect();
+        for h in stale {
+            self.checkpoints.delete(&h);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::snapshots::Snapshots;
+
+    #[test]
+    fn test_value_at() {
+        let mut snapshots = Snapshots::new();
+        assert_eq!(snapshots.value_at(10), None);
+
+        snapshots.record(10, BigInteger::from(100u64)).unwrap();
+        snapshots.record(20, BigInteger::from(200u64)).unwrap();
+        snapshots.record(20, BigInteger::from(250u64)).unwrap();
+        assert!(snapshots.record(15, BigInteger::from(1u64)).is_err());
+
+        assert_eq!(snapshots.value_at(9), None);
+        assert_eq!(snapshots.value_at(10), Some(BigInteger::from(100u64)));
+        assert_eq!(snapshots.value_at(19), Some(BigInteger::from(100u64)));
+        assert_eq!(snapshots.value_at(30), Some(BigInteger::from(250u64)));
+        assert_eq!(snapshots.latest(), Some(BigInteger::from(250u64)));
+    }
+
+    #[test]
+    fn test_prune() {
+        let mut snapshots = Snapshots::new();
+        for height in [10u64, 20, 30] {
+            snapshots.record(height, BigInteger::from(height)).unwrap();
+        }
+        snapshots.prune_before(25);
+        assert_eq!(snapshots.checkpoints.len(), 2);
+        assert_eq!(snapshots.value_at(25), Some(BigInteger::from(20u64)));
+        assert_eq!(snapshots.value_at(15), None);
+    }
+}
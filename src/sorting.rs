@@ -0,0 +1,67 @@
+use crate::serializable_map::SerializableMap;
+
+// Largest value first. The sort is stable, so equal values keep their relative order.
+pub fn sort_descending_by_value<K, V: Ord>(items: &mut [(K, V)]) {
+    items.sort_by(|a, b| b.1.cmp(&a.1));
+}
+
+// References to the `n` entries with the largest values, largest first and ties in slice order.
+pub fn top_n<K, V: Ord>(items: &[(K, V)], n: usize) -> Vec<&(K, V)> {
+    let mut refs: Vec<&(K, V)> = items.iter().collect();
+    refs.sort_by(|a, b| b.1.cmp(&a.1));
+    refs.truncate(n);
+    refs
+}
+
+// Same as `top_n` over a map, ties in key order.
+pub fn top_n_map<K: Ord, V: Ord>(map: &SerializableMap<K, V>, n: usize) -> Vec<(&K, &V)> {
+    let mut refs: Vec<(&K, &V)> = map.iter().collect();
+    refs.sort_by(|a, b| b.1.cmp(a.1));
+    refs.truncate(n);
+    refs
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::serializable_map::SerializableMap;
+    use crate::sorting::{sort_descending_by_value, top_n, top_n_map};
+
+    fn stakes() -> Vec<(&'static str, BigInteger)> {
+        vec![
+            ("val-a", BigInteger::from(300u64)),
+            ("val-b", BigInteger::from(500u64)),
+            ("val-c", BigInteger::from(300u64)),
+            ("val-d", BigInteger::from(100u64)),
+        ]
+    }
+
+    #[test]
+    fn test_sort_descending_by_value() {
+        let mut stakes = stakes();
+        sort_descending_by_value(&mut stakes);
+        let names: Vec<&str> = stakes.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["val-b", "val-a", "val-c", "val-d"]);
+    }
+
+    #[test]
+    fn test_top_n() {
+        let stakes = stakes();
+        let top = top_n(&stakes, 2);
+        assert_eq!(top, vec![&stakes[1], &stakes[0]]);
+        assert_eq!(top_n(&stakes, 10).len(), 4);
+        assert!(top_n(&stakes, 0).is_empty());
+
+        let map = SerializableMap::from(vec![
+            ("b".to_string(), BigDecimal::percent(10)),
+            ("c".to_string(), BigDecimal::percent(50)),
+            ("a".to_string(), BigDecimal::percent(10)),
+        ]);
+        let top: Vec<&str> = top_n_map(&map, 2)
+            .into_iter()
+            .map(|(key, _)| key.as_str())
+            .collect();
+        assert_eq!(top, vec!["c", "a"]);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::rounding::{mul_div, RoundingMode};
+use cosmwasm_std::{Addr, Decimal256};
+
+// Splits `amount` by weight. Every recipient gets its floored share and the units lost to flooring
+// are handed out one by one in input order, so the parts always add up to `amount`.
+pub fn splits(
+    amount: BigInteger,
+    recipients: &[(Addr, BigDecimal)],
+) -> Result<Vec<(Addr, BigInteger)>, CommonError> {
+    let total = BigDecimal::try_sum(recipients.iter().map(|(_, weight)| *weight))?;
+    if total != BigDecimal::one() {
+        return Err(CommonError::InvalidRatio(total.to_string()));
+    }
+
+    let one = BigInteger(Decimal256::one().atomics());
+    let mut parts = recipients
+        .iter()
+        .map(|(addr, weight)| {
+            let part = mul_div(
+                amount,
+                BigInteger(weight.0.atomics()),
+                one,
+                RoundingMode::Floor,
+            )?;
+            Ok((addr.clone(), part))
+        })
+        .collect::<Result<Vec<(Addr, BigInteger)>, CommonError>>()?;
+
+    // at most one unit per recipient is lost, since the weights add up to one
+    let mut remainder = amount - parts.iter().map(|(_, part)| part).sum::<BigInteger>();
+    for ((_, part), (_, weight)) in parts.iter_mut().zip(recipients.iter()) {
+        if remainder.is_zero() {
+            break;
+        }
+        if !weight.is_zero() {
+            *part += BigInteger::one();
+            remainder -= BigInteger::one();
+        }
+    }
+    Ok(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::splits::splits;
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn test_splits() {
+        // 1/3 is rounded down to 18 decimals, so the last weight takes the rest
+        let third = BigDecimal::from_ratio(1u64, 3u64);
+        let recipients = vec![
+            (Addr::unchecked("a"), third),
+            (Addr::unchecked("b"), BigDecimal::zero()),
+            (Addr::unchecked("c"), BigDecimal::one() - third),
+        ];
+        let parts = splits(BigInteger::from(100u64), &recipients).unwrap();
+        assert_eq!(
+            parts,
+            vec![
+                (Addr::unchecked("a"), BigInteger::from(34u64)),
+                (Addr::unchecked("b"), BigInteger::zero()),
+                (Addr::unchecked("c"), BigInteger::from(66u64)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sum_is_exact() {
+        let recipients: Vec<(Addr, BigDecimal)> = (0..7)
+            .map(|i| {
+                (
+                    Addr::unchecked(format!("r{}", i)),
+                    BigDecimal::from_ratio(1u64, 7u64),
+                )
+            })
+            .collect();
+        let mut recipients = recipients;
+        let assigned: BigDecimal = recipients[1..].iter().map(|(_, w)| *w).sum();
+        recipients[0].1 = BigDecimal::one() - assigned;
+        for amount in [0u64, 1, 6, 7, 1_000_003] {
+            let parts = splits(BigInteger::from(amount), &recipients).unwrap();
+            let total: BigInteger = parts.iter().map(|(_, part)| part).sum();
+            assert_eq!(total, BigInteger::from(amount));
+        }
+    }
+
+    #[test]
+    fn test_invalid_weights() {
+        let recipients = vec![(Addr::unchecked("a"), BigDecimal::percent(90))];
+        assert_eq!(
+            splits(BigInteger::one(), &recipients),
+            Err(CommonError::InvalidRatio("0.9".to_string()))
+        );
+        assert!(splits(BigInteger::one(), &[]).is_err());
+    }
+}
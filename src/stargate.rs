@@ -0,0 +1,189 @@
+use crate::asset::Asset;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::serializable_denom::SerializableDenom;
+use cosmwasm_std::{AnyMsg, Binary, CosmosMsg};
+
+// `CosmosMsg::Stargate` is deprecated since CosmWasm 2.0, `CosmosMsg::Any` is its replacement with
+// identical encoding.
+pub fn any_msg(type_url: impl Into<String>, value: Vec<u8>) -> CosmosMsg {
+    CosmosMsg::Any(AnyMsg {
+        type_url: type_url.into(),
+        value: Binary::new(value),
+    })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenFactory {
+    pub package: String,
+}
+
+impl TokenFactory {
+    pub fn new(package: impl Into<String>) -> Self {
+        Self {
+            package: package.into(),
+        }
+    }
+
+    pub fn osmosis() -> Self {
+        Self::new("osmosis.tokenfactory.v1beta1")
+    }
+
+    pub fn full_denom(creator: &str, subdenom: &str) -> String {
+        format!("factory/{}/{}", creator, subdenom)
+    }
+
+    pub fn create_denom(&self, sender: &str, subdenom: &str) -> CosmosMsg {
+        let mut value = vec![];
+        encode_string(&mut value, 1, sender);
+        encode_string(&mut value, 2, subdenom);
+        any_msg(format!("/{}.MsgCreateDenom", self.package), value)
+    }
+
+    pub fn mint(
+        &self,
+        sender: &str,
+        denom: &str,
+        amount: BigInteger,
+        mint_to_address: &str,
+    ) -> CosmosMsg {
+        let mut value = vec![];
+        encode_string(&mut value, 1, sender);
+        encode_bytes(&mut value, 2, &encode_coin(denom, amount));
+        encode_string(&mut value, 3, mint_to_address);
+        any_msg(format!("/{}.MsgMint", self.package), value)
+    }
+
+    pub fn burn(
+        &self,
+        sender: &str,
+        denom: &str,
+        amount: BigInteger,
+        burn_from_address: &str,
+    ) -> CosmosMsg {
+        let mut value = vec![];
+        encode_string(&mut value, 1, sender);
+        encode_bytes(&mut value, 2, &encode_coin(denom, amount));
+        encode_string(&mut value, 3, burn_from_address);
+        any_msg(format!("/{}.MsgBurn", self.package), value)
+    }
+}
+
+pub fn bank_send(
+    from_address: &str,
+    to_address: &str,
+    amount: &[Asset],
+) -> Result<CosmosMsg, CommonError> {
+    let mut value = vec![];
+    encode_string(&mut value, 1, from_address);
+    encode_string(&mut value, 2, to_address);
+    for asset in amount {
+        match &asset.denom {
+            SerializableDenom::Native(denom) => {
+                encode_bytes(&mut value, 3, &encode_coin(denom, asset.amount))
+            }
+            SerializableDenom::Cw20(_) => {
+                return Err(CommonError::UnsupportedDenom(asset.denom.to_string()))
+            }
+        }
+    }
+    Ok(any_msg("/cosmos.bank.v1beta1.MsgSend", value))
+}
+
+pub fn authz_exec(grantee: &str, msgs: &[AnyMsg]) -> CosmosMsg {
+    let mut value = vec![];
+    encode_string(&mut value, 1, grantee);
+    for msg in msgs {
+        let mut any = vec![];
+        encode_string(&mut any, 1, &msg.type_url);
+        encode_bytes(&mut any, 2, msg.value.as_slice());
+        encode_bytes(&mut value, 2, &any);
+    }
+    any_msg("/cosmos.authz.v1beta1.MsgExec", value)
+}
+
+fn encode_coin(denom: &str, amount: BigInteger) -> Vec<u8> {
+    let mut coin = vec![];
+    encode_string(&mut coin, 1, denom);
+    encode_string(&mut coin, 2, &amount.to_string());
+    coin
+}
+
+fn encode_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn encode_bytes(buf: &mut Vec<u8>, field_number: u32, data: &[u8]) {
+    // proto3 omits empty length-delimited fields
+    if data.is_empty() {
+        return;
+    }
+    encode_varint(buf, ((field_number as u64) << 3) | 2);
+    encode_varint(buf, data.len() as u64);
+    buf.extend_from_slice(data);
+}
+
+fn encode_string(buf: &mut Vec<u8>, field_number: u32, data: &str) {
+    encode_bytes(buf, field_number, data.as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::asset::Asset;
+    use crate::biginteger::BigInteger;
+    use crate::stargate::{authz_exec, bank_send, encode_varint, TokenFactory};
+    use cosmwasm_std::{AnyMsg, CosmosMsg};
+
+    fn unwrap_any(msg: CosmosMsg) -> AnyMsg {
+        match msg {
+            CosmosMsg::Any(msg) => msg,
+            _ => panic!("expected an any message"),
+        }
+    }
+
+    #[test]
+    fn test_varint() {
+        let mut buf = vec![];
+        encode_varint(&mut buf, 300);
+        assert_eq!(buf, vec![0xac, 0x02]);
+    }
+
+    #[test]
+    fn test_token_factory() {
+        let tf = TokenFactory::osmosis();
+        let msg = unwrap_any(tf.create_denom("ab", "lp"));
+        assert_eq!(msg.type_url, "/osmosis.tokenfactory.v1beta1.MsgCreateDenom");
+        assert_eq!(
+            msg.value.to_vec(),
+            vec![0x0a, 2, b'a', b'b', 0x12, 2, b'l', b'p']
+        );
+
+        let msg = unwrap_any(tf.mint("ab", "u", BigInteger::from(5u64), "cd"));
+        assert_eq!(msg.type_url, "/osmosis.tokenfactory.v1beta1.MsgMint");
+        assert_eq!(
+            msg.value.to_vec(),
+            vec![0x0a, 2, b'a', b'b', 0x12, 6, 0x0a, 1, b'u', 0x12, 1, b'5', 0x1a, 2, b'c', b'd']
+        );
+        assert_eq!(TokenFactory::full_denom("ab", "lp"), "factory/ab/lp");
+    }
+
+    #[test]
+    fn test_bank_send_and_authz() {
+        let msg = unwrap_any(bank_send("a", "b", &[Asset::native("u", 1u64)]).unwrap());
+        assert_eq!(msg.type_url, "/cosmos.bank.v1beta1.MsgSend");
+        assert_eq!(
+            msg.value.to_vec(),
+            vec![0x0a, 1, b'a', 0x12, 1, b'b', 0x1a, 6, 0x0a, 1, b'u', 0x12, 1, b'1']
+        );
+        assert!(bank_send("a", "b", &[Asset::cw20("token", 1u64)]).is_err());
+
+        let exec = unwrap_any(authz_exec("g", &[msg]));
+        assert_eq!(exec.type_url, "/cosmos.authz.v1beta1.MsgExec");
+        assert_eq!(exec.value.as_slice()[..3], [0x0a, 1, b'g']);
+        assert_eq!(exec.value.as_slice()[3], 0x12);
+    }
+}
@@ -0,0 +1,201 @@
+use crate::bigdecimal::BigDecimal;
+use crate::error::CommonError;
+use crate::number::Number;
+use crate::rounding::RoundingMode;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Timestamp;
+
+// Sorts `values` and returns the middle one, or for an even count the mean of the two middle
+// values rounded down.
+pub fn median_bigdecimal(values: &mut [BigDecimal]) -> Result<Option<BigDecimal>, CommonError> {
+    if values.is_empty() {
+        return Ok(None);
+    }
+    values.sort();
+    let mid = values.len() / 2;
+    if values.len() % 2 == 1 {
+        return Ok(Some(values[mid]));
+    }
+    let sum = values[mid - 1].checked_add(values[mid])?;
+    Ok(Some(sum.checked_div(BigDecimal::percent(200))?))
+}
+
+// Nearest-rank quantile: sorts `values` and returns the smallest value with at least `q` of the
+// values at or below it. It is always one of the inputs, never an interpolation.
+pub fn quantile(
+    values: &mut [BigDecimal],
+    q: BigDecimal,
+) -> Result<Option<BigDecimal>, CommonError> {
+    if !q.is_ratio() {
+        return Err(CommonError::InvalidRatio(q.to_string()));
+    }
+    if values.is_empty() {
+        return Ok(None);
+    }
+    values.sort();
+    let n = BigDecimal::from_ratio(values.len() as u64, 1u64);
+    let rank = q.checked_mul(n)?.to_integer_checked(RoundingMode::Ceil)?;
+    let index = rank.to_u64_saturating().saturating_sub(1) as usize;
+    Ok(Some(values[index.min(values.len() - 1)]))
+}
+
+#[cw_serde]
+pub struct OraclePrice {
+    pub source: String,
+    pub price: BigDecimal,
+    pub updated_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct AggregatedPrice {
+    pub price: BigDecimal,
+    pub sources: Vec<String>,
+    // sources further than `max_deviation` from the median of all sources
+    pub rejected: Vec<String>,
+}
+
+// Median of the sources that lie within `max_deviation` (a fraction, 0.02 for 2%) of the median
+// of all sources. Fails unless at least `min_sources` distinct sources survive.
+pub fn aggregate_prices(
+    prices: Vec<OraclePrice>,
+    min_sources: usize,
+    max_deviation: BigDecimal,
+) -> Result<AggregatedPrice, CommonError> {
+    let mut names: Vec<&str> = prices.iter().map(|p| p.source.as_str()).collect();
+    names.sort();
+    if let Some(pair) = names.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Err(CommonError::Duplicate(pair[0].to_string()));
+    }
+
+    let mut values: Vec<BigDecimal> = prices.iter().map(|p| p.price).collect();
+    let reference = median_bigdecimal(&mut values)?.unwrap_or_default();
+    let band = reference.checked_mul(max_deviation)?;
+
+    let mut sources = vec![];
+    let mut rejected = vec![];
+    let mut accepted = vec![];
+    for price in prices {
+        let distance = price.price.max(reference) - price.price.min(reference);
+        if distance > band {
+            rejected.push(price.source);
+        } else {
+            accepted.push(price.price);
+            sources.push(price.source);
+        }
+    }
+    let required = min_sources.max(1);
+    if sources.len() < required {
+        return Err(CommonError::InsufficientSources {
+            required,
+            actual: sources.len(),
+        });
+    }
+    let price = median_bigdecimal(&mut accepted)?.unwrap_or_default();
+    Ok(AggregatedPrice {
+        price,
+        sources,
+        rejected,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::error::CommonError;
+    use crate::stats::{aggregate_prices, median_bigdecimal, quantile, OraclePrice};
+    use core::str::FromStr;
+    use cosmwasm_std::Timestamp;
+
+    fn decimals(values: &[&str]) -> Vec<BigDecimal> {
+        values
+            .iter()
+            .map(|v| BigDecimal::from_str(v).unwrap())
+            .collect()
+    }
+
+    fn source(name: &str, price: &str) -> OraclePrice {
+        OraclePrice {
+            source: name.to_string(),
+            price: BigDecimal::from_str(price).unwrap(),
+            updated_at: Timestamp::from_seconds(0),
+        }
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median_bigdecimal(&mut []).unwrap(), None);
+        let mut odd = decimals(&["3", "1", "2"]);
+        assert_eq!(
+            median_bigdecimal(&mut odd).unwrap(),
+            Some(BigDecimal::percent(200))
+        );
+        assert_eq!(odd, decimals(&["1", "2", "3"]));
+        let mut even = decimals(&["4", "1", "2", "3"]);
+        assert_eq!(
+            median_bigdecimal(&mut even).unwrap(),
+            Some(BigDecimal::percent(250))
+        );
+        let mut tiny = decimals(&["0", "0.000000000000000001"]);
+        assert_eq!(
+            median_bigdecimal(&mut tiny).unwrap(),
+            Some(BigDecimal::zero())
+        );
+    }
+
+    #[test]
+    fn test_quantile() {
+        let mut values = decimals(&["5", "1", "4", "2", "3"]);
+        let q = |values: &mut [BigDecimal], percent: u64| {
+            quantile(values, BigDecimal::percent(percent))
+                .unwrap()
+                .unwrap()
+        };
+        assert_eq!(q(&mut values, 0), BigDecimal::one());
+        assert_eq!(q(&mut values, 20), BigDecimal::one());
+        assert_eq!(q(&mut values, 21), BigDecimal::percent(200));
+        assert_eq!(q(&mut values, 50), BigDecimal::percent(300));
+        assert_eq!(q(&mut values, 100), BigDecimal::percent(500));
+        assert!(matches!(
+            quantile(&mut values, BigDecimal::percent(101)),
+            Err(CommonError::InvalidRatio(_))
+        ));
+        assert_eq!(quantile(&mut [], BigDecimal::percent(50)).unwrap(), None);
+    }
+
+    #[test]
+    fn test_aggregate_prices() {
+        let prices = vec![
+            source("a", "100"),
+            source("b", "101"),
+            source("c", "99"),
+            source("d", "150"),
+        ];
+        let aggregated = aggregate_prices(prices.clone(), 3, BigDecimal::percent(5)).unwrap();
+        assert_eq!(aggregated.price, BigDecimal::percent(10000));
+        assert_eq!(aggregated.sources, vec!["a", "b", "c"]);
+        assert_eq!(aggregated.rejected, vec!["d"]);
+
+        assert_eq!(
+            aggregate_prices(prices, 4, BigDecimal::percent(5)),
+            Err(CommonError::InsufficientSources {
+                required: 4,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            aggregate_prices(vec![], 0, BigDecimal::percent(5)),
+            Err(CommonError::InsufficientSources {
+                required: 1,
+                actual: 0
+            })
+        );
+        assert_eq!(
+            aggregate_prices(
+                vec![source("a", "1"), source("a", "2")],
+                1,
+                BigDecimal::one()
+            ),
+            Err(CommonError::Duplicate("a".to_string()))
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod delta;
+#[cfg(feature = "storage")]
+pub mod queue;
+#[cfg(feature = "storage")]
+pub mod sharded;
@@ -0,0 +1,149 @@
+use crate::error::CommonError;
+use crate::serializable_map::SerializableMap;
+use core::marker::PhantomData;
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_schema::serde::Serialize;
+use cosmwasm_std::{from_json, to_json_vec, Storage};
+
+// A large map persisted under `namespace` whose updates are written to a small separate delta
+// (`None` marking a delete) instead of reserializing every entry. `flush` folds the delta into
+// the base map, e.g. once it has grown or at the end of an epoch.
+pub struct DeltaMap<K, V> {
+    namespace: &'static [u8],
+    phantom_data: PhantomData<(K, V)>,
+}
+
+impl<K, V> DeltaMap<K, V>
+where
+    K: Ord + Serialize + DeserializeOwned,
+    V: Serialize + DeserializeOwned,
+{
+    pub const fn new(namespace: &'static str) -> Self {
+        Self {
+            namespace: namespace.as_bytes(),
+            phantom_data: PhantomData,
+        }
+    }
+
+    fn key(&self, suffix: &[u8]) -> Vec<u8> {
+        [self.namespace, b":", suffix].concat()
+    }
+
+    fn load_part<T: DeserializeOwned + Default>(
+        &self,
+        storage: &dyn Storage,
+        name: &[u8],
+    ) -> Result<T, CommonError> {
+        match storage.get(&self.key(name)) {
+            Some(bytes) => Ok(from_json(bytes)?),
+            None => Ok(T::default()),
+        }
+    }
+
+    fn load_delta(
+        &self,
+        storage: &dyn Storage,
+    ) -> Result<SerializableMap<K, Option<V>>, CommonError> {
+        self.load_part(storage, b"delta")
+    }
+
+    fn save_delta(
+        &self,
+        storage: &mut dyn Storage,
+        delta: &SerializableMap<K, Option<V>>,
+    ) -> Result<(), CommonError> {
+        storage.set(&self.key(b"delta"), &to_json_vec(delta)?);
+        Ok(())
+    }
+
+    pub fn set(&self, storage: &mut dyn Storage, key: K, value: V) -> Result<(), CommonError> {
+        let mut delta = self.load_delta(storage)?;
+        delta.set(key, Some(value));
+        self.save_delta(storage, &delta)
+    }
+
+    pub fn delete(&self, storage: &mut dyn Storage, key: K) -> Result<(), CommonError> {
+        let mut delta = self.load_delta(storage)?;
+        delta.set(key, None);
+        self.save_delta(storage, &delta)
+    }
+
+    // Only loads the base map when the key has no pending update.
+    pub fn get(&self, storage: &dyn Storage, key: &K) -> Result<Option<V>, CommonError> {
+        let mut delta = self.load_delta(storage)?;
+        if delta.get(key).is_some() {
+            return Ok(delta.take(key).flatten());
+        }
+        let mut base: SerializableMap<K, V> = self.load_part(storage, b"base")?;
+        Ok(base.take(key))
+    }
+
+    // Number of pending updates not yet folded into the base map.
+    pub fn pending(&self, storage: &dyn Storage) -> Result<usize, CommonError> {
+        Ok(self.load_delta(storage)?.len())
+    }
+
+    // The base map with the pending updates applied.
+    pub fn load(&self, storage: &dyn Storage) -> Result<SerializableMap<K, V>, CommonError> {
+        let mut base: SerializableMap<K, V> = self.load_part(storage, b"base")?;
+        for (key, value) in self.load_delta(storage)? {
+            match value {
+                Some(value) => base.set(key, value),
+                None => {
+                    base.delete(&key);
+                }
+            }
+        }
+        Ok(base)
+    }
+
+    pub fn flush(&self, storage: &mut dyn Storage) -> Result<(), CommonError> {
+        let map = self.load(storage)?;
+        storage.set(&self.key(b"base"), &to_json_vec(&map)?);
+        storage.remove(&self.key(b"delta"));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::serializable_map::SerializableMap;
+    use crate::storage::delta::DeltaMap;
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::Storage;
+
+    #[test]
+    fn test_delta_map() {
+        const WEIGHTS: DeltaMap<String, u64> = DeltaMap::new("weights");
+        let mut storage = MockStorage::default();
+        assert_eq!(WEIGHTS.get(&storage, &"a".to_string()).unwrap(), None);
+
+        for (key, value) in [("a", 1u64), ("b", 2), ("c", 3)] {
+            WEIGHTS.set(&mut storage, key.to_string(), value).unwrap();
+        }
+        WEIGHTS.flush(&mut storage).unwrap();
+        assert_eq!(WEIGHTS.pending(&storage).unwrap(), 0);
+
+        WEIGHTS.set(&mut storage, "b".to_string(), 20).unwrap();
+        WEIGHTS.delete(&mut storage, "c".to_string()).unwrap();
+        WEIGHTS.set(&mut storage, "d".to_string(), 4).unwrap();
+        assert_eq!(WEIGHTS.pending(&storage).unwrap(), 3);
+        // only the delta is rewritten until the next flush
+        let delta = storage.get(b"weights:delta").unwrap();
+        assert_eq!(delta, br#"[["b",20],["c",null],["d",4]]"#.to_vec());
+
+        assert_eq!(WEIGHTS.get(&storage, &"a".to_string()).unwrap(), Some(1));
+        assert_eq!(WEIGHTS.get(&storage, &"b".to_string()).unwrap(), Some(20));
+        assert_eq!(WEIGHTS.get(&storage, &"c".to_string()).unwrap(), None);
+
+        let expected = SerializableMap::from(vec![
+            ("a".to_string(), 1u64),
+            ("b".to_string(), 20),
+            ("d".to_string(), 4),
+        ]);
+        assert_eq!(WEIGHTS.load(&storage).unwrap(), expected);
+        WEIGHTS.flush(&mut storage).unwrap();
+        assert_eq!(WEIGHTS.pending(&storage).unwrap(), 0);
+        assert_eq!(WEIGHTS.load(&storage).unwrap(), expected);
+    }
+}
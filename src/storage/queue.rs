@@ -0,0 +1,167 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_schema::serde::Serialize;
+use cosmwasm_std::{Order, Storage, Uint256};
+use cw_storage_plus::{Bound, Item, Map};
+
+#[cw_serde]
+#[derive(Copy, Default)]
+struct Bounds {
+    head: BigInteger,
+    tail: BigInteger,
+}
+
+// FIFO queue with its head and tail indices under `bounds_namespace` and its items under
+// `items_namespace`. Items live at absolute indices in [head, tail), keyed by their big-endian
+// bytes so they range in order, and an index handed out by `peek` stays valid as a cursor while
+// earlier items are popped.
+pub struct Queue<T> {
+    bounds: Item<Bounds>,
+    items: Map<[u8; 32], T>,
+}
+
+impl<T> Queue<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    pub const fn new(bounds_namespace: &'static str, items_namespace: &'static str) -> Self {
+        Self {
+            bounds: Item::new(bounds_namespace),
+            items: Map::new(items_namespace),
+        }
+    }
+
+    fn load_bounds(&self, storage: &dyn Storage) -> Result<Bounds, CommonError> {
+        Ok(self.bounds.may_load(storage)?.unwrap_or_default())
+    }
+
+    pub fn head(&self, storage: &dyn Storage) -> Result<BigInteger, CommonError> {
+        Ok(self.load_bounds(storage)?.head)
+    }
+
+    pub fn tail(&self, storage: &dyn Storage) -> Result<BigInteger, CommonError> {
+        Ok(self.load_bounds(storage)?.tail)
+    }
+
+    pub fn len(&self, storage: &dyn Storage) -> Result<BigInteger, CommonError> {
+        let bounds = self.load_bounds(storage)?;
+        Ok(bounds.tail - bounds.head)
+    }
+
+    pub fn is_empty(&self, storage: &dyn Storage) -> Result<bool, CommonError> {
+        Ok(self.len(storage)?.is_zero())
+    }
+
+    pub fn push_back(&self, storage: &mut dyn Storage, item: &T) -> Result<(), CommonError> {
+        let mut bounds = self.load_bounds(storage)?;
+        self.items
+            .save(storage, bounds.tail.0.to_be_bytes(), item)?;
+        bounds.tail = bounds.tail.checked_add(BigInteger::one())?;
+        Ok(self.bounds.save(storage, &bounds)?)
+    }
+
+    pub fn front(&self, storage: &dyn Storage) -> Result<Option<T>, CommonError> {
+        let bounds = self.load_bounds(storage)?;
+        if bounds.head == bounds.tail {
+            return Ok(None);
+        }
+        self.get(storage, bounds.head)
+    }
+
+    pub fn pop_front(&self, storage: &mut dyn Storage) -> Result<Option<T>, CommonError> {
+        let mut bounds = self.load_bounds(storage)?;
+        if bounds.head == bounds.tail {
+            return Ok(None);
+        }
+        let item = self.get(storage, bounds.head)?;
+        self.items.remove(storage, bounds.head.0.to_be_bytes());
+        bounds.head = bounds.head.checked_add(BigInteger::one())?;
+        self.bounds.save(storage, &bounds)?;
+        Ok(item)
+    }
+
+    pub fn get(&self, storage: &dyn Storage, index: BigInteger) -> Result<Option<T>, CommonError> {
+        Ok(self.items.may_load(storage, index.0.to_be_bytes())?)
+    }
+
+    // Up to `limit` items from the front, or after the absolute index `start_after`.
+    pub fn peek(
+        &self,
+        storage: &dyn Storage,
+        start_after: Option<BigInteger>,
+        limit: usize,
+    ) -> Result<Vec<(BigInteger, T)>, CommonError> {
+        let min = start_after.map(|after| Bound::exclusive(after.0.to_be_bytes()));
+        self.items
+            .range(storage, min, None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (key, value) = item?;
+                Ok((BigInteger(Uint256::from_be_bytes(key)), value))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::storage::queue::Queue;
+    use cosmwasm_std::testing::MockStorage;
+
+    const UNBONDINGS: Queue<u64> = Queue::new("unbondings_bounds", "unbondings");
+
+    #[test]
+    fn test_push_pop() {
+        let mut storage = MockStorage::default();
+        assert!(UNBONDINGS.is_empty(&storage).unwrap());
+        assert_eq!(UNBONDINGS.pop_front(&mut storage).unwrap(), None);
+
+        for value in [10u64, 20, 30] {
+            UNBONDINGS.push_back(&mut storage, &value).unwrap();
+        }
+        assert_eq!(UNBONDINGS.len(&storage).unwrap(), BigInteger::from(3u64));
+        assert_eq!(UNBONDINGS.front(&storage).unwrap(), Some(10));
+        assert_eq!(UNBONDINGS.pop_front(&mut storage).unwrap(), Some(10));
+        assert_eq!(UNBONDINGS.pop_front(&mut storage).unwrap(), Some(20));
+        UNBONDINGS.push_back(&mut storage, &40).unwrap();
+        assert_eq!(UNBONDINGS.len(&storage).unwrap(), BigInteger::from(2u64));
+        assert_eq!(UNBONDINGS.head(&storage).unwrap(), BigInteger::from(2u64));
+        assert_eq!(UNBONDINGS.pop_front(&mut storage).unwrap(), Some(30));
+        assert_eq!(UNBONDINGS.pop_front(&mut storage).unwrap(), Some(40));
+        assert!(UNBONDINGS.is_empty(&storage).unwrap());
+    }
+
+    #[test]
+    fn test_peek_pagination() {
+        let mut storage = MockStorage::default();
+        for value in 0u64..5 {
+            UNBONDINGS.push_back(&mut storage, &value).unwrap();
+        }
+        UNBONDINGS.pop_front(&mut storage).unwrap();
+
+        let page = UNBONDINGS.peek(&storage, None, 2).unwrap();
+        assert_eq!(
+            page,
+            vec![(BigInteger::from(1u64), 1), (BigInteger::from(2u64), 2)]
+        );
+        let cursor = page.last().map(|(index, _)| *index);
+        let page = UNBONDINGS.peek(&storage, cursor, 10).unwrap();
+        assert_eq!(
+            page.into_iter().map(|(_, v)| v).collect::<Vec<_>>(),
+            vec![3, 4]
+        );
+        assert_eq!(
+            UNBONDINGS
+                .peek(&storage, Some(BigInteger::from(0u64)), 1)
+                .unwrap(),
+            vec![(BigInteger::from(1u64), 1)]
+        );
+        assert!(UNBONDINGS
+            .peek(&storage, Some(BigInteger::MAX), 10)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,151 @@
+use crate::commit_reveal::sha256;
+use crate::error::CommonError;
+use crate::serializable_map::SerializableMap;
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_schema::serde::Serialize;
+use cosmwasm_std::{to_json_vec, Storage};
+use cw_storage_plus::Map;
+
+// A map too large for one item, split into `shards` serialized maps under `namespace`. An entry
+// lives in the shard picked by the hash of its JSON-encoded key, so each read or write only
+// touches one shard.
+pub struct ShardedMap<K, V> {
+    map: Map<u32, SerializableMap<K, V>>,
+    shards: u32,
+}
+
+impl<K, V> ShardedMap<K, V>
+where
+    K: Ord + Serialize + DeserializeOwned,
+    V: Serialize + DeserializeOwned,
+{
+    pub const fn new(namespace: &'static str, shards: u32) -> Self {
+        assert!(shards > 0, "a sharded map needs at least one shard");
+        Self {
+            map: Map::new(namespace),
+            shards,
+        }
+    }
+
+    pub fn shards(&self) -> u32 {
+        self.shards
+    }
+
+    pub fn shard_of(&self, key: &K) -> Result<u32, CommonError> {
+        let digest = sha256(&to_json_vec(key)?);
+        let prefix = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]);
+        Ok(prefix % self.shards)
+    }
+
+    pub fn load_shard(
+        &self,
+        storage: &dyn Storage,
+        shard: u32,
+    ) -> Result<SerializableMap<K, V>, CommonError> {
+        Ok(self.map.may_load(storage, shard)?.unwrap_or_default())
+    }
+
+    fn save_shard(
+        &self,
+        storage: &mut dyn Storage,
+        shard: u32,
+        map: &SerializableMap<K, V>,
+    ) -> Result<(), CommonError> {
+        if map.is_empty() {
+            self.map.remove(storage, shard);
+        } else {
+            self.map.save(storage, shard, map)?;
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, storage: &dyn Storage, key: &K) -> Result<Option<V>, CommonError> {
+        Ok(self.load_shard(storage, self.shard_of(key)?)?.take(key))
+    }
+
+    pub fn set(&self, storage: &mut dyn Storage, key: K, value: V) -> Result<(), CommonError> {
+        let shard = self.shard_of(&key)?;
+        let mut map = self.load_shard(storage, shard)?;
+        map.set(key, value);
+        self.save_shard(storage, shard, &map)
+    }
+
+    pub fn delete(&self, storage: &mut dyn Storage, key: &K) -> Result<bool, CommonError> {
+        let shard = self.shard_of(key)?;
+        let mut map = self.load_shard(storage, shard)?;
+        let deleted = map.delete(key);
+        self.save_shard(storage, shard, &map)?;
+        Ok(deleted)
+    }
+
+    // Every entry, loading one shard at a time. Entries are in key order within a shard but not
+    // across shards; use `load_shard` to page through a map too large for one call.
+    pub fn entries(&self, storage: &dyn Storage) -> Result<Vec<(K, V)>, CommonError> {
+        let mut entries = vec![];
+        for shard in 0..self.shards {
+            entries.extend(self.load_shard(storage, shard)?);
+        }
+        Ok(entries)
+    }
+
+    // Migration from a single `SerializableMap` item: entries are added to the shards, and the
+    // caller removes the old item afterwards.
+    pub fn import(
+        &self,
+        storage: &mut dyn Storage,
+        map: SerializableMap<K, V>,
+    ) -> Result<(), CommonError> {
+        let mut shards: Vec<SerializableMap<K, V>> = (0..self.shards)
+            .map(|shard| self.load_shard(storage, shard))
+            .collect::<Result<_, _>>()?;
+        for (key, value) in map {
+            let shard = self.shard_of(&key)?;
+            shards[shard as usize].set(key, value);
+        }
+        for (shard, map) in shards.iter().enumerate() {
+            self.save_shard(storage, shard as u32, map)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::serializable_map::SerializableMap;
+    use crate::storage::sharded::ShardedMap;
+    use cosmwasm_std::testing::MockStorage;
+
+    #[test]
+    fn test_sharded_map() {
+        const BALANCES: ShardedMap<u64, u64> = ShardedMap::new("balances", 4);
+        let mut storage = MockStorage::default();
+        let legacy = SerializableMap::from((0u64..40).map(|i| (i, i * 10)).collect());
+        BALANCES.import(&mut storage, legacy.clone()).unwrap();
+
+        let mut sizes = vec![];
+        for shard in 0..BALANCES.shards() {
+            let map = BALANCES.load_shard(&storage, shard).unwrap();
+            for (key, _) in map.iter() {
+                assert_eq!(BALANCES.shard_of(key).unwrap(), shard);
+            }
+            sizes.push(map.len());
+        }
+        assert_eq!(sizes.iter().sum::<usize>(), 40);
+        assert!(sizes.iter().all(|size| *size > 0));
+
+        assert_eq!(BALANCES.get(&storage, &7).unwrap(), Some(70));
+        BALANCES.set(&mut storage, 7, 71).unwrap();
+        BALANCES.set(&mut storage, 100, 1000).unwrap();
+        assert!(BALANCES.delete(&mut storage, &8).unwrap());
+        assert!(!BALANCES.delete(&mut storage, &8).unwrap());
+        assert_eq!(BALANCES.get(&storage, &7).unwrap(), Some(71));
+        assert_eq!(BALANCES.get(&storage, &8).unwrap(), None);
+
+        let mut entries = BALANCES.entries(&storage).unwrap();
+        entries.sort();
+        let mut expected: Vec<(u64, u64)> = legacy.into_iter().filter(|(k, _)| *k != 8).collect();
+        expected[7].1 = 71;
+        expected.push((100, 1000));
+        assert_eq!(entries, expected);
+    }
+}
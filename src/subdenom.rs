@@ -0,0 +1,94 @@
+use crate::commit_reveal::sha256;
+use crate::denom_pair::DenomPair;
+use crate::validation::MAX_SUBDENOM_LENGTH;
+use cosmwasm_std::HexBinary;
+
+// hex digits of the name's sha256 kept when it has to be truncated
+const HASH_SUFFIX_LENGTH: usize = 8;
+
+// `name` if it fits, otherwise its longest prefix that leaves room for `.` and the first hex
+// digits of the sha256 of the whole name, so names sharing a long prefix stay distinct.
+pub fn fit_subdenom(name: &str) -> String {
+    if name.len() <= MAX_SUBDENOM_LENGTH {
+        return name.to_string();
+    }
+    let room = MAX_SUBDENOM_LENGTH - HASH_SUFFIX_LENGTH - 1;
+    let end = name
+        .char_indices()
+        .map(|(i, c)| i + c.len_utf8())
+        .take_while(|end| *end <= room)
+        .last()
+        .unwrap_or(0);
+    let hash = HexBinary::from(sha256(name.as_bytes())).to_hex();
+    format!("{}.{}", &name[..end], &hash[..HASH_SUFFIX_LENGTH])
+}
+
+// `{prefix}/{base}/{quote}`, e.g. the LP token of a pair. The order of the pair matters.
+pub fn pair_subdenom(prefix: &str, pair: &DenomPair) -> String {
+    fit_subdenom(&format!("{}/{}", prefix, pair))
+}
+
+// `{prefix}/{pool_id}`, e.g. the receipt token of a pool.
+pub fn pool_subdenom(prefix: &str, pool_id: u64) -> String {
+    fit_subdenom(&format!("{}/{}", prefix, pool_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::denom_pair::DenomPair;
+    use crate::serializable_denom::SerializableDenom;
+    use crate::subdenom::{fit_subdenom, pair_subdenom, pool_subdenom};
+    use crate::validation::MAX_SUBDENOM_LENGTH;
+    use std::collections::BTreeSet;
+
+    fn native(denom: &str) -> SerializableDenom {
+        SerializableDenom::Native(denom.to_string())
+    }
+
+    #[test]
+    fn test_short_names_are_kept() {
+        let pair = DenomPair::new(native("uatom"), native("uosmo"));
+        assert_eq!(pair_subdenom("lp", &pair), "lp/native_uatom/native_uosmo");
+        assert_eq!(pool_subdenom("pool", 42), "pool/42");
+        assert_eq!(pool_subdenom("pool", u64::MAX).len(), 25);
+    }
+
+    #[test]
+    fn test_long_names_are_truncated() {
+        let ibc = "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+        let pair = DenomPair::new(native(ibc), native("uosmo"));
+        let subdenom = pair_subdenom("lp", &pair);
+        assert_eq!(subdenom.len(), MAX_SUBDENOM_LENGTH);
+        assert!(subdenom.starts_with("lp/native_ibc/27394FB092D2ECCD5612"));
+        assert_eq!(subdenom, pair_subdenom("lp", &pair));
+
+        // a multi-byte character straddling the cut is dropped whole
+        let name = format!("{}é{}", "a".repeat(34), "b".repeat(20));
+        let fitted = fit_subdenom(&name);
+        assert!(fitted.len() <= MAX_SUBDENOM_LENGTH);
+        assert!(fitted.starts_with(&format!("{}.", "a".repeat(34))));
+    }
+
+    #[test]
+    fn test_no_collisions() {
+        let prefix = "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5E";
+        let mut subdenoms = BTreeSet::new();
+        for i in 0..256u32 {
+            let pair = DenomPair::new(native(&format!("{}{:02X}", prefix, i)), native("uosmo"));
+            subdenoms.insert(pair_subdenom("lp", &pair));
+            subdenoms.insert(pair_subdenom("lp", &pair.inverse()));
+        }
+        assert_eq!(subdenoms.len(), 512);
+        assert!(subdenoms.iter().all(|s| s.len() <= MAX_SUBDENOM_LENGTH));
+
+        let native_pair = DenomPair::new(native("token"), native("uosmo"));
+        let cw20_pair = DenomPair::new(
+            SerializableDenom::Cw20("token".to_string()),
+            native("uosmo"),
+        );
+        assert_ne!(
+            pair_subdenom("lp", &native_pair),
+            pair_subdenom("lp", &cw20_pair)
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::asset::Asset;
+use crate::events::{find_attr, AttrValue};
+use crate::serializable_denom::SerializableDenom;
+use core::fmt::Debug;
+use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{
+    from_json, to_json_binary, Coin, ContractResult, OwnedDeps, Response, StdResult, SystemError,
+    SystemResult, Uint128, WasmQuery,
+};
+use cw20::{BalanceResponse, Cw20QueryMsg};
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+#[macro_export]
+macro_rules! asset {
+    (cw20: $address:expr, $amount:expr) => {
+        $crate::asset::Asset::cw20($address, $amount as u128)
+    };
+    ($denom:expr, $amount:expr) => {
+        $crate::asset::Asset::native($denom, $amount as u128)
+    };
+}
+
+#[macro_export]
+macro_rules! bigdec {
+    ($value:literal) => {
+        <$crate::bigdecimal::BigDecimal as core::str::FromStr>::from_str(stringify!($value))
+            .unwrap()
+    };
+}
+
+// Native assets are served by the bank querier, cw20 assets answer `Cw20QueryMsg::Balance` smart
+// queries sent to the token contract.
+pub fn mock_querier(balances: &[(&str, &[Asset])]) -> StdResult<MockQuerier> {
+    let mut querier = MockQuerier::new(&[]);
+    let mut cw20_balances: BTreeMap<(String, String), Uint128> = BTreeMap::new();
+    for (holder, assets) in balances {
+        let mut coins = vec![];
+        for asset in assets.iter() {
+            match &asset.denom {
+                SerializableDenom::Native(denom) => {
+                    coins.push(Coin::new(asset.amount.to_uint128()?, denom))
+                }
+                SerializableDenom::Cw20(contract) => {
+                    let balance = cw20_balances
+                        .entry((contract.clone(), holder.to_string()))
+                        .or_default();
+                    *balance += asset.amount.to_uint128()?;
+                }
+            }
+        }
+        querier.bank.update_balance(*holder, coins);
+    }
+
+    querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { contract_addr, msg } => match from_json(msg) {
+            Ok(Cw20QueryMsg::Balance { address }) => {
+                let balance = cw20_balances
+                    .get(&(contract_addr.clone(), address))
+                    .copied()
+                    .unwrap_or_default();
+                SystemResult::Ok(ContractResult::from(to_json_binary(&BalanceResponse {
+                    balance,
+                })))
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "cw20 query".to_string(),
+            }),
+        },
+        _ => SystemResult::Err(SystemError::UnsupportedRequest {
+            kind: "wasm query".to_string(),
+        }),
+    });
+    Ok(querier)
+}
+
+pub fn mock_dependencies_with_assets(
+    balances: &[(&str, &[Asset])],
+) -> StdResult<OwnedDeps<MockStorage, MockApi, MockQuerier>> {
+    Ok(OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: mock_querier(balances)?,
+        custom_query_type: PhantomData,
+    })
+}
+
+pub fn assert_attribute<C, T>(response: &Response<C>, key: &str, expected: T)
+where
+    T: AttrValue + PartialEq + Debug,
+{
+    let value = find_attr(&response.attributes, key)
+        .unwrap_or_else(|| panic!("attribute {} not found", key));
+    let actual = T::from_attr_value(value)
+        .unwrap_or_else(|err| panic!("attribute {} could not be parsed: {}", key, err));
+    assert_eq!(actual, expected, "attribute {}", key);
+}
+
+pub fn assert_event_attribute<C, T>(response: &Response<C>, ty: &str, key: &str, expected: T)
+where
+    T: AttrValue + PartialEq + Debug,
+{
+    let event = response
+        .events
+        .iter()
+        .find(|event| event.ty == ty)
+        .unwrap_or_else(|| panic!("event {} not found", ty));
+    let value = find_attr(&event.attributes, key)
+        .unwrap_or_else(|| panic!("attribute {} not found in event {}", key, ty));
+    let actual = T::from_attr_value(value)
+        .unwrap_or_else(|err| panic!("attribute {} could not be parsed: {}", key, err));
+    assert_eq!(actual, expected, "attribute {} of event {}", key, ty);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::asset::Asset;
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::response::ResponseBuilder;
+    use crate::testing::{assert_attribute, assert_event_attribute, mock_dependencies_with_assets};
+    use cosmwasm_std::{Event, Response, Uint128};
+    use cw20::{BalanceResponse, Cw20QueryMsg};
+
+    #[test]
+    fn test_macros() {
+        assert_eq!(
+            asset!("uatom", 1_000_000),
+            Asset::native("uatom", 1_000_000u128)
+        );
+        assert_eq!(asset!(cw20: "token", 5), Asset::cw20("token", 5u128));
+        assert_eq!(bigdec!(1.5), BigDecimal::percent(150));
+    }
+
+    #[test]
+    fn test_mock_querier() {
+        let deps = mock_dependencies_with_assets(&[
+            ("alice", &[asset!("uatom", 100), asset!(cw20: "token", 7)]),
+            ("bob", &[asset!(cw20: "token", 3)]),
+        ])
+        .unwrap();
+        let querier = deps.as_ref().querier;
+
+        assert_eq!(
+            querier.query_balance("alice", "uatom").unwrap().amount,
+            Uint128::new(100)
+        );
+        let response: BalanceResponse = querier
+            .query_wasm_smart(
+                "token",
+                &Cw20QueryMsg::Balance {
+                    address: "bob".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(response.balance, Uint128::new(3));
+    }
+
+    #[test]
+    fn test_assert_attribute() {
+        let response: Response = ResponseBuilder::new()
+            .add_typed_attribute("amount", &BigInteger::from(10u64))
+            .add_event(Event::new("swap").add_attribute("price", "1.50"))
+            .build();
+        assert_attribute(&response, "amount", BigInteger::from(10u64));
+        assert_event_attribute(&response, "swap", "price", bigdec!(1.5));
+    }
+}
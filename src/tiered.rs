@@ -0,0 +1,103 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::serializable_map::SerializableMap;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::serde::Serialize;
+
+// Each tier applies from its threshold (inclusive) up to the next one.
+#[cw_serde]
+pub struct TieredSchedule<T>
+where
+    T: Serialize,
+{
+    pub tiers: SerializableMap<BigInteger, T>,
+}
+
+impl<T> TieredSchedule<T>
+where
+    T: Serialize,
+{
+    // Tiers must be given with strictly increasing thresholds.
+    pub fn new(tiers: Vec<(BigInteger, T)>) -> Result<Self, CommonError> {
+        for pair in tiers.windows(2) {
+            let (prev, next) = (pair[0].0, pair[1].0);
+            if next == prev {
+                return Err(CommonError::Duplicate(next.to_string()));
+            }
+            if next < prev {
+                return Err(CommonError::NotSorted(next.to_string()));
+            }
+        }
+        Ok(Self {
+            tiers: SerializableMap::from(tiers),
+        })
+    }
+
+    // `None` if the volume is below the first threshold
+    pub fn value_for(&self, volume: BigInteger) -> Option<&T> {
+        self.tiers
+            .range(..=volume)
+            .next_back()
+            .map(|(_, value)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::tiered::TieredSchedule;
+
+    fn schedule() -> TieredSchedule<BigDecimal> {
+        TieredSchedule::new(vec![
+            (BigInteger::zero(), BigDecimal::bps(30)),
+            (BigInteger::from(1_000u64), BigDecimal::bps(20)),
+            (BigInteger::from(10_000u64), BigDecimal::bps(10)),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_value_for() {
+        let schedule = schedule();
+        assert_eq!(
+            schedule.value_for(BigInteger::from(999u64)),
+            Some(&BigDecimal::bps(30))
+        );
+        assert_eq!(
+            schedule.value_for(BigInteger::from(1_000u64)),
+            Some(&BigDecimal::bps(20))
+        );
+        assert_eq!(
+            schedule.value_for(BigInteger::MAX),
+            Some(&BigDecimal::bps(10))
+        );
+
+        let schedule = TieredSchedule::new(vec![(BigInteger::from(5u64), 1u16)]).unwrap();
+        assert_eq!(schedule.value_for(BigInteger::from(4u64)), None);
+    }
+
+    #[test]
+    fn test_validation() {
+        assert_eq!(
+            TieredSchedule::new(vec![(BigInteger::one(), 1u16), (BigInteger::one(), 2u16)]),
+            Err(CommonError::Duplicate("1".to_string()))
+        );
+        assert_eq!(
+            TieredSchedule::new(vec![
+                (BigInteger::from(2u64), 1u16),
+                (BigInteger::one(), 2u16)
+            ]),
+            Err(CommonError::NotSorted("1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let schedule = schedule();
+        let json = cosmwasm_std::to_json_vec(&schedule).unwrap();
+        let parsed: TieredSchedule<BigDecimal> = cosmwasm_std::from_json(json).unwrap();
+        assert_eq!(parsed, schedule);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::serializable_map::SerializableMap;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Timestamp;
+
+// Amounts summed per `bucket_size` seconds. Buckets that end more than `retention` seconds ago are
+// pruned on every `add`, so the state stays bounded.
+#[cw_serde]
+pub struct TimeBuckets {
+    pub bucket_size: u64,
+    pub retention: u64,
+    pub buckets: SerializableMap<u64, BigInteger>,
+}
+
+impl TimeBuckets {
+    pub fn new(bucket_size: u64, retention: u64) -> Result<Self, CommonError> {
+        if bucket_size == 0 || retention < bucket_size {
+            return Err(CommonError::InvalidBuckets(format!(
+                "bucket size {} with retention {}",
+                bucket_size, retention
+            )));
+        }
+        Ok(Self {
+            bucket_size,
+            retention,
+            buckets: SerializableMap::new(),
+        })
+    }
+
+    fn epoch(&self, time: Timestamp) -> u64 {
+        time.seconds() / self.bucket_size
+    }
+
+    pub fn add(&mut self, now: Timestamp, amount: BigInteger) -> Result<(), CommonError> {
+        let epoch = self.epoch(now);
+        let total = self
+            .buckets
+            .get(&epoch)
+            .copied()
+            .unwrap_or_default()
+            .checked_add(amount)?;
+        self.buckets.set(epoch, total);
+        self.prune(now);
+        Ok(())
+    }
+
+    // Sum of the buckets overlapping the last `window` seconds, including the current one.
+    pub fn sum_last(&self, now: Timestamp, window: u64) -> Result<BigInteger, CommonError> {
+        let from = self.epoch(now.minus_seconds(window.min(now.seconds())));
+        self.buckets
+            .range(from..=self.epoch(now))
+            .try_fold(BigInteger::zero(), |acc, (_, amount)| {
+                Ok(acc.checked_add(*amount)?)
+            })
+    }
+
+    pub fn prune(&mut self, now: Timestamp) {
+        let oldest = self.epoch(now.minus_seconds(self.retention.min(now.seconds())));
+        let stale: Vec<u64> = self
+            .buckets
+            .range(..oldest)
+            .map(|(epoch, _)| *epoch)
+            .collect();
+        for epoch in stale {
+            self.buckets.delete(&epoch);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::time_buckets::TimeBuckets;
+    use cosmwasm_std::Timestamp;
+
+    const HOUR: u64 = 3600;
+
+    #[test]
+    fn test_sum_last() {
+        let mut buckets = TimeBuckets::new(HOUR, 48 * HOUR).unwrap();
+        let start = Timestamp::from_seconds(100 * HOUR);
+        for hour in 0..30 {
+            buckets
+                .add(start.plus_seconds(hour * HOUR), BigInteger::from(10u64))
+                .unwrap();
+        }
+        let now = start.plus_seconds(29 * HOUR + 1);
+        assert_eq!(
+            buckets.sum_last(now, 23 * HOUR).unwrap(),
+            BigInteger::from(240u64)
+        );
+        assert_eq!(
+            buckets.sum_last(now, 1000 * HOUR).unwrap(),
+            BigInteger::from(300u64)
+        );
+    }
+
+    #[test]
+    fn test_prune() {
+        let mut buckets = TimeBuckets::new(HOUR, 2 * HOUR).unwrap();
+        for hour in 0..10 {
+            buckets
+                .add(Timestamp::from_seconds(hour * HOUR), BigInteger::one())
+                .unwrap();
+        }
+        assert_eq!(buckets.buckets.len(), 3);
+        assert!(TimeBuckets::new(0, HOUR).is_err());
+        assert!(TimeBuckets::new(HOUR, 1).is_err());
+    }
+}
@@ -0,0 +1,120 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::BlockInfo;
+use cw_utils::Expiration;
+use std::ops::{BitOr, BitOrAssign};
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum TimeInForce {
+    // good till cancelled
+    Gtc,
+    // good till the expiration
+    Gtt(Expiration),
+    // immediate or cancel, the unfilled part is dropped
+    Ioc,
+    // fill or kill, either fully filled or dropped
+    Fok,
+}
+
+impl TimeInForce {
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        match self {
+            TimeInForce::Gtt(expiration) => expiration.is_expired(block),
+            _ => false,
+        }
+    }
+
+    pub fn allows_partial_fill(&self) -> bool {
+        !matches!(self, TimeInForce::Fok)
+    }
+
+    // whether the unfilled part stays on the book
+    pub fn rests_on_book(&self) -> bool {
+        matches!(self, TimeInForce::Gtc | TimeInForce::Gtt(_))
+    }
+}
+
+#[cw_serde]
+#[derive(Copy, Default, Eq)]
+pub struct OrderFlags(pub u8);
+
+impl OrderFlags {
+    pub const NONE: Self = Self(0);
+    // rejected instead of matching on insertion
+    pub const POST_ONLY: Self = Self(1);
+    // may only decrease an existing position
+    pub const REDUCE_ONLY: Self = Self(1 << 1);
+    // not shown in depth queries
+    pub const HIDDEN: Self = Self(1 << 2);
+
+    pub fn contains(&self, flags: Self) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+
+    pub fn insert(&mut self, flags: Self) {
+        self.0 |= flags.0;
+    }
+
+    pub fn remove(&mut self, flags: Self) {
+        self.0 &= !flags.0;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for OrderFlags {
+    type Output = OrderFlags;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for OrderFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.insert(rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time_in_force::{OrderFlags, TimeInForce};
+    use cosmwasm_std::testing::mock_env;
+    use cw_utils::Expiration;
+
+    #[test]
+    fn test_time_in_force() {
+        let block = mock_env().block;
+        let gtt = TimeInForce::Gtt(Expiration::AtHeight(block.height));
+        assert!(gtt.is_expired(&block));
+        assert!(!TimeInForce::Gtc.is_expired(&block));
+        assert!(TimeInForce::Ioc.allows_partial_fill());
+        assert!(!TimeInForce::Fok.allows_partial_fill());
+        assert!(gtt.rests_on_book());
+        assert!(!TimeInForce::Ioc.rests_on_book());
+
+        assert_eq!(
+            cosmwasm_std::to_json_string(&TimeInForce::Fok).unwrap(),
+            "\"fok\""
+        );
+    }
+
+    #[test]
+    fn test_order_flags() {
+        let mut flags = OrderFlags::POST_ONLY | OrderFlags::HIDDEN;
+        assert!(flags.contains(OrderFlags::POST_ONLY));
+        assert!(!flags.contains(OrderFlags::REDUCE_ONLY));
+        flags.remove(OrderFlags::POST_ONLY);
+        assert_eq!(flags, OrderFlags::HIDDEN);
+        flags.remove(OrderFlags::HIDDEN);
+        assert!(flags.is_empty());
+
+        let json = cosmwasm_std::to_json_string(&(OrderFlags::POST_ONLY | OrderFlags::REDUCE_ONLY))
+            .unwrap();
+        assert_eq!(json, "3");
+        let parsed: OrderFlags = cosmwasm_std::from_json(json).unwrap();
+        assert!(parsed.contains(OrderFlags::REDUCE_ONLY));
+    }
+}
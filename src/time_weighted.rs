@@ -0,0 +1,158 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::serializable_map::SerializableMap;
+use core::ops::Bound::Excluded;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Timestamp;
+
+// A piecewise-constant value such as the total staked, keyed by the second it changed. The value
+// is zero before the first change.
+#[cw_serde]
+#[derive(Default)]
+pub struct TimeWeightedValue {
+    pub changes: SerializableMap<u64, BigInteger>,
+}
+
+impl TimeWeightedValue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Changes are append-only, recording at the latest time again overwrites it.
+    pub fn record(&mut self, now: Timestamp, value: BigInteger) -> Result<(), CommonError> {
+        if let Some((last, _)) = self.changes.last() {
+            if now.seconds() < *last {
+                return Err(CommonError::NotSorted(now.seconds().to_string()));
+            }
+        }
+        self.changes.set(now.seconds(), value);
+        Ok(())
+    }
+
+    pub fn value_at(&self, time: Timestamp) -> BigInteger {
+        self.changes
+            .range(..=time.seconds())
+            .next_back()
+            .map(|(_, value)| *value)
+            .unwrap_or_default()
+    }
+
+    // Sum of value * seconds over [start, end).
+    pub fn integral(&self, start: Timestamp, end: Timestamp) -> Result<BigInteger, CommonError> {
+        let (start, end) = (start.seconds(), end.seconds());
+        if end < start {
+            return Err(CommonError::InvalidRange {
+                min: start.to_string(),
+                max: end.to_string(),
+            });
+        }
+        if end == start {
+            return Ok(BigInteger::zero());
+        }
+        let mut total = BigInteger::zero();
+        let mut cursor = start;
+        let mut current = self.value_at(Timestamp::from_seconds(start));
+        for (time, value) in self.changes.range((Excluded(start), Excluded(end))) {
+            total = total.checked_add(current.checked_mul(BigInteger::from(time - cursor))?)?;
+            cursor = *time;
+            current = *value;
+        }
+        Ok(total.checked_add(current.checked_mul(BigInteger::from(end - cursor))?)?)
+    }
+
+    // Duration-weighted average over [start, end), rounded down. An empty window gives the value
+    // at `start`.
+    pub fn average(&self, start: Timestamp, end: Timestamp) -> Result<BigInteger, CommonError> {
+        let integral = self.integral(start, end)?;
+        if end == start {
+            return Ok(self.value_at(start));
+        }
+        Ok(integral.checked_div(BigInteger::from(end.seconds() - start.seconds()))?)
+    }
+
+    // Collapses the changes up to `time` into one at `time`, so averages over windows starting at
+    // or after it are unchanged.
+    pub fn prune_before(&mut self, time: Timestamp) {
+        let value = self.value_at(time);
+        let stale: Vec<u64> = self
+            .changes
+            .range(..=time.seconds())
+            .map(|(time, _)| *time)
+            .collect();
+        if stale.is_empty() {
+            return;
+        }
+        for time in stale {
+            self.changes.delete(&time);
+        }
+        self.changes.set(time.seconds(), value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::time_weighted::TimeWeightedValue;
+    use cosmwasm_std::Timestamp;
+
+    fn at(seconds: u64) -> Timestamp {
+        Timestamp::from_seconds(seconds)
+    }
+
+    fn staked() -> TimeWeightedValue {
+        let mut staked = TimeWeightedValue::new();
+        staked.record(at(100), BigInteger::from(1000u64)).unwrap();
+        staked.record(at(150), BigInteger::from(3000u64)).unwrap();
+        staked.record(at(200), BigInteger::zero()).unwrap();
+        staked
+    }
+
+    #[test]
+    fn test_average() {
+        let staked = staked();
+        assert_eq!(staked.value_at(at(99)), BigInteger::zero());
+        assert_eq!(staked.value_at(at(150)), BigInteger::from(3000u64));
+
+        // half the window at 1000, half at 3000
+        assert_eq!(
+            staked.average(at(100), at(200)).unwrap(),
+            BigInteger::from(2000u64)
+        );
+        // zero before the first change
+        assert_eq!(
+            staked.average(at(50), at(150)).unwrap(),
+            BigInteger::from(500u64)
+        );
+        assert_eq!(
+            staked.integral(at(120), at(250)).unwrap(),
+            BigInteger::from(30 * 1000 + 50 * 3000u64)
+        );
+        assert_eq!(
+            staked.average(at(160), at(160)).unwrap(),
+            BigInteger::from(3000u64)
+        );
+        assert!(matches!(
+            staked.average(at(200), at(100)),
+            Err(CommonError::InvalidRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_record_and_prune() {
+        let mut staked = staked();
+        assert_eq!(
+            staked.record(at(199), BigInteger::one()),
+            Err(CommonError::NotSorted("199".to_string()))
+        );
+
+        let before = staked.average(at(170), at(300)).unwrap();
+        staked.prune_before(at(170));
+        assert_eq!(staked.changes.len(), 2);
+        assert_eq!(staked.average(at(170), at(300)).unwrap(), before);
+        assert_eq!(staked.value_at(at(170)), BigInteger::from(3000u64));
+
+        let serialized = cosmwasm_std::to_json_string(&staked).unwrap();
+        assert_eq!(serialized, r#"{"changes":[[170,"3000"],[200,"0"]]}"#);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::asset::Asset;
+use crate::balances::Balances;
+use crate::error::CommonError;
+use crate::serializable_denom::SerializableDenom;
+use cosmwasm_std::{BankMsg, CosmosMsg};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferPlan {
+    pub messages: Vec<CosmosMsg>,
+    // coalesced transfers that did not fit, to be sent in a later transaction
+    pub remainder: Vec<(String, Asset)>,
+}
+
+// Coalesces transfers into one bank send per recipient carrying all of its native coins, plus one
+// cw20 transfer per recipient and token. Recipients are handled in address order and at most
+// `max_messages` messages are built; a recipient's native coins always go out together.
+pub fn plan_transfers(
+    transfers: &[(String, Asset)],
+    max_messages: usize,
+) -> Result<TransferPlan, CommonError> {
+    let mut by_recipient: BTreeMap<&str, Balances> = BTreeMap::new();
+    for (recipient, asset) in transfers {
+        by_recipient
+            .entry(recipient.as_str())
+            .or_default()
+            .add_asset(asset)?;
+    }
+
+    let mut plan = TransferPlan {
+        messages: vec![],
+        remainder: vec![],
+    };
+    for (recipient, balances) in by_recipient {
+        let coins = balances.to_coins()?;
+        if !coins.is_empty() {
+            if plan.messages.len() < max_messages {
+                plan.messages.push(
+                    BankMsg::Send {
+                        to_address: recipient.to_string(),
+                        amount: coins,
+                    }
+                    .into(),
+                );
+            } else {
+                plan.remainder.extend(coins.into_iter().map(|coin| {
+                    (
+                        recipient.to_string(),
+                        Asset::native(coin.denom, coin.amount),
+                    )
+                }));
+            }
+        }
+        for asset in balances.to_assets() {
+            if let SerializableDenom::Native(_) = asset.denom {
+                continue;
+            }
+            if plan.messages.len() < max_messages {
+                plan.messages.push(asset.transfer_msg(recipient)?);
+            } else {
+                plan.remainder.push((recipient.to_string(), asset));
+            }
+        }
+    }
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::asset::Asset;
+    use crate::transfers::plan_transfers;
+    use cosmwasm_std::{coin, BankMsg, Coin, CosmosMsg};
+
+    fn send(to: &str, amount: Vec<Coin>) -> CosmosMsg {
+        BankMsg::Send {
+            to_address: to.to_string(),
+            amount,
+        }
+        .into()
+    }
+
+    #[test]
+    fn test_coalesce_native() {
+        let transfers = vec![
+            ("bob".to_string(), Asset::native("uusd", 10u64)),
+            ("alice".to_string(), Asset::native("uusd", 5u64)),
+            ("bob".to_string(), Asset::native("uatom", 1u64)),
+            ("bob".to_string(), Asset::native("uusd", 15u64)),
+            ("carol".to_string(), Asset::native("uusd", 0u64)),
+        ];
+        let plan = plan_transfers(&transfers, 10).unwrap();
+        assert_eq!(
+            plan.messages,
+            vec![
+                send("alice", vec![coin(5, "uusd")]),
+                send("bob", vec![coin(1, "uatom"), coin(25, "uusd")]),
+            ]
+        );
+        assert!(plan.remainder.is_empty());
+    }
+
+    #[test]
+    fn test_remainder_carry_over() {
+        let transfers = vec![
+            ("alice".to_string(), Asset::native("uusd", 5u64)),
+            ("bob".to_string(), Asset::native("uusd", 10u64)),
+            ("bob".to_string(), Asset::native("uatom", 1u64)),
+            ("carol".to_string(), Asset::native("uusd", 7u64)),
+        ];
+        let plan = plan_transfers(&transfers, 1).unwrap();
+        assert_eq!(plan.messages, vec![send("alice", vec![coin(5, "uusd")])]);
+        assert_eq!(
+            plan.remainder,
+            vec![
+                ("bob".to_string(), Asset::native("uatom", 1u64)),
+                ("bob".to_string(), Asset::native("uusd", 10u64)),
+                ("carol".to_string(), Asset::native("uusd", 7u64)),
+            ]
+        );
+
+        let next = plan_transfers(&plan.remainder, 1).unwrap();
+        assert_eq!(
+            next.messages,
+            vec![send("bob", vec![coin(1, "uatom"), coin(10, "uusd")])]
+        );
+        assert_eq!(next.remainder.len(), 1);
+    }
+
+    #[cfg(feature = "cw20")]
+    #[test]
+    fn test_cw20_transfers() {
+        use cosmwasm_std::{to_json_binary, WasmMsg};
+        use cw20::Cw20ExecuteMsg;
+
+        let transfers = vec![
+            ("alice".to_string(), Asset::cw20("token", 3u64)),
+            ("alice".to_string(), Asset::native("uusd", 5u64)),
+            ("alice".to_string(), Asset::cw20("token", 4u64)),
+        ];
+        let plan = plan_transfers(&transfers, 10).unwrap();
+        assert_eq!(
+            plan.messages,
+            vec![
+                send("alice", vec![coin(5, "uusd")]),
+                WasmMsg::Execute {
+                    contract_addr: "token".to_string(),
+                    msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                        recipient: "alice".to_string(),
+                        amount: 7u128.into(),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::BlockInfo;
+use cw_utils::Expiration;
+
+#[cw_serde]
+pub struct UnbondEntry {
+    pub amount: BigInteger,
+    pub release: Expiration,
+}
+
+// One user's pending unbondings, ordered by release where releases are comparable.
+#[cw_serde]
+pub struct Unbondings {
+    pub max_entries: usize,
+    pub entries: Vec<UnbondEntry>,
+}
+
+impl Unbondings {
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            entries: vec![],
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Entries releasing at the same time are merged, so they do not count against `max_entries`.
+    pub fn add(&mut self, amount: BigInteger, release: Expiration) -> Result<(), CommonError> {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.release == release) {
+            entry.amount = entry.amount.checked_add(amount)?;
+            return Ok(());
+        }
+        if self.entries.len() >= self.max_entries {
+            return Err(CommonError::CapacityExceeded {
+                max: self.max_entries,
+            });
+        }
+        let position = self
+            .entries
+            .iter()
+            .position(|e| e.release > release)
+            .unwrap_or(self.entries.len());
+        self.entries
+            .insert(position, UnbondEntry { amount, release });
+        Ok(())
+    }
+
+    fn sum<'a>(
+        mut entries: impl Iterator<Item = &'a UnbondEntry>,
+    ) -> Result<BigInteger, CommonError> {
+        entries.try_fold(BigInteger::zero(), |total, e| {
+            Ok(total.checked_add(e.amount)?)
+        })
+    }
+
+    pub fn total(&self) -> Result<BigInteger, CommonError> {
+        Self::sum(self.entries.iter())
+    }
+
+    pub fn claimable(&self, block: &BlockInfo) -> Result<BigInteger, CommonError> {
+        Self::sum(self.entries.iter().filter(|e| e.release.is_expired(block)))
+    }
+
+    pub fn pending(&self, block: &BlockInfo) -> Result<BigInteger, CommonError> {
+        Self::sum(self.entries.iter().filter(|e| !e.release.is_expired(block)))
+    }
+
+    // Removes matured entries and returns the claimed amount. With `max`, at most that much is
+    // claimed and the matured entry it stops in keeps the remainder.
+    pub fn claim(&mut self, block: &BlockInfo, max: Option<BigInteger>) -> BigInteger {
+        let mut remaining = max.unwrap_or(BigInteger::MAX);
+        let mut claimed = BigInteger::zero();
+        for entry in self.entries.iter_mut() {
+            if remaining.is_zero() {
+                break;
+            }
+            if !entry.release.is_expired(block) {
+                continue;
+            }
+            let take = entry.amount.min(remaining);
+            entry.amount -= take;
+            remaining -= take;
+            claimed += take;
+        }
+        self.entries.retain(|e| !e.amount.is_zero());
+        claimed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::unbonding::Unbondings;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::Timestamp;
+    use cw_utils::Expiration;
+
+    fn at(seconds: u64) -> Expiration {
+        Expiration::AtTime(Timestamp::from_seconds(seconds))
+    }
+
+    #[test]
+    fn test_add() {
+        let mut unbondings = Unbondings::new(2);
+        unbondings.add(BigInteger::from(10u64), at(200)).unwrap();
+        unbondings.add(BigInteger::from(5u64), at(100)).unwrap();
+        unbondings.add(BigInteger::from(1u64), at(200)).unwrap();
+        assert_eq!(unbondings.entries.len(), 2);
+        assert_eq!(unbondings.entries[0].release, at(100));
+        assert_eq!(unbondings.entries[1].amount, BigInteger::from(11u64));
+        assert_eq!(
+            unbondings.add(BigInteger::from(1u64), at(300)),
+            Err(CommonError::CapacityExceeded { max: 2 })
+        );
+    }
+
+    #[test]
+    fn test_claim() {
+        let mut block = mock_env().block;
+        block.time = Timestamp::from_seconds(150);
+
+        let mut unbondings = Unbondings::new(10);
+        unbondings.add(BigInteger::from(10u64), at(100)).unwrap();
+        unbondings.add(BigInteger::from(20u64), at(120)).unwrap();
+        unbondings.add(BigInteger::from(30u64), at(200)).unwrap();
+        assert_eq!(
+            unbondings.claimable(&block).unwrap(),
+            BigInteger::from(30u64)
+        );
+        assert_eq!(unbondings.pending(&block).unwrap(), BigInteger::from(30u64));
+
+        assert_eq!(
+            unbondings.claim(&block, Some(BigInteger::from(15u64))),
+            BigInteger::from(15u64)
+        );
+        assert_eq!(unbondings.entries.len(), 2);
+        assert_eq!(unbondings.entries[0].amount, BigInteger::from(15u64));
+
+        assert_eq!(unbondings.claim(&block, None), BigInteger::from(15u64));
+        assert_eq!(unbondings.claim(&block, None), BigInteger::zero());
+        assert_eq!(unbondings.total().unwrap(), BigInteger::from(30u64));
+
+        block.time = Timestamp::from_seconds(200);
+        assert_eq!(unbondings.claim(&block, None), BigInteger::from(30u64));
+        assert!(unbondings.is_empty());
+    }
+
+    #[test]
+    fn test_totals_overflow() {
+        let mut block = mock_env().block;
+        block.time = Timestamp::from_seconds(150);
+
+        let mut unbondings = Unbondings::new(10);
+        unbondings.add(BigInteger::MAX, at(100)).unwrap();
+        unbondings.add(BigInteger::MAX, at(200)).unwrap();
+        assert!(unbondings.total().is_err());
+        assert_eq!(unbondings.claimable(&block).unwrap(), BigInteger::MAX);
+        assert_eq!(unbondings.pending(&block).unwrap(), BigInteger::MAX);
+
+        unbondings.add(BigInteger::one(), at(120)).unwrap();
+        assert!(unbondings.claimable(&block).is_err());
+        unbondings.add(BigInteger::one(), at(300)).unwrap();
+        assert!(unbondings.pending(&block).is_err());
+    }
+}
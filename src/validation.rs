@@ -0,0 +1,165 @@
+use crate::error::CommonError;
+use cosmwasm_std::{Addr, Api};
+
+pub const MAX_ADDRESSES: usize = 1000;
+pub const MIN_SYMBOL_LENGTH: usize = 3;
+pub const MAX_SYMBOL_LENGTH: usize = 12;
+pub const MIN_NAME_LENGTH: usize = 3;
+pub const MAX_NAME_LENGTH: usize = 50;
+pub const MAX_URL_LENGTH: usize = 256;
+pub const MAX_SUBDENOM_LENGTH: usize = 44;
+
+pub fn validate_addresses(api: &dyn Api, addresses: &[String]) -> Result<Vec<Addr>, CommonError> {
+    validate_addresses_with_limit(api, addresses, MAX_ADDRESSES)
+}
+
+pub fn validate_addresses_with_limit(
+    api: &dyn Api,
+    addresses: &[String],
+    max: usize,
+) -> Result<Vec<Addr>, CommonError> {
+    if addresses.len() > max {
+        return Err(CommonError::TooManyAddresses {
+            max,
+            actual: addresses.len(),
+        });
+    }
+    let validated = addresses
+        .iter()
+        .map(|address| api.addr_validate(address))
+        .collect::<Result<Vec<Addr>, _>>()?;
+    assert_unique(&validated)?;
+    Ok(validated)
+}
+
+pub fn assert_unique<T: Ord + ToString>(items: &[T]) -> Result<(), CommonError> {
+    let mut sorted: Vec<&T> = items.iter().collect();
+    sorted.sort();
+    match sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+        Some(pair) => Err(CommonError::Duplicate(pair[0].to_string())),
+        None => Ok(()),
+    }
+}
+
+pub fn assert_sorted<T: Ord + ToString>(items: &[T]) -> Result<(), CommonError> {
+    match items.windows(2).find(|pair| pair[0] > pair[1]) {
+        Some(pair) => Err(CommonError::NotSorted(pair[1].to_string())),
+        None => Ok(()),
+    }
+}
+
+pub fn validate_symbol(symbol: &str) -> Result<(), CommonError> {
+    let length_ok = (MIN_SYMBOL_LENGTH..=MAX_SYMBOL_LENGTH).contains(&symbol.len());
+    if !length_ok || !symbol.bytes().all(|b| b.is_ascii_uppercase()) {
+        return Err(CommonError::InvalidSymbol(symbol.to_string()));
+    }
+    Ok(())
+}
+
+pub fn validate_name(name: &str) -> Result<(), CommonError> {
+    let length = name.chars().count();
+    if !(MIN_NAME_LENGTH..=MAX_NAME_LENGTH).contains(&length) {
+        return Err(CommonError::InvalidNameLength {
+            min: MIN_NAME_LENGTH,
+            max: MAX_NAME_LENGTH,
+            actual: length,
+        });
+    }
+    Ok(())
+}
+
+pub fn validate_url(url: &str) -> Result<(), CommonError> {
+    let host = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or_default();
+    if host.is_empty()
+        || host.starts_with('/')
+        || url.len() > MAX_URL_LENGTH
+        || !url.bytes().all(|b| b.is_ascii_graphic())
+    {
+        return Err(CommonError::InvalidUrl(url.to_string()));
+    }
+    Ok(())
+}
+
+pub fn validate_subdenom(subdenom: &str) -> Result<(), CommonError> {
+    let valid_chars = subdenom
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'/' | b'-' | b'_'));
+    if subdenom.is_empty() || subdenom.len() > MAX_SUBDENOM_LENGTH || !valid_chars {
+        return Err(CommonError::InvalidSubdenom(subdenom.to_string()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::CommonError;
+    use crate::validation::{
+        assert_sorted, assert_unique, validate_addresses, validate_addresses_with_limit,
+        validate_name, validate_subdenom, validate_symbol, validate_url,
+    };
+    use cosmwasm_std::testing::MockApi;
+
+    #[test]
+    fn test_validate_addresses() {
+        let api = MockApi::default();
+        let alice = api.addr_make("alice").to_string();
+        let bob = api.addr_make("bob").to_string();
+
+        let validated = validate_addresses(&api, &[alice.clone(), bob.clone()]).unwrap();
+        assert_eq!(validated.len(), 2);
+
+        assert_eq!(
+            validate_addresses(&api, &[alice.clone(), bob.clone(), alice.clone()]),
+            Err(CommonError::Duplicate(alice.clone()))
+        );
+        assert_eq!(
+            validate_addresses_with_limit(&api, &[alice.clone(), bob], 1),
+            Err(CommonError::TooManyAddresses { max: 1, actual: 2 })
+        );
+        assert!(validate_addresses(&api, &["invalid".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_assert_sorted() {
+        assert!(assert_sorted(&[1, 2, 2, 3]).is_ok());
+        assert_eq!(
+            assert_sorted(&[1, 3, 2]),
+            Err(CommonError::NotSorted("2".to_string()))
+        );
+        assert!(assert_unique(&[3, 1, 2]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_symbol_and_name() {
+        assert!(validate_symbol("ATOM").is_ok());
+        assert!(validate_symbol("AB").is_err());
+        assert!(validate_symbol("Atom").is_err());
+        assert!(validate_symbol("ABCDEFGHIJKLM").is_err());
+
+        assert!(validate_name("Cosmos Hub").is_ok());
+        assert_eq!(
+            validate_name("ab"),
+            Err(CommonError::InvalidNameLength {
+                min: 3,
+                max: 50,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_url_and_subdenom() {
+        assert!(validate_url("https://example.com/logo.png").is_ok());
+        assert!(validate_url("ftp://example.com").is_err());
+        assert!(validate_url("https://").is_err());
+        assert!(validate_url("https://exa mple.com").is_err());
+
+        assert!(validate_subdenom("ulp.pool-1/share_a").is_ok());
+        assert!(validate_subdenom("").is_err());
+        assert!(validate_subdenom("bad denom").is_err());
+        assert!(validate_subdenom(&"a".repeat(45)).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use crate::balances::Balances;
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::number::Number;
+use crate::rate_cache::CachedRate;
+use crate::serializable_denom::SerializableDenom;
+use crate::serializable_map::SerializableMap;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Timestamp;
+
+// How a collateral denom is counted: `decimals` converts base units to whole tokens and `haircut`
+// is the fraction of the market value that is not counted.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub struct CollateralConfig {
+    pub decimals: u32,
+    pub haircut: BigDecimal,
+}
+
+impl CollateralConfig {
+    pub fn new(decimals: u32, haircut: BigDecimal) -> Self {
+        Self { decimals, haircut }
+    }
+
+    pub fn validate(&self) -> Result<(), CommonError> {
+        if !self.haircut.is_ratio() {
+            return Err(CommonError::InvalidRatio(self.haircut.to_string()));
+        }
+        Ok(())
+    }
+}
+
+// Value of one denom after its haircut. `price` is the quote value of one whole token.
+pub fn asset_value(
+    amount: BigInteger,
+    price: BigDecimal,
+    config: &CollateralConfig,
+) -> Result<BigDecimal, CommonError> {
+    config.validate()?;
+    amount
+        .checked_scale_down(config.decimals)?
+        .checked_mul(price)?
+        .checked_mul(BigDecimal::one() - config.haircut)
+}
+
+// Haircut value of every denom in `balances`. Fails when a held denom has no config, or its price
+// is missing or older than `max_age` seconds, rather than valuing the position without it.
+pub fn basket_value(
+    balances: &Balances,
+    prices: &SerializableMap<SerializableDenom, CachedRate>,
+    decimals_registry: &SerializableMap<SerializableDenom, CollateralConfig>,
+    now: Timestamp,
+    max_age: u64,
+) -> Result<BigDecimal, CommonError> {
+    let mut total = BigDecimal::zero();
+    for (denom, amount) in balances.iter() {
+        if amount.is_zero() {
+            continue;
+        }
+        let config = decimals_registry
+            .get(denom)
+            .ok_or_else(|| CommonError::UnsupportedDenom(denom.to_string()))?;
+        let cached = prices
+            .get(denom)
+            .ok_or_else(|| CommonError::MissingRate(denom.to_string()))?;
+        let age = now.seconds().saturating_sub(cached.updated_at.seconds());
+        if age > max_age {
+            return Err(CommonError::StaleRate {
+                pair: denom.to_string(),
+                age,
+                max_age,
+            });
+        }
+        total = total.checked_add(asset_value(*amount, cached.rate, config)?)?;
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::balances::Balances;
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::rate_cache::CachedRate;
+    use crate::serializable_denom::SerializableDenom;
+    use crate::serializable_map::SerializableMap;
+    use crate::valuation::{basket_value, CollateralConfig};
+    use cosmwasm_std::Timestamp;
+
+    fn denom(name: &str) -> SerializableDenom {
+        SerializableDenom::Native(name.to_string())
+    }
+
+    fn setup() -> (
+        SerializableMap<SerializableDenom, CachedRate>,
+        SerializableMap<SerializableDenom, CollateralConfig>,
+    ) {
+        let rate = |rate: u64, updated_at: u64| CachedRate {
+            rate: BigDecimal::from(BigInteger::from(rate), 0),
+            updated_at: Timestamp::from_seconds(updated_at),
+        };
+        let prices = SerializableMap::from(vec![
+            (denom("uatom"), rate(10, 100)),
+            (denom("wei"), rate(2000, 50)),
+        ]);
+        let registry = SerializableMap::from(vec![
+            (
+                denom("uatom"),
+                CollateralConfig::new(6, BigDecimal::percent(20)),
+            ),
+            (
+                denom("wei"),
+                CollateralConfig::new(18, BigDecimal::percent(10)),
+            ),
+        ]);
+        (prices, registry)
+    }
+
+    #[test]
+    fn test_basket_value() {
+        let (prices, registry) = setup();
+        let mut balances = Balances::new();
+        balances
+            .add(denom("uatom"), BigInteger::from(5_000_000u64))
+            .unwrap();
+        balances
+            .add(denom("wei"), BigInteger::from(500_000_000_000_000_000u64))
+            .unwrap();
+        // 5 atom at 10 less 20% plus 0.5 eth at 2000 less 10%
+        assert_eq!(
+            basket_value(
+                &balances,
+                &prices,
+                &registry,
+                Timestamp::from_seconds(110),
+                60
+            )
+            .unwrap(),
+            BigDecimal::from(BigInteger::from(940u64), 0)
+        );
+        assert_eq!(
+            basket_value(
+                &Balances::new(),
+                &prices,
+                &registry,
+                Timestamp::from_seconds(110),
+                60
+            )
+            .unwrap(),
+            BigDecimal::zero()
+        );
+    }
+
+    #[test]
+    fn test_basket_value_errors() {
+        let (prices, registry) = setup();
+        let now = Timestamp::from_seconds(110);
+
+        let mut balances = Balances::new();
+        balances.add(denom("wei"), BigInteger::one()).unwrap();
+        assert_eq!(
+            basket_value(&balances, &prices, &registry, now, 30),
+            Err(CommonError::StaleRate {
+                pair: denom("wei").to_string(),
+                age: 60,
+                max_age: 30,
+            })
+        );
+
+        let mut balances = Balances::new();
+        balances.add(denom("uosmo"), BigInteger::one()).unwrap();
+        assert_eq!(
+            basket_value(&balances, &prices, &registry, now, 60),
+            Err(CommonError::UnsupportedDenom(denom("uosmo").to_string()))
+        );
+
+        let mut registry = registry;
+        registry.set(denom("uosmo"), CollateralConfig::new(6, BigDecimal::zero()));
+        assert_eq!(
+            basket_value(&balances, &prices, &registry, now, 60),
+            Err(CommonError::MissingRate(denom("uosmo").to_string()))
+        );
+
+        registry.set(
+            denom("uatom"),
+            CollateralConfig::new(6, BigDecimal::percent(150)),
+        );
+        let mut balances = Balances::new();
+        balances.add(denom("uatom"), BigInteger::one()).unwrap();
+        assert!(matches!(
+            basket_value(&balances, &prices, &registry, now, 60),
+            Err(CommonError::InvalidRatio(_))
+        ));
+    }
+}
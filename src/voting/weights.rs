@@ -0,0 +1,112 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use crate::rounding::{mul_div, RoundingMode};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Decimal256;
+
+// Maps a stake to its voting weight. `total_stake` is the sum of all stakes in the vote.
+pub trait VotingWeight {
+    fn weight(&self, stake: BigInteger, total_stake: BigInteger)
+        -> Result<BigInteger, CommonError>;
+
+    fn weights(&self, stakes: &[BigInteger]) -> Result<Vec<BigInteger>, CommonError> {
+        let total = stakes
+            .iter()
+            .try_fold(BigInteger::zero(), |total, stake| total.checked_add(*stake))?;
+        stakes
+            .iter()
+            .map(|stake| self.weight(*stake, total))
+            .collect()
+    }
+}
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum WeightTransform {
+    Identity,
+    // quadratic voting: weight grows with the square root of the stake
+    Sqrt,
+    // no single stake counts for more than `max_fraction` of the total stake
+    Capped { max_fraction: BigDecimal },
+}
+
+impl VotingWeight for WeightTransform {
+    fn weight(
+        &self,
+        stake: BigInteger,
+        total_stake: BigInteger,
+    ) -> Result<BigInteger, CommonError> {
+        match self {
+            WeightTransform::Identity => Ok(stake),
+            WeightTransform::Sqrt => Ok(stake.isqrt()),
+            WeightTransform::Capped { max_fraction } => {
+                if max_fraction.0 > Decimal256::one() {
+                    return Err(CommonError::InvalidRatio(max_fraction.to_string()));
+                }
+                let cap = mul_div(
+                    total_stake,
+                    BigInteger(max_fraction.0.atomics()),
+                    BigInteger(Decimal256::one().atomics()),
+                    RoundingMode::Floor,
+                )?;
+                Ok(stake.min(cap))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::voting::weights::{VotingWeight, WeightTransform};
+
+    fn transforms() -> [WeightTransform; 3] {
+        [
+            WeightTransform::Identity,
+            WeightTransform::Sqrt,
+            WeightTransform::Capped {
+                max_fraction: BigDecimal::percent(10),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_weights() {
+        let stakes = [100u64, 400, 500].map(BigInteger::from);
+        assert_eq!(
+            WeightTransform::Sqrt.weights(&stakes).unwrap(),
+            [10u64, 20, 22].map(BigInteger::from)
+        );
+        let capped = WeightTransform::Capped {
+            max_fraction: BigDecimal::percent(25),
+        };
+        assert_eq!(
+            capped.weights(&stakes).unwrap(),
+            [100u64, 250, 250].map(BigInteger::from)
+        );
+        let invalid = WeightTransform::Capped {
+            max_fraction: BigDecimal::percent(101),
+        };
+        assert_eq!(
+            invalid.weights(&stakes),
+            Err(CommonError::InvalidRatio("1.01".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_transforms_are_monotonic() {
+        let total = BigInteger::from(1_000_000u64);
+        for transform in transforms() {
+            let mut last = BigInteger::zero();
+            for stake in (0u64..=1_000_000).step_by(997) {
+                let weight = transform.weight(BigInteger::from(stake), total).unwrap();
+                assert!(weight >= last, "{:?} at {}", transform, stake);
+                assert!(weight <= BigInteger::from(stake));
+                last = weight;
+            }
+        }
+    }
+}
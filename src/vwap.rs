@@ -0,0 +1,82 @@
+use crate::bigdecimal::BigDecimal;
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal256, Uint256, Uint512};
+
+// Volume weighted average price. Both sums are kept in 512 bits so that accumulating large
+// volumes cannot overflow before the division.
+#[cw_serde]
+#[derive(Default, Eq)]
+pub struct Vwap {
+    pub epoch: u64,
+    pub price_volume: Uint512,
+    pub volume: Uint512,
+}
+
+impl Vwap {
+    pub fn new(epoch: u64) -> Self {
+        Self {
+            epoch,
+            ..Self::default()
+        }
+    }
+
+    pub fn reset(&mut self, epoch: u64) {
+        *self = Self::new(epoch);
+    }
+
+    pub fn update(&mut self, price: BigDecimal, volume: BigInteger) -> Result<(), CommonError> {
+        let weighted = Uint512::from(price.0.atomics()).checked_mul(Uint512::from(volume.0))?;
+        self.price_volume = self.price_volume.checked_add(weighted)?;
+        self.volume = self.volume.checked_add(Uint512::from(volume.0))?;
+        Ok(())
+    }
+
+    // `None` until some volume has been recorded
+    pub fn value(&self) -> Option<BigDecimal> {
+        if self.volume.is_zero() {
+            return None;
+        }
+        // the average never exceeds the largest price recorded, so it fits in 256 bits
+        let atomics = Uint256::try_from(self.price_volume / self.volume).ok()?;
+        Some(BigDecimal(Decimal256::new(atomics)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bigdecimal::BigDecimal;
+    use crate::biginteger::BigInteger;
+    use crate::vwap::Vwap;
+
+    #[test]
+    fn test_vwap() {
+        let mut vwap = Vwap::new(1);
+        assert_eq!(vwap.value(), None);
+
+        vwap.update(BigDecimal::percent(100), BigInteger::from(30u64))
+            .unwrap();
+        vwap.update(BigDecimal::percent(200), BigInteger::from(10u64))
+            .unwrap();
+        assert_eq!(vwap.value(), Some(BigDecimal::percent(125)));
+
+        vwap.reset(2);
+        assert_eq!(vwap, Vwap::new(2));
+        assert_eq!(vwap.value(), None);
+    }
+
+    #[test]
+    fn test_large_volume() {
+        let mut vwap = Vwap::default();
+        for _ in 0..3 {
+            vwap.update(BigDecimal::MAX, BigInteger::from(u128::MAX))
+                .unwrap();
+        }
+        assert_eq!(vwap.value(), Some(BigDecimal::MAX));
+
+        let json = cosmwasm_std::to_json_vec(&vwap).unwrap();
+        let parsed: Vwap = cosmwasm_std::from_json(json).unwrap();
+        assert_eq!(parsed, vwap);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::biginteger::BigInteger;
+use crate::error::CommonError;
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct LossAllocation {
+    // one entry per tranche, in the order they were given
+    pub losses: Vec<BigInteger>,
+    // the part of the loss no tranche could cover, to be socialized
+    pub shortfall: BigInteger,
+}
+
+// Applies `loss` to `balances` in order, each tranche absorbing as much as it holds before the
+// next one is touched.
+pub fn allocate_loss(balances: &[BigInteger], loss: BigInteger) -> LossAllocation {
+    let mut remaining = loss;
+    let losses = balances
+        .iter()
+        .map(|balance| {
+            let absorbed = (*balance).min(remaining);
+            remaining -= absorbed;
+            absorbed
+        })
+        .collect();
+    LossAllocation {
+        losses,
+        shortfall: remaining,
+    }
+}
+
+// The usual layout: the insurance fund absorbs first, then junior and finally senior capital.
+#[cw_serde]
+#[derive(Copy, Eq, Default)]
+pub struct Tranches {
+    pub insurance_fund: BigInteger,
+    pub junior: BigInteger,
+    pub senior: BigInteger,
+}
+
+impl Tranches {
+    // Deducts the loss from the tranches and returns what could not be covered.
+    pub fn absorb(&mut self, loss: BigInteger) -> BigInteger {
+        let allocation = allocate_loss(&[self.insurance_fund, self.junior, self.senior], loss);
+        self.insurance_fund -= allocation.losses[0];
+        self.junior -= allocation.losses[1];
+        self.senior -= allocation.losses[2];
+        allocation.shortfall
+    }
+
+    pub fn total(&self) -> Result<BigInteger, CommonError> {
+        Ok(self
+            .insurance_fund
+            .checked_add(self.junior)?
+            .checked_add(self.senior)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::biginteger::BigInteger;
+    use crate::error::CommonError;
+    use crate::waterfall::{allocate_loss, LossAllocation, Tranches};
+
+    fn amounts(values: &[u64]) -> Vec<BigInteger> {
+        values.iter().map(|v| BigInteger::from(*v)).collect()
+    }
+
+    #[test]
+    fn test_allocate_loss() {
+        let balances = amounts(&[100, 200, 300]);
+        assert_eq!(
+            allocate_loss(&balances, BigInteger::from(250u64)),
+            LossAllocation {
+                losses: amounts(&[100, 150, 0]),
+                shortfall: BigInteger::zero(),
+            }
+        );
+        assert_eq!(
+            allocate_loss(&balances, BigInteger::from(700u64)),
+            LossAllocation {
+                losses: amounts(&[100, 200, 300]),
+                shortfall: BigInteger::from(100u64),
+            }
+        );
+        assert_eq!(
+            allocate_loss(&balances, BigInteger::zero()).losses,
+            amounts(&[0, 0, 0])
+        );
+        assert_eq!(
+            allocate_loss(&[], BigInteger::from(5u64)).shortfall,
+            BigInteger::from(5u64)
+        );
+    }
+
+    #[test]
+    fn test_tranches_absorb() {
+        let mut tranches = Tranches {
+            insurance_fund: BigInteger::from(50u64),
+            junior: BigInteger::from(100u64),
+            senior: BigInteger::from(1000u64),
+        };
+        assert_eq!(tranches.absorb(BigInteger::from(80u64)), BigInteger::zero());
+        assert!(tranches.insurance_fund.is_zero());
+        assert_eq!(tranches.junior, BigInteger::from(70u64));
+
+        assert_eq!(
+            tranches.absorb(BigInteger::from(1100u64)),
+            BigInteger::from(30u64)
+        );
+        assert!(tranches.total().unwrap().is_zero());
+
+        let full = Tranches {
+            insurance_fund: BigInteger::MAX,
+            junior: BigInteger::one(),
+            senior: BigInteger::zero(),
+        };
+        assert!(matches!(full.total(), Err(CommonError::Std(_))));
+    }
+}